@@ -0,0 +1,143 @@
+//! # Attachment GET API
+//!
+//! This module provides functionality to download a repository attachment from Bitbucket
+//! Server, such as an image embedded in a pull request comment. Comments reference their
+//! attachments with `attachment:` links containing the attachment ID.
+
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use reqwest::header::HeaderMap;
+
+/// Request for downloading a repository attachment.
+#[derive(Clone, Debug)]
+pub struct AttachmentGet {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the attachment
+    attachment_id: String,
+}
+
+impl ApiRequest for AttachmentGet {
+    // response is raw bytes, not JSON
+    type Output = Vec<u8>;
+    const ENDPOINT_NAME: &'static str = "attachment_get";
+
+    /// Sends the request to download the attachment.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the attachment's bytes, `None` if the attachment doesn't
+    /// exist, or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers, e.g. the attachment's `Content-Type`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        match self.client.send_bytes_request(self.build_request()?).await {
+            // the attachment was deleted, or the ID is wrong
+            Err(error) if is_missing_attachment(&error) => Ok((None, HeaderMap::new())),
+            response => response,
+        }
+    }
+}
+
+impl DescribeRequest for AttachmentGet {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/attachments/{}",
+            self.project_key, self.repository_slug, self.attachment_id
+        );
+
+        self.client.build_bytes_request(&request_uri, None)
+    }
+}
+
+/// Check whether an error is the 404 of an attachment that doesn't exist, rather than of a
+/// missing project or repository.
+fn is_missing_attachment(error: &Error) -> bool {
+    error.status() == Some(404)
+        && error.server_errors().iter().any(|server_error| {
+            server_error.exception_simple_name() == Some("NoSuchAttachmentException")
+        })
+}
+
+crate::client::impl_with_client!(AttachmentGet);
+
+impl Api {
+    /// Creates a request to download a repository attachment.
+    ///
+    /// The attachment is returned as raw bytes, exactly as stored by the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `attachment_id` - The ID of the attachment, e.g. from an `attachment:` link in a comment
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let attachment = client
+    ///         .api()
+    ///         .attachment_get("PROJECT", "REPO", "42")
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     match attachment {
+    ///         Some(bytes) => std::fs::write("screenshot.png", bytes)?,
+    ///         None => println!("The attachment no longer exists"),
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * A missing attachment is returned as `None` rather than an error. A missing project or
+    ///   repository is still an `Error::HttpClientError`.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-repository/#api-api-latest-projects-projectkey-repos-repositoryslug-attachments-attachmentid-get)
+    pub fn attachment_get(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        attachment_id: &str,
+    ) -> AttachmentGet {
+        AttachmentGet {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            attachment_id: attachment_id.to_owned(),
+        }
+    }
+}
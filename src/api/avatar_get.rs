@@ -0,0 +1,159 @@
+//! # Avatar GET API
+//!
+//! This module provides functionality to download the avatar image of a user or a project
+//! from Bitbucket Server, e.g. so that dashboards can cache avatars locally instead of
+//! loading them from the server on every page view.
+
+use crate::api::Api;
+use crate::client::{
+    encode_path_segment, ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest,
+};
+use crate::Error;
+use std::collections::BTreeMap;
+
+/// Request for downloading the avatar image of a user or a project.
+#[derive(Clone, Debug)]
+pub struct AvatarGet {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The path of the user or project owning the avatar, e.g. `users/jdoe`
+    owner_path: String,
+
+    /// The requested width and height of the avatar, in pixels
+    size: u32,
+}
+
+impl ApiRequest for AvatarGet {
+    // response is raw bytes, not JSON
+    type Output = Vec<u8>;
+    const ENDPOINT_NAME: &'static str = "avatar_get";
+
+    /// Sends the request to download the avatar.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the avatar image bytes or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers, e.g. the image's `Content-Type`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.client.send_bytes_request(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for AvatarGet {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!("api/latest/{}/avatar.png", self.owner_path);
+
+        let mut params = BTreeMap::new();
+        params.insert("s".to_string(), self.size.to_string());
+
+        self.client.build_bytes_request(&request_uri, Some(params))
+    }
+}
+
+crate::client::impl_with_client!(AvatarGet);
+
+impl Api {
+    /// Creates a request to download the avatar image of a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_slug` - The slug of the user
+    /// * `size` - The requested width and height of the avatar, in pixels
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let avatar = client.api().user_avatar("jdoe", 64).send().await?;
+    ///
+    ///     if let Some(bytes) = avatar {
+    ///         std::fs::write("jdoe.png", bytes)?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * Users without an avatar get a default one: depending on the server's configuration,
+    ///   it either returns a generic image or redirects to an external avatar service. The
+    ///   default HTTP client follows the redirect, without sending the API token to other
+    ///   hosts; with redirects disabled, the request fails with `Error::Redirect` holding the
+    ///   URL of the default avatar.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-system-maintenance/#api-api-latest-users-userslug-avatar-png-get)
+    pub fn user_avatar(self, user_slug: &str, size: u32) -> AvatarGet {
+        AvatarGet {
+            client: self.client,
+            owner_path: format!("users/{}", encode_path_segment(user_slug)),
+            size,
+        }
+    }
+
+    /// Creates a request to download the avatar image of a project.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project
+    /// * `size` - The requested width and height of the avatar, in pixels
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let avatar = client.api().project_avatar("PROJECT", 48).send().await?;
+    ///
+    ///     if let Some(bytes) = avatar {
+    ///         std::fs::write("project.png", bytes)?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * Projects without an avatar get a generic default image.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-project/#api-api-latest-projects-projectkey-avatar-png-get)
+    pub fn project_avatar(self, project_key: &str, size: u32) -> AvatarGet {
+        AvatarGet {
+            client: self.client,
+            owner_path: format!("projects/{project_key}"),
+            size,
+        }
+    }
+}
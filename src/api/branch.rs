@@ -0,0 +1,51 @@
+//! # Branch Common Types
+//!
+//! This module contains common types used by the branch API endpoints.
+
+use serde::{Deserialize, Serialize};
+
+/// A branch of a repository, as returned by the API.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Branch {
+    /// The fully qualified ID of the branch, e.g. `refs/heads/feature`
+    pub id: String,
+
+    /// The short display ID of the branch, e.g. `feature`
+    pub display_id: String,
+
+    /// The type of the reference, `BRANCH`
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub ref_type: Option<String>,
+
+    /// The ID of the latest commit on the branch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_commit: Option<String>,
+
+    /// Whether this is the default branch of the repository
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_can_deserialize() {
+        let json = r#"{
+            "id": "refs/heads/main",
+            "displayId": "main",
+            "type": "BRANCH",
+            "latestCommit": "8d51122def5632836d1cb1026e879069e10a1e13",
+            "latestChangeset": "8d51122def5632836d1cb1026e879069e10a1e13",
+            "isDefault": true
+        }"#;
+
+        let branch: Branch = serde_json::from_str(json).unwrap();
+
+        assert_eq!(branch.display_id, "main");
+        assert_eq!(branch.ref_type, Some("BRANCH".to_string()));
+        assert!(branch.is_default);
+    } // end of it_can_deserialize
+}
@@ -0,0 +1,182 @@
+//! # Branch DELETE API
+//!
+//! This module provides functionality to delete a branch of a repository in Bitbucket Server,
+//! through the branch utilities REST module.
+
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// The payload for deleting a branch.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchDeletePayload {
+    /// The name of the branch, e.g. `refs/heads/feature`
+    pub name: String,
+
+    /// The commit the branch is expected to point at; the branch is not deleted if it moved
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_point: Option<String>,
+
+    /// Only check that the branch can be deleted, without deleting it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+}
+
+/// Request builder for deleting a branch.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct BranchDelete {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The name of the branch, e.g. `refs/heads/feature`
+    name: String,
+
+    /// The commit the branch is expected to point at; the branch is not deleted if it moved
+    #[builder(setter(into, strip_option), default)]
+    end_point: Option<String>,
+
+    /// Only check that the branch can be deleted, without deleting it
+    #[builder(setter(into, strip_option), default)]
+    dry_run: Option<bool>,
+}
+
+impl ApiRequest for BranchDelete {
+    // response has no content
+    type Output = ();
+    const ENDPOINT_NAME: &'static str = "branch_delete";
+
+    /// Sends the request to delete the branch.
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or failure.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.client
+            .send_request::<Self::Output>(self.build_request()?)
+            .await
+    }
+}
+
+impl DescribeRequest for BranchDelete {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "branch-utils/latest/projects/{}/repos/{}/branches",
+            self.project_key, self.repository_slug
+        );
+
+        let payload = BranchDeletePayload {
+            name: self.name.clone(),
+            end_point: self.end_point.clone(),
+            dry_run: self.dry_run,
+        };
+
+        self.client.build_request(
+            Method::DELETE,
+            &request_uri,
+            None,
+            Some(&self.client.to_body(&payload)),
+        )
+    }
+}
+
+crate::client::impl_with_client!(BranchDelete);
+
+impl Api {
+    /// Creates a request builder for deleting a branch.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `name` - The name of the branch, e.g. `refs/heads/feature`
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     client
+    ///         .api()
+    ///         .branch_delete("PROJECT", "REPO", "refs/heads/feature")
+    ///         .end_point("8d51122def5632836d1cb1026e879069e10a1e13")
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_WRITE permission for the repository, and branch
+    ///   permissions may prevent deleting some branches.
+    /// * The default branch of a repository cannot be deleted.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-repository/#api-branch-utils-latest-projects-projectkey-repos-repositoryslug-branches-delete)
+    pub fn branch_delete(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        name: &str,
+    ) -> BranchDeleteBuilder {
+        let mut builder = BranchDeleteBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .name(name.to_string());
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_can_serialize_payload() {
+        let payload = BranchDeletePayload {
+            name: "refs/heads/feature".to_string(),
+            end_point: Some("8d51122def5".to_string()),
+            dry_run: None,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&payload).unwrap(),
+            r#"{"name":"refs/heads/feature","endPoint":"8d51122def5"}"#
+        );
+    } // end of it_can_serialize_payload
+}
@@ -0,0 +1,138 @@
+//! # Branch Merged Check
+//!
+//! This module provides a helper that checks whether a branch is fully merged into another,
+//! e.g. for branch cleanup or release automation that must not delete or tag unmerged work.
+//! A branch is merged when it has no commits that the other branch doesn't have.
+
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+use reqwest::header::HeaderMap;
+
+/// Request for checking whether a branch is merged into another.
+#[derive(Clone, Debug)]
+pub struct BranchIsMerged {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The branch to check, e.g. `feature` or `refs/heads/feature`
+    branch: String,
+
+    /// The branch it should be merged into, e.g. `main` or `refs/heads/main`
+    into: String,
+}
+
+impl ApiRequest for BranchIsMerged {
+    type Output = bool;
+    const ENDPOINT_NAME: &'static str = "branch_is_merged";
+
+    /// Sends the request to compare the branches.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either whether the branch is merged or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request; the headers of the comparison are not kept, so they are empty.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        // a single commit on the branch is enough to know it isn't merged
+        let page = Api {
+            client: self.client.clone(),
+        }
+        .compare_commits(
+            &self.project_key,
+            &self.repository_slug,
+            &self.branch,
+            &self.into,
+        )
+        .limit(1u32)
+        .build()
+        .map_err(Error::from_builder)?
+        .send()
+        .await?
+        .ok_or_else(|| {
+            Error::Unexpected(format!(
+                "Comparing {} with {} returned no commits page",
+                self.branch, self.into
+            ))
+        })?;
+
+        Ok((Some(page.values.is_empty()), HeaderMap::new()))
+    }
+}
+
+crate::client::impl_with_client!(BranchIsMerged);
+
+impl Api {
+    /// Creates a request to check whether a branch is merged into another.
+    ///
+    /// The commits on `branch` that are not on `into` are listed with
+    /// [`Api::compare_commits`]; the branch is merged if there are none.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `branch` - The branch to check, e.g. `feature` or `refs/heads/feature`
+    /// * `into` - The branch it should be merged into, e.g. `main` or `refs/heads/main`
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let merged = client
+    ///         .api()
+    ///         .is_branch_merged("PROJECT", "REPO", "feature/login", "main")
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if merged == Some(true) {
+    ///         println!("feature/login can be deleted");
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * If either branch doesn't exist, the server responds with HTTP 404 and the request
+    ///   fails with `Error::HttpClientError`; it never reports a missing branch as unmerged.
+    pub fn is_branch_merged(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        branch: &str,
+        into: &str,
+    ) -> BranchIsMerged {
+        BranchIsMerged {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            branch: branch.to_owned(),
+            into: into.to_owned(),
+        }
+    }
+}
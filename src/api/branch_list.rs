@@ -0,0 +1,179 @@
+//! # Branch List API
+//!
+//! This module provides functionality to list the branches of a repository in Bitbucket Server,
+//! optionally filtered by name.
+
+use crate::api::branch::Branch;
+use crate::api::common::Page;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+/// Request builder for listing branches.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct BranchList {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// Only return branches whose name contains this text
+    #[builder(setter(into, strip_option), default)]
+    filter_text: Option<String>,
+
+    /// ALPHABETICAL or MODIFICATION. If not passed, MODIFICATION is assumed.
+    #[builder(setter(into, strip_option), default)]
+    order_by: Option<String>,
+
+    /// Start number for the page (inclusive). If not passed, first page is assumed.
+    #[builder(setter(into, strip_option), default)]
+    start: Option<u64>,
+
+    /// Number of items to return. If not passed, a page size of 25 is used.
+    #[builder(setter(into, strip_option), default)]
+    limit: Option<u64>,
+}
+
+impl BranchList {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for BranchList {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/branches",
+            self.project_key, self.repository_slug
+        );
+
+        let mut params = BTreeMap::new();
+
+        if let Some(filter_text) = &self.filter_text {
+            params.insert("filterText".to_string(), filter_text.clone());
+        }
+        if let Some(order_by) = &self.order_by {
+            params.insert("orderBy".to_string(), order_by.clone());
+        }
+        if let Some(start) = &self.start {
+            params.insert("start".to_string(), start.to_string());
+        }
+        if let Some(limit) = &self.limit {
+            params.insert("limit".to_string(), limit.to_string());
+        }
+
+        self.client
+            .build_request(Method::GET, &request_uri, Some(params), None)
+    }
+}
+
+impl ApiRequest for BranchList {
+    type Output = Page<Branch>;
+    const ENDPOINT_NAME: &'static str = "branch_list";
+
+    /// Sends the request to list branches.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either a page of branches or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for BranchList {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(BranchList);
+
+impl Api {
+    /// Creates a request builder for listing the branches of a repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let response = client
+    ///         .api()
+    ///         .branch_list("PROJECT", "REPO")
+    ///         .filter_text("release/")
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(page) = response {
+    ///         for branch in page.values {
+    ///             println!("{}", branch.display_id);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-repository/#api-api-latest-projects-projectkey-repos-repositoryslug-branches-get)
+    pub fn branch_list(self, project_key: &str, repository_slug: &str) -> BranchListBuilder {
+        let mut builder = BranchListBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string());
+        builder
+    }
+}
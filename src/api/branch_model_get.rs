@@ -0,0 +1,310 @@
+//! # Branch Model GET API
+//!
+//! This module provides functionality to retrieve the branching model of a repository from
+//! Bitbucket Server: its development and production branches, and the prefixes of its branch
+//! types, e.g. `feature/` and `release/` for git-flow. Automation creating branches uses it
+//! to name them the way the repository expects. It uses the `branch-utils` REST module.
+
+use crate::api::branch::Branch;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// The branching model of a repository, as returned by the API.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchModel {
+    /// The development and production branches of the model
+    #[serde(flatten)]
+    pub branches: BranchModelBranches,
+
+    /// The enabled branch types and their prefixes
+    #[serde(default)]
+    pub types: Vec<BranchType>,
+}
+
+impl BranchModel {
+    /// The prefix of a branch type, e.g. `feature/` for [`BranchTypeId::Feature`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The branch type
+    ///
+    /// # Returns
+    ///
+    /// The prefix, or `None` if the branch type isn't enabled in the model.
+    pub fn prefix(&self, id: &BranchTypeId) -> Option<&str> {
+        self.types
+            .iter()
+            .find(|branch_type| &branch_type.id == id)
+            .map(|branch_type| branch_type.prefix.as_str())
+    }
+}
+
+/// The branches of a branching model.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchModelBranches {
+    /// The branch that features are merged into, e.g. `develop`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub development: Option<Branch>,
+
+    /// The branch that releases are merged into, e.g. `master`; not every model has one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub production: Option<Branch>,
+}
+
+/// A branch type of a branching model.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchType {
+    /// The kind of branch
+    pub id: BranchTypeId,
+
+    /// The display name of the branch type, e.g. `Feature`
+    pub display_name: String,
+
+    /// The prefix of the names of branches of this type, e.g. `feature/`
+    pub prefix: String,
+}
+
+/// The kinds of branch types of a branching model.
+///
+/// When serialized, it uses uppercase strings as required by the API. Kinds this crate doesn't
+/// know about deserialize to `Unknown`.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BranchTypeId {
+    /// Branches fixing bugs
+    #[serde(rename = "BUGFIX")]
+    Bugfix,
+
+    /// Branches developing features
+    #[serde(rename = "FEATURE")]
+    Feature,
+
+    /// Branches fixing production urgently
+    #[serde(rename = "HOTFIX")]
+    Hotfix,
+
+    /// Branches preparing releases
+    #[serde(rename = "RELEASE")]
+    Release,
+
+    /// The kind is unknown or not set
+    #[default]
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+/// Request for retrieving the branching model of a repository.
+#[derive(Clone, Debug)]
+pub struct BranchModelGet {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+}
+
+impl BranchModelGet {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for BranchModelGet {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "branch-utils/latest/projects/{}/repos/{}/branchmodel",
+            self.project_key, self.repository_slug
+        );
+
+        self.client
+            .build_request(Method::GET, &request_uri, None, None)
+    }
+}
+
+impl ApiRequest for BranchModelGet {
+    type Output = BranchModel;
+    const ENDPOINT_NAME: &'static str = "branch_model_get";
+
+    /// Sends the request to retrieve the branching model.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the branching model or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for BranchModelGet {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(BranchModelGet);
+
+impl Api {
+    /// Creates a request to retrieve the branching model of a repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::branch_model_get::BranchTypeId;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let branch_model = client
+    ///         .api()
+    ///         .branch_model_get("PROJECT", "REPO")
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(prefix) = branch_model
+    ///         .as_ref()
+    ///         .and_then(|branch_model| branch_model.prefix(&BranchTypeId::Feature))
+    ///     {
+    ///         println!("New feature branch: {}login-page", prefix);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * Only enabled branch types are returned.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-repository/#api-branch-utils-latest-projects-projectkey-repos-repositoryslug-branchmodel-get)
+    pub fn branch_model_get(self, project_key: &str, repository_slug: &str) -> BranchModelGet {
+        BranchModelGet {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_can_deserialize_git_flow_model() {
+        let json = r#"{
+            "development": {
+                "id": "refs/heads/develop",
+                "displayId": "develop",
+                "type": "BRANCH",
+                "latestCommit": "8d51122def5632836d1cb1026e879069e10a1e13",
+                "isDefault": true
+            },
+            "production": {
+                "id": "refs/heads/master",
+                "displayId": "master",
+                "type": "BRANCH",
+                "latestCommit": "0a943a29376f2336b78312d99e65da17048951db",
+                "isDefault": false
+            },
+            "types": [
+                {"id": "BUGFIX", "displayName": "Bugfix", "prefix": "bugfix/"},
+                {"id": "FEATURE", "displayName": "Feature", "prefix": "feature/"},
+                {"id": "HOTFIX", "displayName": "Hotfix", "prefix": "hotfix/"},
+                {"id": "RELEASE", "displayName": "Release", "prefix": "release/"}
+            ]
+        }"#;
+
+        let branch_model: BranchModel = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            branch_model
+                .branches
+                .development
+                .as_ref()
+                .unwrap()
+                .display_id,
+            "develop"
+        );
+        assert_eq!(
+            branch_model
+                .branches
+                .production
+                .as_ref()
+                .unwrap()
+                .display_id,
+            "master"
+        );
+        assert_eq!(branch_model.types.len(), 4);
+        assert_eq!(branch_model.types[1].display_name, "Feature");
+        assert_eq!(
+            branch_model.prefix(&BranchTypeId::Feature),
+            Some("feature/")
+        );
+        assert_eq!(
+            branch_model.prefix(&BranchTypeId::Release),
+            Some("release/")
+        );
+    } // end of it_can_deserialize_git_flow_model
+
+    #[test]
+    fn it_can_deserialize_model_without_production_branch() {
+        let json = r#"{
+            "development": {"id": "refs/heads/main", "displayId": "main"},
+            "types": [{"id": "FEATURE", "displayName": "Feature", "prefix": "feat/"}]
+        }"#;
+
+        let branch_model: BranchModel = serde_json::from_str(json).unwrap();
+
+        assert_eq!(branch_model.branches.production, None);
+        assert_eq!(branch_model.prefix(&BranchTypeId::Feature), Some("feat/"));
+        assert_eq!(branch_model.prefix(&BranchTypeId::Hotfix), None);
+    } // end of it_can_deserialize_model_without_production_branch
+}
@@ -0,0 +1,253 @@
+//! # Prune Merged Branches
+//!
+//! This module provides a repository hygiene helper that deletes the branches matching a glob
+//! pattern (e.g. `renovate/*`) once they are fully merged into the default branch. A dry run
+//! reports the branches that would be deleted without deleting anything.
+
+use crate::api::branch::Branch;
+use crate::api::pagination::paged_stream;
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+use derive_builder::Builder;
+use futures_util::TryStreamExt;
+use reqwest::header::HeaderMap;
+
+/// Request builder for deleting merged branches.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct PruneMergedBranches {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The glob pattern matched against the display ID of the branches, e.g. `renovate/*`
+    pattern: String,
+
+    /// Only report the branches that would be deleted, without deleting them
+    #[builder(default)]
+    dry_run: bool,
+}
+
+impl PruneMergedBranches {
+    /// Lists all the branches of the repository, following pagination.
+    async fn branches(&self) -> Result<Vec<Branch>, Error> {
+        paged_stream(|start| {
+            Api {
+                client: self.client.clone(),
+            }
+            .branch_list(&self.project_key, &self.repository_slug)
+            .start(start)
+            .build()
+        })
+        .try_collect()
+        .await
+    }
+
+    /// Checks whether a branch has no commits that are not on the default branch.
+    async fn is_merged(&self, branch: &Branch, default_branch: &Branch) -> Result<bool, Error> {
+        let merged = Api {
+            client: self.client.clone(),
+        }
+        .is_branch_merged(
+            &self.project_key,
+            &self.repository_slug,
+            &branch.id,
+            &default_branch.id,
+        )
+        .send()
+        .await?;
+
+        Ok(merged.unwrap_or_default())
+    }
+}
+
+impl ApiRequest for PruneMergedBranches {
+    type Output = Vec<Branch>;
+    const ENDPOINT_NAME: &'static str = "branch_prune_merged";
+
+    /// Sends the requests to find the merged branches and delete them.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the branches that were deleted, or would be deleted in a dry
+    /// run, or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the requests; as several requests are made, the headers are empty.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        let branches = self.branches().await?;
+
+        let default_branch = branches
+            .iter()
+            .find(|branch| branch.is_default)
+            .cloned()
+            .ok_or_else(|| {
+                Error::Unexpected(format!(
+                    "Repository {}/{} has no default branch",
+                    self.project_key, self.repository_slug
+                ))
+            })?;
+
+        let mut pruned = Vec::new();
+        for branch in branches {
+            if branch.is_default || !glob_matches(&self.pattern, &branch.display_id) {
+                continue;
+            }
+            if !self.is_merged(&branch, &default_branch).await? {
+                continue;
+            }
+
+            if !self.dry_run {
+                let mut delete = Api {
+                    client: self.client.clone(),
+                }
+                .branch_delete(&self.project_key, &self.repository_slug, &branch.id);
+                if let Some(latest_commit) = &branch.latest_commit {
+                    delete.end_point(latest_commit.clone());
+                }
+
+                delete
+                    .build()
+                    .map_err(Error::from_builder)?
+                    .send()
+                    .await?;
+            }
+
+            pruned.push(branch);
+        }
+
+        Ok((Some(pruned), HeaderMap::new()))
+    }
+}
+
+/// Matches a glob pattern against a text.
+///
+/// `*` matches any sequence of characters, including `/`, and `?` matches a single character.
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // the position of the last `*` in the pattern and the text position it was tried at
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, star_t)) => {
+                    p = star + 1;
+                    t = star_t + 1;
+                    backtrack = Some((star, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+crate::client::impl_with_client!(PruneMergedBranches);
+
+impl Api {
+    /// Creates a request builder for deleting the branches matching a pattern that are fully
+    /// merged into the default branch.
+    ///
+    /// All branches are listed with [`Api::branch_list`]; each branch matching the pattern is
+    /// compared to the default branch with [`Api::compare_commits`], and deleted with
+    /// [`Api::branch_delete`] if it has no commits the default branch doesn't have. The default
+    /// branch itself is never deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pattern` - The glob pattern matched against the display ID of the branches, e.g.
+    ///   `renovate/*`; `*` matches any characters including `/`, `?` matches one character
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let branches = client
+    ///         .api()
+    ///         .prune_merged_branches("PROJECT", "REPO", "renovate/*")
+    ///         .dry_run(true)
+    ///         .build()?
+    ///         .send()
+    ///         .await?
+    ///         .unwrap_or_default();
+    ///
+    ///     for branch in branches {
+    ///         println!("Would delete {}", branch.display_id);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_WRITE permission for the repository.
+    /// * A branch is only deleted if it still points at the commit it was checked at.
+    /// * If a deletion fails, the error is returned and the remaining branches are left as they
+    ///   are; branches deleted before the failure stay deleted.
+    pub fn prune_merged_branches(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pattern: &str,
+    ) -> PruneMergedBranchesBuilder {
+        let mut builder = PruneMergedBranchesBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .pattern(pattern.to_string());
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_can_match_globs() {
+        assert!(glob_matches("renovate/*", "renovate/serde-1.x"));
+        assert!(glob_matches("*", "feature/a/b"));
+        assert!(glob_matches("bot-*-fix", "bot-123-fix"));
+        assert!(glob_matches("release-?", "release-1"));
+        assert!(glob_matches("main", "main"));
+        assert!(!glob_matches("renovate/*", "feature/renovate"));
+        assert!(!glob_matches("release-?", "release-10"));
+        assert!(!glob_matches("bot-*-fix", "bot-123-fixed"));
+    } // end of it_can_match_globs
+}
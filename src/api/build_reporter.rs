@@ -0,0 +1,233 @@
+//! # Build Reporter
+//!
+//! This module provides a reporter for posting the build statuses of a commit as a build
+//! progresses. It remembers the last state posted for each build key and can optionally reject
+//! transitions that make no sense, such as marking a successful build as in progress again,
+//! before they confuse CI dashboards.
+//!
+//! For build matrices, child builds can be posted under a parent build key, and the parent's
+//! state computed from its children with [`BuildStatusState::aggregate`].
+
+use crate::api::build_status::BuildStatusState;
+use crate::api::build_status_post::BuildStatusPostPayload;
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, Client};
+use crate::Error;
+use derive_builder::Builder;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Posts the build statuses of a commit, tracking the last posted state of each key.
+#[derive(Debug, Default, Builder)]
+pub struct BuildReporter {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the commit to post the build statuses for
+    commit_id: String,
+
+    /// Whether to reject posting a state that isn't a valid transition from the last state
+    /// posted for the same key; see [`BuildStatusState::can_transition_to`]
+    #[builder(default)]
+    valid_states: bool,
+
+    /// The last state posted for each key
+    #[builder(setter(skip))]
+    last_states: Mutex<HashMap<String, BuildStatusState>>,
+
+    /// The keys of the child builds posted for each parent key, in the order first posted
+    #[builder(setter(skip))]
+    children: Mutex<HashMap<String, Vec<String>>>,
+}
+
+impl BuildReporter {
+    /// Posts a build status for the commit.
+    ///
+    /// # Arguments
+    ///
+    /// * `build_status` - The build status payload to post
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or failure. With `valid_states` set, an invalid transition
+    /// is a `RequestError` and nothing is posted.
+    pub async fn post(&self, build_status: &BuildStatusPostPayload) -> ApiResponse<()> {
+        if self.valid_states {
+            if let Some(last_state) = self.last_state(&build_status.key) {
+                if !last_state.can_transition_to(&build_status.state) {
+                    return Err(Error::RequestError(format!(
+                        "Invalid build status transition for key '{}': {:?} to {:?}",
+                        build_status.key, last_state, build_status.state
+                    )));
+                }
+            }
+        }
+
+        let response = Api {
+            client: self.client.clone(),
+        }
+        .build_status_post(
+            &self.project_key,
+            &self.repository_slug,
+            &self.commit_id,
+            build_status,
+        )
+        .send()
+        .await?;
+
+        self.last_states
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(build_status.key.clone(), build_status.state.clone());
+
+        Ok(response)
+    }
+
+    /// Posts the build status of a child build, with `parent` set to the parent's key.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent_key` - The key of the parent build, e.g. the key of the whole pipeline
+    /// * `build_status` - The build status payload of the child; its `parent` is overwritten
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or failure, as with [`BuildReporter::post`].
+    pub async fn post_child(
+        &self,
+        parent_key: &str,
+        build_status: &BuildStatusPostPayload,
+    ) -> ApiResponse<()> {
+        let mut build_status = build_status.clone();
+        build_status.parent = Some(parent_key.to_string());
+
+        let response = self.post(&build_status).await?;
+
+        let mut children = self
+            .children
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let keys = children.entry(parent_key.to_string()).or_default();
+        if !keys.contains(&build_status.key) {
+            keys.push(build_status.key);
+        }
+
+        Ok(response)
+    }
+
+    /// The state of a parent build, aggregated from the last states of the child builds
+    /// posted under it with [`BuildReporter::post_child`].
+    ///
+    /// # Arguments
+    ///
+    /// * `parent_key` - The key of the parent build
+    ///
+    /// # Returns
+    ///
+    /// The aggregated state; see [`BuildStatusState::aggregate`] for the rules.
+    pub fn parent_state(&self, parent_key: &str) -> BuildStatusState {
+        let child_keys = self
+            .children
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(parent_key)
+            .cloned()
+            .unwrap_or_default();
+        let child_states: Vec<BuildStatusState> = child_keys
+            .iter()
+            .filter_map(|key| self.last_state(key))
+            .collect();
+
+        BuildStatusState::aggregate(&child_states)
+    }
+
+    /// The last state successfully posted for a key by this reporter.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the build
+    ///
+    /// # Returns
+    ///
+    /// The last posted state, or `None` if nothing was posted for the key.
+    pub fn last_state(&self, key: &str) -> Option<BuildStatusState> {
+        self.last_states
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(key)
+            .cloned()
+    }
+}
+
+impl Api {
+    /// Creates a builder for a reporter that posts the build statuses of a commit.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `commit_id` - The ID of the commit to post the build statuses for
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring the reporter
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::new;
+    /// use bitbucket_server_rs::api::build_status::BuildStatusState;
+    /// use bitbucket_server_rs::api::build_status_post::BuildStatusPostPayload;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let reporter = client
+    ///         .api()
+    ///         .build_reporter("PROJECT_KEY", "REPOSITORY_SLUG", "COMMIT_ID")
+    ///         .valid_states(true)
+    ///         .build()?;
+    ///
+    ///     let mut build_status = BuildStatusPostPayload {
+    ///         key: "build-123".to_string(),
+    ///         state: BuildStatusState::InProgress,
+    ///         url: "https://ci.example.com/build/123".to_string(),
+    ///         ..Default::default()
+    ///     };
+    ///     reporter.post(&build_status).await?;
+    ///
+    ///     build_status.state = BuildStatusState::Successful;
+    ///     reporter.post(&build_status).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * Only the states posted through the reporter are tracked; statuses posted by other
+    ///   clients are not read back.
+    /// * Use [`BuildReporter::post_child`] and [`BuildReporter::parent_state`] to report the
+    ///   jobs of a build matrix under a parent build.
+    pub fn build_reporter(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        commit_id: &str,
+    ) -> BuildReporterBuilder {
+        let mut builder = BuildReporterBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .commit_id(commit_id.to_string());
+        builder
+    }
+}
@@ -55,6 +55,92 @@ impl From<String> for BuildStatusState {
     }
 }
 
+impl BuildStatusState {
+    /// Check whether the state is final, i.e. the build has finished.
+    ///
+    /// # Returns
+    ///
+    /// `true` for `Successful`, `Failed` and `Cancelled`.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            BuildStatusState::Successful | BuildStatusState::Failed | BuildStatusState::Cancelled
+        )
+    }
+
+    /// Check whether a build in this state can move to another state.
+    ///
+    /// A build that hasn't finished can move to any state, and a finished build can only move
+    /// to another final state (e.g. a failed build being marked successful). A finished build
+    /// moving back to `InProgress` or `Unknown` is rejected; re-runs should post under a new key
+    /// or build number.
+    ///
+    /// # Arguments
+    ///
+    /// * `next` - The state to move to
+    ///
+    /// # Returns
+    ///
+    /// `true` if the transition is allowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::api::build_status::BuildStatusState;
+    ///
+    /// assert!(BuildStatusState::InProgress.can_transition_to(&BuildStatusState::Successful));
+    /// assert!(!BuildStatusState::Successful.can_transition_to(&BuildStatusState::InProgress));
+    /// ```
+    pub fn can_transition_to(&self, next: &BuildStatusState) -> bool {
+        !self.is_terminal() || next.is_terminal()
+    }
+
+    /// Compute the state of a parent build from the states of its child builds.
+    ///
+    /// The rules are applied in order:
+    ///
+    /// 1. No children: `Unknown`.
+    /// 2. Any child `Failed`: `Failed`, even if others are still running.
+    /// 3. Any child `InProgress` or `Unknown` (not started yet): `InProgress`.
+    /// 4. Any child `Cancelled`: `Cancelled`.
+    /// 5. Otherwise, all children are `Successful`: `Successful`.
+    ///
+    /// # Arguments
+    ///
+    /// * `children` - The states of the child builds
+    ///
+    /// # Returns
+    ///
+    /// The state of the parent build.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::api::build_status::BuildStatusState;
+    ///
+    /// let children = [BuildStatusState::Successful, BuildStatusState::InProgress];
+    /// assert_eq!(BuildStatusState::aggregate(&children), BuildStatusState::InProgress);
+    /// ```
+    pub fn aggregate<'a>(
+        children: impl IntoIterator<Item = &'a BuildStatusState>,
+    ) -> BuildStatusState {
+        let children: Vec<&BuildStatusState> = children.into_iter().collect();
+        let any = |state: BuildStatusState| children.iter().any(|child| **child == state);
+
+        if children.is_empty() {
+            BuildStatusState::Unknown
+        } else if any(BuildStatusState::Failed) {
+            BuildStatusState::Failed
+        } else if any(BuildStatusState::InProgress) || any(BuildStatusState::Unknown) {
+            BuildStatusState::InProgress
+        } else if any(BuildStatusState::Cancelled) {
+            BuildStatusState::Cancelled
+        } else {
+            BuildStatusState::Successful
+        }
+    }
+}
+
 /// Represents test results associated with a build.
 ///
 /// This struct contains counts of test results in different states.
@@ -95,4 +181,29 @@ mod tests {
         assert_eq!(state, BuildStatusState::Unknown);
     } // end of it_can_convert_string_to_state
 
+    #[test]
+    fn it_can_check_transitions() {
+        use BuildStatusState::*;
+
+        assert!(Unknown.can_transition_to(&InProgress));
+        assert!(InProgress.can_transition_to(&InProgress));
+        assert!(InProgress.can_transition_to(&Failed));
+        assert!(Failed.can_transition_to(&Successful));
+        assert!(!Successful.can_transition_to(&InProgress));
+        assert!(!Cancelled.can_transition_to(&Unknown));
+    } // end of it_can_check_transitions
+
+    #[test]
+    fn it_can_aggregate_child_states() {
+        use BuildStatusState::*;
+
+        assert_eq!(BuildStatusState::aggregate(&[]), Unknown);
+        assert_eq!(BuildStatusState::aggregate(&[Successful, Successful]), Successful);
+        assert_eq!(BuildStatusState::aggregate(&[Successful, InProgress, Failed]), Failed);
+        assert_eq!(BuildStatusState::aggregate(&[Successful, InProgress]), InProgress);
+        assert_eq!(BuildStatusState::aggregate(&[Successful, Unknown]), InProgress);
+        assert_eq!(BuildStatusState::aggregate(&[Cancelled, InProgress]), InProgress);
+        assert_eq!(BuildStatusState::aggregate(&[Successful, Cancelled]), Cancelled);
+        assert_eq!(BuildStatusState::aggregate(&[Cancelled, Failed]), Failed);
+    } // end of it_can_aggregate_child_states
 }
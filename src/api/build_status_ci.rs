@@ -0,0 +1,338 @@
+//! # Build Status from CI Environment Variables
+//!
+//! This module fills a build status payload from the environment variables CI providers set
+//! for every build, so that a CI step can report its status in a few lines. GitHub Actions,
+//! GitLab CI and Jenkins are supported; the provider is detected, or can be chosen explicitly.
+//!
+//! It is only available with the `ci` feature.
+
+use crate::api::build_status::BuildStatusState;
+use crate::api::build_status_post::BuildStatusPostPayload;
+use crate::Error;
+
+/// A CI provider whose environment variables describe the running build.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CiProvider {
+    /// GitHub Actions, detected by `GITHUB_ACTIONS=true`
+    GitHubActions,
+
+    /// GitLab CI, detected by `GITLAB_CI=true`
+    GitLab,
+
+    /// Jenkins, detected by `JENKINS_URL`
+    Jenkins,
+}
+
+impl CiProvider {
+    /// Detect the CI provider running the current process.
+    ///
+    /// # Returns
+    ///
+    /// The detected provider, or `None` outside of a supported CI provider.
+    pub fn detect() -> Option<CiProvider> {
+        Self::detect_in(&env_var)
+    }
+
+    /// The ID of the commit being built, to post the build status for.
+    ///
+    /// This is `GITHUB_SHA` on GitHub Actions, `CI_COMMIT_SHA` on GitLab CI and `GIT_COMMIT`
+    /// on Jenkins.
+    ///
+    /// # Returns
+    ///
+    /// The commit ID, or an error if its environment variable is not set.
+    pub fn commit_id(self) -> Result<String, Error> {
+        self.commit_id_in(&env_var)
+    }
+
+    /// Detect the CI provider from the given variables.
+    fn detect_in(var: &impl Fn(&str) -> Option<String>) -> Option<CiProvider> {
+        if var("GITHUB_ACTIONS").as_deref() == Some("true") {
+            Some(CiProvider::GitHubActions)
+        } else if var("GITLAB_CI").as_deref() == Some("true") {
+            Some(CiProvider::GitLab)
+        } else if var("JENKINS_URL").is_some() {
+            Some(CiProvider::Jenkins)
+        } else {
+            None
+        }
+    }
+
+    /// Read the commit ID from the given variables.
+    fn commit_id_in(self, var: &impl Fn(&str) -> Option<String>) -> Result<String, Error> {
+        match self {
+            CiProvider::GitHubActions => required(var, "GITHUB_SHA"),
+            CiProvider::GitLab => required(var, "CI_COMMIT_SHA"),
+            CiProvider::Jenkins => required(var, "GIT_COMMIT"),
+        }
+    }
+
+    /// Fill a build status payload from the given variables.
+    fn payload_in(
+        self,
+        state: BuildStatusState,
+        var: &impl Fn(&str) -> Option<String>,
+    ) -> Result<BuildStatusPostPayload, Error> {
+        let payload = match self {
+            CiProvider::GitHubActions => {
+                let workflow = required(var, "GITHUB_WORKFLOW")?;
+
+                BuildStatusPostPayload {
+                    key: format!("{}/{}", workflow, required(var, "GITHUB_JOB")?),
+                    url: format!(
+                        "{}/{}/actions/runs/{}",
+                        required(var, "GITHUB_SERVER_URL")?,
+                        required(var, "GITHUB_REPOSITORY")?,
+                        required(var, "GITHUB_RUN_ID")?
+                    ),
+                    build_number: var("GITHUB_RUN_NUMBER"),
+                    name: Some(workflow),
+                    // already fully qualified, e.g. refs/heads/main
+                    reference: var("GITHUB_REF"),
+                    ..Default::default()
+                }
+            }
+            CiProvider::GitLab => BuildStatusPostPayload {
+                key: required(var, "CI_JOB_NAME")?,
+                url: required(var, "CI_JOB_URL")?,
+                build_number: var("CI_PIPELINE_IID"),
+                name: var("CI_JOB_NAME"),
+                reference: qualified_ref(var("CI_COMMIT_BRANCH"), var("CI_COMMIT_TAG")),
+                ..Default::default()
+            },
+            CiProvider::Jenkins => BuildStatusPostPayload {
+                key: required(var, "JOB_NAME")?,
+                url: required(var, "BUILD_URL")?,
+                build_number: var("BUILD_NUMBER"),
+                name: var("JOB_NAME"),
+                // only set by multibranch pipelines
+                reference: qualified_ref(var("BRANCH_NAME"), var("TAG_NAME")),
+                ..Default::default()
+            },
+        };
+
+        Ok(BuildStatusPostPayload { state, ..payload })
+    }
+}
+
+impl BuildStatusPostPayload {
+    /// Create a build status payload from the environment variables of the detected CI
+    /// provider.
+    ///
+    /// The key, URL, build number, name and ref of the build are filled in; the payload's
+    /// fields can be overridden before posting it. Use [`CiProvider::commit_id`] for the
+    /// commit to post it for.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The state of the build
+    ///
+    /// # Returns
+    ///
+    /// The payload, or an error if no CI provider is detected or one of its required
+    /// environment variables is not set.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::api::build_status::BuildStatusState;
+    /// use bitbucket_server_rs::api::build_status_ci::CiProvider;
+    /// use bitbucket_server_rs::api::build_status_post::BuildStatusPostPayload;
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let build_status = BuildStatusPostPayload {
+    ///         description: Some("Unit tests".to_string()),
+    ///         ..BuildStatusPostPayload::from_ci_env(BuildStatusState::InProgress)?
+    ///     };
+    ///     let commit_id = CiProvider::detect().unwrap().commit_id()?;
+    ///
+    ///     client
+    ///         .api()
+    ///         .build_status_post("PROJECT_KEY", "REPOSITORY_SLUG", &commit_id, &build_status)
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_ci_env(state: BuildStatusState) -> Result<Self, Error> {
+        let provider = CiProvider::detect().ok_or_else(|| {
+            Error::Unexpected("No supported CI provider detected".to_string())
+        })?;
+
+        Self::from_ci_env_of(provider, state)
+    }
+
+    /// Create a build status payload from the environment variables of the given CI provider.
+    ///
+    /// This behaves like [`BuildStatusPostPayload::from_ci_env`], without detecting the
+    /// provider, e.g. for a Jenkins agent that also has `GITLAB_CI` set.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The CI provider whose variables to read
+    /// * `state` - The state of the build
+    ///
+    /// # Returns
+    ///
+    /// The payload, or an error if one of the provider's required environment variables is
+    /// not set.
+    pub fn from_ci_env_of(provider: CiProvider, state: BuildStatusState) -> Result<Self, Error> {
+        provider.payload_in(state, &env_var)
+    }
+}
+
+/// Read an environment variable, treating an empty value as unset.
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+/// Read a variable that must be set.
+fn required(var: &impl Fn(&str) -> Option<String>, name: &str) -> Result<String, Error> {
+    var(name).ok_or_else(|| {
+        Error::Unexpected(format!("The CI environment variable {name} is not set"))
+    })
+}
+
+/// Qualify a branch or tag name, e.g. `main` as `refs/heads/main`.
+fn qualified_ref(branch: Option<String>, tag: Option<String>) -> Option<String> {
+    branch
+        .map(|branch| format!("refs/heads/{branch}"))
+        .or_else(|| tag.map(|tag| format!("refs/tags/{tag}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn vars(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn it_reads_github_actions_variables() {
+        let var = vars(&[
+            ("GITHUB_ACTIONS", "true"),
+            ("GITHUB_WORKFLOW", "CI"),
+            ("GITHUB_JOB", "test"),
+            ("GITHUB_SERVER_URL", "https://github.com"),
+            ("GITHUB_REPOSITORY", "acme/widgets"),
+            ("GITHUB_RUN_ID", "1234"),
+            ("GITHUB_RUN_NUMBER", "56"),
+            ("GITHUB_REF", "refs/heads/main"),
+            ("GITHUB_SHA", "COMMIT_ID"),
+        ]);
+
+        let provider = CiProvider::detect_in(&var).unwrap();
+        assert_eq!(provider, CiProvider::GitHubActions);
+        assert_eq!(provider.commit_id_in(&var).unwrap(), "COMMIT_ID");
+        assert_eq!(
+            provider.payload_in(BuildStatusState::Successful, &var).unwrap(),
+            BuildStatusPostPayload {
+                key: "CI/test".to_string(),
+                state: BuildStatusState::Successful,
+                url: "https://github.com/acme/widgets/actions/runs/1234".to_string(),
+                build_number: Some("56".to_string()),
+                name: Some("CI".to_string()),
+                reference: Some("refs/heads/main".to_string()),
+                ..Default::default()
+            }
+        );
+    } // it_reads_github_actions_variables
+
+    #[test]
+    fn it_reads_gitlab_variables() {
+        let var = vars(&[
+            ("GITLAB_CI", "true"),
+            ("CI_JOB_NAME", "unit-tests"),
+            ("CI_JOB_URL", "https://gitlab.example.com/acme/widgets/-/jobs/78"),
+            ("CI_PIPELINE_IID", "9"),
+            ("CI_COMMIT_TAG", "v1.0.0"),
+            ("CI_COMMIT_SHA", "COMMIT_ID"),
+        ]);
+
+        let provider = CiProvider::detect_in(&var).unwrap();
+        assert_eq!(provider, CiProvider::GitLab);
+        assert_eq!(provider.commit_id_in(&var).unwrap(), "COMMIT_ID");
+        assert_eq!(
+            provider.payload_in(BuildStatusState::Failed, &var).unwrap(),
+            BuildStatusPostPayload {
+                key: "unit-tests".to_string(),
+                state: BuildStatusState::Failed,
+                url: "https://gitlab.example.com/acme/widgets/-/jobs/78".to_string(),
+                build_number: Some("9".to_string()),
+                name: Some("unit-tests".to_string()),
+                reference: Some("refs/tags/v1.0.0".to_string()),
+                ..Default::default()
+            }
+        );
+    } // it_reads_gitlab_variables
+
+    #[test]
+    fn it_reads_jenkins_variables() {
+        let var = vars(&[
+            ("JENKINS_URL", "https://jenkins.example.com/"),
+            ("JOB_NAME", "widgets/main"),
+            ("BUILD_URL", "https://jenkins.example.com/job/widgets/job/main/12/"),
+            ("BUILD_NUMBER", "12"),
+            ("BRANCH_NAME", "main"),
+            ("GIT_COMMIT", "COMMIT_ID"),
+        ]);
+
+        let provider = CiProvider::detect_in(&var).unwrap();
+        assert_eq!(provider, CiProvider::Jenkins);
+        assert_eq!(provider.commit_id_in(&var).unwrap(), "COMMIT_ID");
+        assert_eq!(
+            provider.payload_in(BuildStatusState::InProgress, &var).unwrap(),
+            BuildStatusPostPayload {
+                key: "widgets/main".to_string(),
+                state: BuildStatusState::InProgress,
+                url: "https://jenkins.example.com/job/widgets/job/main/12/".to_string(),
+                build_number: Some("12".to_string()),
+                name: Some("widgets/main".to_string()),
+                reference: Some("refs/heads/main".to_string()),
+                ..Default::default()
+            }
+        );
+    } // it_reads_jenkins_variables
+
+    #[test]
+    fn it_reads_the_variables_of_an_explicit_provider() {
+        let var = vars(&[
+            ("GITLAB_CI", "true"),
+            ("JENKINS_URL", "https://jenkins.example.com/"),
+            ("JOB_NAME", "widgets"),
+            ("BUILD_URL", "https://jenkins.example.com/job/widgets/3/"),
+        ]);
+
+        assert_eq!(CiProvider::detect_in(&var), Some(CiProvider::GitLab));
+
+        let payload = CiProvider::Jenkins
+            .payload_in(BuildStatusState::Successful, &var)
+            .unwrap();
+        assert_eq!(payload.key, "widgets");
+        assert_eq!(payload.reference, None);
+    } // it_reads_the_variables_of_an_explicit_provider
+
+    #[test]
+    fn it_reports_missing_variables() {
+        let var = vars(&[("GITHUB_ACTIONS", "true"), ("GITHUB_WORKFLOW", "CI")]);
+
+        let error = CiProvider::GitHubActions
+            .payload_in(BuildStatusState::Successful, &var)
+            .unwrap_err();
+        assert!(error.to_string().contains("GITHUB_JOB"));
+
+        assert_eq!(CiProvider::detect_in(&vars(&[])), None);
+    } // it_reports_missing_variables
+}
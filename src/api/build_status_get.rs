@@ -6,11 +6,17 @@
 
 use crate::api::build_status::{BuildStatusState, TestResults};
 use crate::api::Api;
-use crate::client::{ApiRequest, ApiResponse, Client};
-use chrono::{serde::ts_seconds_option, DateTime, Utc};
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::timestamp::{ts_milliseconds_option, Timestamp};
+use crate::Error;
 use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
 
 /// Represents the build status associated with a commit.
 ///
@@ -39,12 +45,16 @@ pub struct BuildStatus {
     pub build_number: Option<String>,
 
     /// The date when the build status was last updated.
-    #[serde(skip_serializing_if = "Option::is_none", with = "ts_seconds_option")]
-    pub updated_date: Option<DateTime<Utc>>,
+    ///
+    /// Sent by the API as milliseconds since the epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "ts_milliseconds_option")]
+    pub updated_date: Option<Timestamp>,
 
     /// The date when the build status was created.
-    #[serde(skip_serializing_if = "Option::is_none", with = "ts_seconds_option")]
-    pub created_date: Option<DateTime<Utc>>,
+    ///
+    /// Sent by the API as milliseconds since the epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "ts_milliseconds_option")]
+    pub created_date: Option<Timestamp>,
 
     /// A description of the build result.
     ///
@@ -81,12 +91,122 @@ pub struct BuildStatus {
     /// This provides test result information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub test_results: Option<TestResults>,
+
+    /// The fields of the response that are not modeled above, keyed by their JSON name.
+    ///
+    /// This gives access to fields added by newer servers or plugins without a crate update.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl BuildStatus {
+    /// Keep only the most recent build status of each key, e.g. the last rerun of a build.
+    ///
+    /// A build status is more recent than another with the same key if it was updated later;
+    /// statuses updated at the same time (or without a date) are ordered by their build
+    /// number, parsed as a number. A status with a build number that isn't a number is older
+    /// than one with a numeric build number.
+    ///
+    /// # Arguments
+    ///
+    /// * `statuses` - The build statuses, e.g. all the pages of [`Api::build_status_list`]
+    ///
+    /// # Returns
+    ///
+    /// The most recent build status of each key, in the order the keys first appear.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::build_status_get::BuildStatus;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let page = client
+    ///         .api()
+    ///         .build_status_list("COMMIT_ID")
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(page) = page {
+    ///         for status in BuildStatus::latest_per_key(page.values) {
+    ///             println!("{}: {:?}", status.key, status.state);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn latest_per_key(statuses: impl IntoIterator<Item = BuildStatus>) -> Vec<BuildStatus> {
+        let mut latest: Vec<BuildStatus> = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+
+        for status in statuses {
+            match positions.get(&status.key) {
+                Some(&position) => {
+                    if status.recency() > latest[position].recency() {
+                        latest[position] = status;
+                    }
+                }
+                None => {
+                    positions.insert(status.key.clone(), latest.len());
+                    latest.push(status);
+                }
+            }
+        }
+
+        latest
+    }
+
+    /// Sort build statuses from the most recent to the oldest, so that the first one is the
+    /// latest.
+    ///
+    /// Build statuses are ordered like in [`BuildStatus::latest_per_key`]: by update date,
+    /// then by build number. Build statuses without an update date come last. The sort is
+    /// stable, so the server's order is kept between equally recent build statuses.
+    ///
+    /// # Arguments
+    ///
+    /// * `statuses` - The build statuses to sort
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::api::build_status_get::BuildStatus;
+    ///
+    /// let mut statuses: Vec<BuildStatus> = serde_json::from_str(r#"[
+    ///     {"key": "ci", "state": "FAILED", "url": "https://ci/1", "updatedDate": 1738198900000},
+    ///     {"key": "ci", "state": "SUCCESSFUL", "url": "https://ci/2", "updatedDate": 1738199000000}
+    /// ]"#)?;
+    ///
+    /// BuildStatus::sort_newest_first(&mut statuses);
+    /// assert_eq!(statuses[0].url, "https://ci/2");
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn sort_newest_first(statuses: &mut [BuildStatus]) {
+        statuses.sort_by_key(|status| Reverse(status.recency()));
+    }
+
+    /// The sort key of [`BuildStatus::latest_per_key`] and [`BuildStatus::sort_newest_first`]:
+    /// the update date, then the build number.
+    fn recency(&self) -> (Option<Timestamp>, Option<u64>) {
+        (
+            self.updated_date,
+            self.build_number
+                .as_ref()
+                .and_then(|build_number| build_number.trim().parse().ok()),
+        )
+    }
 }
 
 /// Request builder for retrieving build status information.
 ///
 /// This struct is used to build and send requests to retrieve build status information.
-#[derive(Debug, Default, Builder)]
+#[derive(Clone, Debug, Default, Builder)]
 pub struct BuildStatusGet {
     /// The HTTP client to use for making requests
     pub client: Client,
@@ -105,32 +225,77 @@ pub struct BuildStatusGet {
     pub key: Option<String>,
 }
 
-impl ApiRequest for BuildStatusGet {
-    type Output = BuildStatus;
+impl BuildStatusGet {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
 
-    /// Sends the request to retrieve build status information.
+impl DescribeRequest for BuildStatusGet {
+    /// Builds the request, without sending it.
     ///
     /// # Returns
     ///
-    /// A Result containing either the build status or an error.
-    async fn send(&self) -> ApiResponse<Self::Output> {
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
         let request_uri = format!(
             "api/latest/projects/{}/repos/{}/commits/{}/builds",
             self.project_key, self.repository_slug, self.commit_id
         );
 
-        let mut params = HashMap::new();
+        let mut params = BTreeMap::new();
 
         if let Some(key) = &self.key {
             params.insert("key".to_string(), key.clone());
         }
 
         self.client
-            .get::<BuildStatusGet>(&request_uri, Some(params))
-            .await
+            .build_request(Method::GET, &request_uri, Some(params), None)
+    }
+}
+
+impl ApiRequest for BuildStatusGet {
+    type Output = BuildStatus;
+    const ENDPOINT_NAME: &'static str = "build_status_get";
+
+    /// Sends the request to retrieve build status information.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the build status or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
     }
 }
 
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for BuildStatusGet {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(BuildStatusGet);
+
 impl Api {
     /// Creates a request builder for retrieving build status information.
     ///
@@ -202,6 +367,7 @@ impl Api {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::timestamp::epoch_seconds;
     use serde_json::from_str;
 
     #[test]
@@ -211,8 +377,8 @@ mod tests {
             "state": "SUCCESSFUL",
             "url": "https://my-build-status.com/path",
             "buildNumber": "9",
-            "createdDate": 1738198923,
-            "updatedDate": 1738198924,
+            "createdDate": 1738198923000,
+            "updatedDate": 1738198924000,
             "duration": 12,
             "description": "DESCRIPTION",
             "name": "NAME",
@@ -231,8 +397,8 @@ mod tests {
         assert_eq!(build_status.state, BuildStatusState::Successful);
         assert_eq!(build_status.url, "https://my-build-status.com/path");
         assert_eq!(build_status.build_number.unwrap(), "9");
-        assert_eq!(build_status.created_date.unwrap().timestamp(), 1738198923);
-        assert_eq!(build_status.updated_date.unwrap().timestamp(), 1738198924);
+        assert_eq!(epoch_seconds(&build_status.created_date.unwrap()), 1738198923);
+        assert_eq!(epoch_seconds(&build_status.updated_date.unwrap()), 1738198924);
         assert_eq!(build_status.duration.unwrap(), 12);
         assert_eq!(build_status.description.unwrap(), "DESCRIPTION");
         assert_eq!(build_status.name.unwrap(), "NAME");
@@ -247,4 +413,108 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn it_deserializes_dates_in_milliseconds() {
+        let json = r#"{
+            "key": "KEY",
+            "state": "SUCCESSFUL",
+            "url": "https://my-build-status.com/path",
+            "createdDate": 1738198923456,
+            "updatedDate": 1738198924789
+        }"#;
+
+        let build_status: BuildStatus = from_str(json).unwrap();
+
+        assert_eq!(
+            build_status.created_date.unwrap().to_rfc3339(),
+            "2025-01-30T01:02:03.456+00:00"
+        );
+        assert_eq!(
+            build_status.updated_date.unwrap().to_rfc3339(),
+            "2025-01-30T01:02:04.789+00:00"
+        );
+        assert_eq!(
+            serde_json::to_value(&build_status).unwrap()["updatedDate"],
+            serde_json::json!(1738198924789i64)
+        );
+    }
+
+    #[test]
+    fn it_truncates_dates_to_seconds() {
+        let json = r#"{
+            "key": "KEY",
+            "state": "SUCCESSFUL",
+            "url": "https://my-build-status.com/path",
+            "updatedDate": 1738198924789
+        }"#;
+
+        let build_status: BuildStatus = from_str(json).unwrap();
+
+        // with or without chrono, the whole seconds are the same
+        assert_eq!(epoch_seconds(&build_status.updated_date.unwrap()), 1738198924);
+    }
+
+    #[test]
+    fn it_can_deserialize_null_or_absent_dates() {
+        let json = r#"{
+            "key": "KEY",
+            "state": "INPROGRESS",
+            "url": "https://my-build-status.com/path",
+            "updatedDate": null
+        }"#;
+
+        let build_status: BuildStatus = from_str(json).unwrap();
+
+        assert_eq!(build_status.updated_date, None);
+        assert_eq!(build_status.created_date, None);
+    }
+
+    #[test]
+    fn it_keeps_unmodeled_fields() {
+        let json = r#"{
+            "key": "KEY",
+            "state": "SUCCESSFUL",
+            "url": "https://my-build-status.com/path",
+            "buildNumber": "9",
+            "logsUrl": "https://my-build-status.com/path/logs"
+        }"#;
+
+        let build_status: BuildStatus = from_str(json).unwrap();
+
+        assert_eq!(build_status.build_number.as_deref(), Some("9"));
+        assert_eq!(build_status.extra.len(), 1);
+        assert_eq!(
+            build_status.extra["logsUrl"],
+            "https://my-build-status.com/path/logs"
+        );
+        assert_eq!(
+            serde_json::to_value(&build_status).unwrap(),
+            from_str::<serde_json::Value>(json).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_keeps_latest_build_status_per_key() {
+        let json = r#"[
+            {"key": "ci", "state": "FAILED", "url": "https://ci/1", "buildNumber": "9", "updatedDate": 1738198900000},
+            {"key": "lint", "state": "SUCCESSFUL", "url": "https://lint/1", "buildNumber": "1", "updatedDate": 1738198900000},
+            {"key": "ci", "state": "SUCCESSFUL", "url": "https://ci/3", "buildNumber": "11", "updatedDate": 1738199000000},
+            {"key": "ci", "state": "INPROGRESS", "url": "https://ci/2", "buildNumber": "10", "updatedDate": 1738198950000},
+            {"key": "deploy", "state": "FAILED", "url": "https://deploy/1", "buildNumber": "9"},
+            {"key": "deploy", "state": "SUCCESSFUL", "url": "https://deploy/2", "buildNumber": "10"}
+        ]"#;
+
+        let statuses: Vec<BuildStatus> = from_str(json).unwrap();
+        let latest = BuildStatus::latest_per_key(statuses);
+
+        let keys: Vec<&str> = latest.iter().map(|status| status.key.as_str()).collect();
+        assert_eq!(keys, ["ci", "lint", "deploy"]);
+        assert_eq!(latest[0].url, "https://ci/3");
+        assert_eq!(latest[0].state, BuildStatusState::Successful);
+        assert_eq!(latest[1].url, "https://lint/1");
+        // without dates, build number 10 is newer than 9, not older as text
+        assert_eq!(latest[2].url, "https://deploy/2");
+    }
 }
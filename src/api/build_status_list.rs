@@ -0,0 +1,204 @@
+//! # Build Status List API
+//!
+//! This module provides functionality to list all the build statuses of a commit in Bitbucket
+//! Server, across build keys. Use [`Api::build_status_get`] to get a single build status by key.
+
+use crate::api::build_status_get::BuildStatus;
+use crate::api::common::Page;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{
+    encode_path_segment, ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest,
+};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The order of the build statuses of a commit.
+///
+/// When serialized, it uses the lowercase names of the `orderBy` query parameter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BuildStatusOrder {
+    /// The most recently updated build statuses first
+    Newest,
+
+    /// The least recently updated build statuses first
+    Oldest,
+
+    /// Grouped by the state of the build statuses
+    Status,
+}
+
+impl BuildStatusOrder {
+    /// The name of the order, as used in query parameters.
+    ///
+    /// # Returns
+    ///
+    /// `newest`, `oldest` or `status`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BuildStatusOrder::Newest => "newest",
+            BuildStatusOrder::Oldest => "oldest",
+            BuildStatusOrder::Status => "status",
+        }
+    }
+}
+
+/// Request builder for listing the build statuses of a commit.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct BuildStatusList {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The full ID of the commit
+    commit_id: String,
+
+    /// The order of the build statuses. If not passed, the newest build statuses come first.
+    #[builder(setter(into, strip_option), default)]
+    order_by: Option<BuildStatusOrder>,
+
+    /// Start number for the page (inclusive). If not passed, first page is assumed.
+    #[builder(setter(into, strip_option), default)]
+    start: Option<u64>,
+
+    /// Number of items to return. If not passed, a page size of 25 is used.
+    #[builder(setter(into, strip_option), default)]
+    limit: Option<u64>,
+}
+
+impl BuildStatusList {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for BuildStatusList {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "build-status/latest/commits/{}",
+            encode_path_segment(&self.commit_id)
+        );
+
+        let mut params = BTreeMap::new();
+
+        if let Some(order_by) = &self.order_by {
+            params.insert("orderBy".to_string(), order_by.as_str().to_string());
+        }
+        if let Some(start) = &self.start {
+            params.insert("start".to_string(), start.to_string());
+        }
+        if let Some(limit) = &self.limit {
+            params.insert("limit".to_string(), limit.to_string());
+        }
+
+        self.client
+            .build_request(Method::GET, &request_uri, Some(params), None)
+    }
+}
+
+impl ApiRequest for BuildStatusList {
+    type Output = Page<BuildStatus>;
+    const ENDPOINT_NAME: &'static str = "build_status_list";
+
+    /// Sends the request to list the build statuses.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either a page of build statuses or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for BuildStatusList {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(BuildStatusList);
+
+impl Api {
+    /// Creates a request builder for listing all the build statuses of a commit.
+    ///
+    /// # Arguments
+    ///
+    /// * `commit_id` - The full ID of the commit
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let response = client
+    ///         .api()
+    ///         .build_status_list("8d51122def5632836d1cb1026e879069e10a1e13")
+    ///         .limit(100u64)
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(page) = response {
+    ///         for build_status in page.values {
+    ///             println!("{}: {:?}", build_status.key, build_status.state);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * Build statuses are stored per commit, not per repository, so no project or
+    ///   repository is needed.
+    /// * To read the current state from the first build status whatever the server's order,
+    ///   sort the listed build statuses with [`BuildStatus::sort_newest_first`].
+    ///
+    /// See the [Bitbucket Server REST API documentation](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-build-status/#api-build-status-latest-commits-commitid-get)
+    pub fn build_status_list(self, commit_id: &str) -> BuildStatusListBuilder {
+        let mut builder = BuildStatusListBuilder::default();
+        builder
+            .client(self.client.clone())
+            .commit_id(commit_id.to_string());
+        builder
+    }
+}
@@ -5,9 +5,15 @@
 //! to integrate CI/CD systems with Bitbucket Server.
 
 use crate::api::build_status::{BuildStatusState, TestResults};
+use crate::api::build_status_get::BuildStatus;
 use crate::api::Api;
-use crate::client::{ApiRequest, ApiResponse, Client};
-use chrono::{serde::ts_seconds_option, DateTime, Utc};
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use crate::timestamp::{ts_milliseconds_option, Timestamp};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 /// The payload for posting a build status update.
@@ -38,9 +44,10 @@ pub struct BuildStatusPostPayload {
     
     /// The date when the build status was added.
     ///
+    /// Serialized as milliseconds since the epoch, as expected by the API.
     /// If not provided, the current time will be used.
-    #[serde(skip_serializing_if = "Option::is_none", with = "ts_seconds_option")]
-    pub date_added: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "ts_milliseconds_option")]
+    pub date_added: Option<Timestamp>,
     
     /// A description of the build result.
     ///
@@ -82,7 +89,7 @@ pub struct BuildStatusPostPayload {
 /// Request builder for posting a build status update.
 ///
 /// This struct is used to build and send requests to post build status updates.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct BuildStatusPost {
     /// The HTTP client to use for making requests
     client: Client,
@@ -100,9 +107,96 @@ pub struct BuildStatusPost {
     build_status: BuildStatusPostPayload,
 }
 
+impl BuildStatusPost {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+
+    /// Posts the build status, then reads it back by key to return the record stored by the
+    /// server.
+    ///
+    /// The posting endpoint responds with no content; the follow-up GET confirms the write
+    /// landed and returns the server-assigned fields, such as `createdDate` and `updatedDate`.
+    /// Use [`ApiRequest::send`] to skip it.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the stored build status or an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::new;
+    /// use bitbucket_server_rs::api::build_status::BuildStatusState;
+    /// use bitbucket_server_rs::api::build_status_post::BuildStatusPostPayload;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let build_status = BuildStatusPostPayload {
+    ///         key: "build-123".to_string(),
+    ///         state: BuildStatusState::Successful,
+    ///         url: "https://ci.example.com/build/123".to_string(),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let stored = client
+    ///         .api()
+    ///         .build_status_post("PROJECT_KEY", "REPOSITORY_SLUG", "COMMIT_ID", &build_status)
+    ///         .send_and_get()
+    ///         .await?;
+    ///
+    ///     println!("Stored at {:?}", stored.and_then(|stored| stored.updated_date));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn send_and_get(&self) -> ApiResponse<BuildStatus> {
+        self.send().await?;
+
+        Api {
+            client: self.client.clone(),
+        }
+        .build_status_get(&self.project_key, &self.commit_id, &self.repository_slug)
+        .key(self.build_status.key.clone())
+        .build()
+        .map_err(Error::from_builder)?
+        .send()
+        .await
+    }
+}
+
+impl DescribeRequest for BuildStatusPost {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/commits/{}/builds",
+            self.project_key, self.repository_slug, self.commit_id
+        );
+
+        self.client.build_request(
+            Method::POST,
+            &request_uri,
+            None,
+            Some(&self.client.to_body(&self.build_status)),
+        )
+    }
+}
+
 impl ApiRequest for BuildStatusPost {
     // response has no content
     type Output = ();
+    const ENDPOINT_NAME: &'static str = "build_status_post";
 
     /// Sends the request to post a build status update.
     ///
@@ -110,20 +204,33 @@ impl ApiRequest for BuildStatusPost {
     ///
     /// A Result indicating success or failure.
     async fn send(&self) -> ApiResponse<Self::Output> {
-        let request_uri = format!(
-            "api/latest/projects/{}/repos/{}/commits/{}/builds",
-            self.project_key, self.repository_slug, self.commit_id
-        );
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
 
-        self.client
-            .post::<Self>(
-                &request_uri,
-                &serde_json::to_string(&self.build_status).unwrap(),
-            )
-            .await
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for BuildStatusPost {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
     }
 }
 
+crate::client::impl_with_client!(BuildStatusPost);
+
 impl Api {
     /// Creates a request to post a build status update for a commit.
     ///
@@ -206,6 +313,7 @@ impl Api {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::timestamp::from_epoch_seconds;
 
     #[test]
     fn it_can_serialize() {
@@ -214,11 +322,7 @@ mod tests {
             state: BuildStatusState::Successful,
             url: "URL".to_string(),
             build_number: Some("1".to_string()),
-            date_added: Some(
-                chrono::DateTime::parse_from_rfc3339("2025-01-30T01:02:03Z")
-                    .unwrap()
-                    .with_timezone(&Utc),
-            ),
+            date_added: from_epoch_seconds(1738198923),
             description: Some("DESCRIPTION".to_string()),
             duration: Some(12),
             name: Some("NAME".to_string()),
@@ -234,10 +338,26 @@ mod tests {
         let json = serde_json::to_string(&build_status).unwrap();
         assert_eq!(
             json,
-            r#"{"key":"KEY","state":"SUCCESSFUL","url":"URL","buildNumber":"1","dateAdded":1738198923,"description":"DESCRIPTION","duration":12,"name":"NAME","parent":"PARENT","ref":"REF","testResults":{"failed":2,"successful":3,"skipped":1}}"#
+            r#"{"key":"KEY","state":"SUCCESSFUL","url":"URL","buildNumber":"1","dateAdded":1738198923000,"description":"DESCRIPTION","duration":12,"name":"NAME","parent":"PARENT","ref":"REF","testResults":{"failed":2,"successful":3,"skipped":1}}"#
         );
     } // it_can_serialize
 
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn it_serializes_date_added_in_milliseconds() {
+        let build_status = BuildStatusPostPayload {
+            date_added: Some(
+                chrono::DateTime::parse_from_rfc3339("2025-01-30T01:02:03.456Z")
+                    .unwrap()
+                    .with_timezone(&chrono::Utc),
+            ),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(&build_status).unwrap();
+        assert_eq!(json["dateAdded"], serde_json::json!(1738198923456i64));
+    } // it_serializes_date_added_in_milliseconds
+
     #[test]
     fn it_can_serialize_partially() {
         let build_status = BuildStatusPostPayload {
@@ -0,0 +1,158 @@
+//! # Build Status POST for Pull Request API
+//!
+//! This module provides functionality to post a build status to the head commit of a pull
+//! request, i.e. the latest commit of its source branch, without resolving that commit first.
+
+use crate::api::build_status_post::BuildStatusPostPayload;
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+
+/// Request for posting a build status to the latest commit of a pull request.
+#[derive(Clone, Debug)]
+pub struct BuildStatusPostForPr {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+
+    /// The build status payload to post
+    build_status: BuildStatusPostPayload,
+}
+
+impl ApiRequest for BuildStatusPostForPr {
+    // response has no content
+    type Output = ();
+    const ENDPOINT_NAME: &'static str = "build_status_post_for_pr";
+
+    /// Sends the requests to resolve the pull request's latest commit and post the build status.
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or failure.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the requests, keeping the headers of the build status response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        let api = Api {
+            client: self.client.clone(),
+        };
+
+        let pull_request = api
+            .pull_request_get(
+                &self.project_key,
+                &self.repository_slug,
+                &self.pull_request_id,
+            )
+            .build()
+            .map_err(Error::from_builder)?
+            .send()
+            .await?
+            .ok_or_else(|| {
+                Error::Unexpected(format!(
+                    "Pull request {} was not returned",
+                    self.pull_request_id
+                ))
+            })?;
+
+        let mut build_status = self.build_status.clone();
+        if build_status.reference.is_none() {
+            build_status.reference = Some(pull_request.from_ref.id);
+        }
+
+        Api {
+            client: self.client.clone(),
+        }
+        .build_status_post(
+            &self.project_key,
+            &self.repository_slug,
+            &pull_request.from_ref.latest_commit,
+            &build_status,
+        )
+        .send_with_headers()
+        .await
+    }
+}
+
+crate::client::impl_with_client!(BuildStatusPostForPr);
+
+impl Api {
+    /// Creates a request to post a build status to the latest commit of a pull request.
+    ///
+    /// The pull request is retrieved with [`Api::pull_request_get`], then the build status is
+    /// posted with [`Api::build_status_post`] to the latest commit of its source branch. If the
+    /// payload has no `reference`, the source branch is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    /// * `build_status` - The build status payload to post
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::build_status::BuildStatusState;
+    /// use bitbucket_server_rs::api::build_status_post::BuildStatusPostPayload;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let build_status = BuildStatusPostPayload {
+    ///         key: "build-123".to_string(),
+    ///         state: BuildStatusState::InProgress,
+    ///         url: "https://ci.example.com/build/123".to_string(),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     client
+    ///         .api()
+    ///         .build_status_post_for_pr("PROJECT_KEY", "REPOSITORY_SLUG", "123", &build_status)
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * If new commits are pushed between the two requests, the build status is posted to the
+    ///   commit that was the latest when the pull request was retrieved.
+    pub fn build_status_post_for_pr(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+        build_status: &BuildStatusPostPayload,
+    ) -> BuildStatusPostForPr {
+        BuildStatusPostForPr {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            pull_request_id: pull_request_id.to_owned(),
+            build_status: build_status.to_owned(),
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! # Build Status POST for Pull Request Commits API
+//!
+//! This module provides functionality to post the same build status to every commit a pull
+//! request introduces, for CI that marks each commit rather than only the head commit. Commits
+//! that already carry a build status with the same key and state are skipped, so reruns on
+//! large pull requests don't repeat their writes.
+
+use crate::api::build_status_post::BuildStatusPostPayload;
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+/// The commits a build status was posted to, and the ones it was already on.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildStatusBatch {
+    /// The IDs of the commits the build status was posted to
+    pub posted: Vec<String>,
+
+    /// The IDs of the commits that already had a build status with the same key and state
+    pub skipped: Vec<String>,
+}
+
+/// Request for posting a build status to all the commits a pull request introduces.
+#[derive(Clone, Debug)]
+pub struct BuildStatusPostForPrCommits {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+
+    /// The build status payload to post
+    build_status: BuildStatusPostPayload,
+}
+
+impl BuildStatusPostForPrCommits {
+    /// Checks whether a commit already has a build status with the payload's key and state.
+    ///
+    /// # Arguments
+    ///
+    /// * `commit_id` - The ID of the commit
+    async fn is_posted(&self, commit_id: &str) -> Result<bool, Error> {
+        let response = Api {
+            client: self.client.clone(),
+        }
+        .build_status_get(&self.project_key, commit_id, &self.repository_slug)
+        .key(self.build_status.key.clone())
+        .build()
+        .map_err(Error::from_builder)?
+        .send()
+        .await;
+
+        match response {
+            Ok(build_status) => Ok(build_status
+                .is_some_and(|build_status| build_status.state == self.build_status.state)),
+            // no build status with this key yet
+            Err(Error::HttpClientError { status: 404, .. }) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl ApiRequest for BuildStatusPostForPrCommits {
+    type Output = BuildStatusBatch;
+    const ENDPOINT_NAME: &'static str = "build_status_post_for_pr_commits";
+
+    /// Sends the requests to post the build status to the commits of the pull request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the commits posted to and skipped, or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the requests; as several requests are made, the headers are empty.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        let commits = Api {
+            client: self.client.clone(),
+        }
+        .pull_request_incoming_commits(
+            &self.project_key,
+            &self.repository_slug,
+            &self.pull_request_id,
+        )
+        .send()
+        .await?
+        .unwrap_or_default();
+
+        let mut batch = BuildStatusBatch::default();
+        for commit in commits {
+            if self.is_posted(&commit.id).await? {
+                batch.skipped.push(commit.id);
+                continue;
+            }
+
+            Api {
+                client: self.client.clone(),
+            }
+            .build_status_post(
+                &self.project_key,
+                &self.repository_slug,
+                &commit.id,
+                &self.build_status,
+            )
+            .send()
+            .await?;
+            batch.posted.push(commit.id);
+        }
+
+        Ok((Some(batch), HeaderMap::new()))
+    }
+}
+
+crate::client::impl_with_client!(BuildStatusPostForPrCommits);
+
+impl Api {
+    /// Creates a request to post a build status to every commit a pull request introduces.
+    ///
+    /// The commits are listed with [`Api::pull_request_incoming_commits`]. For each of them,
+    /// the build status with the payload's key is read with [`Api::build_status_get`], and the
+    /// payload is posted with [`Api::build_status_post`] unless that build status is already in
+    /// the payload's state.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    /// * `build_status` - The build status payload to post
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::build_status::BuildStatusState;
+    /// use bitbucket_server_rs::api::build_status_post::BuildStatusPostPayload;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let build_status = BuildStatusPostPayload {
+    ///         key: "lint".to_string(),
+    ///         state: BuildStatusState::Successful,
+    ///         url: "https://ci.example.com/build/123".to_string(),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let batch = client
+    ///         .api()
+    ///         .build_status_post_for_pr_commits("PROJECT_KEY", "REPOSITORY_SLUG", "123", &build_status)
+    ///         .send()
+    ///         .await?
+    ///         .unwrap_or_default();
+    ///
+    ///     println!("Posted to {} commits, {} already green", batch.posted.len(), batch.skipped.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * The commits are handled one at a time; on the first error, the commits before it have
+    ///   been posted to and the error is returned.
+    pub fn build_status_post_for_pr_commits(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+        build_status: &BuildStatusPostPayload,
+    ) -> BuildStatusPostForPrCommits {
+        BuildStatusPostForPrCommits {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            pull_request_id: pull_request_id.to_owned(),
+            build_status: build_status.to_owned(),
+        }
+    }
+}
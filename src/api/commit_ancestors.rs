@@ -0,0 +1,166 @@
+//! # Commit Ancestors API
+//!
+//! This module provides a way to walk the history of a commit in Bitbucket Server: starting
+//! from a commit, its parents are fetched generation by generation, following every parent of
+//! merge commits, up to a bounded depth.
+
+use crate::api::common::Commit;
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+use reqwest::header::HeaderMap;
+use std::collections::HashSet;
+
+/// The maximum number of generations walked by [`Api::commit_ancestors`].
+pub const MAX_ANCESTOR_DEPTH: u32 = 100;
+
+/// Request for retrieving the ancestors of a commit.
+#[derive(Clone, Debug)]
+pub struct CommitAncestors {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the commit to start from
+    commit_id: String,
+
+    /// The number of generations to walk
+    depth: u32,
+}
+
+impl CommitAncestors {
+    /// Fetches a commit.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the commit or an error if it doesn't exist.
+    async fn fetch(&self, commit_id: &str) -> Result<Commit, Error> {
+        Api {
+            client: self.client.clone(),
+        }
+        .commit_get(&self.project_key, &self.repository_slug, commit_id)
+        .send()
+        .await?
+        .ok_or_else(|| Error::Unexpected(format!("Commit {commit_id} was not returned")))
+    }
+}
+
+impl ApiRequest for CommitAncestors {
+    type Output = Vec<Commit>;
+    const ENDPOINT_NAME: &'static str = "commit_ancestors";
+
+    /// Sends the requests to walk the ancestors of the commit.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the ancestors or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the requests to walk the ancestors of the commit.
+    ///
+    /// Several requests are made, so the headers are empty.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the ancestors and empty headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        let start = self.fetch(&self.commit_id).await?;
+
+        let mut seen = HashSet::from([start.id.clone()]);
+        let mut generation = vec![start];
+        let mut ancestors = Vec::new();
+
+        for _ in 0..self.depth.min(MAX_ANCESTOR_DEPTH) {
+            let parent_ids: Vec<String> = generation
+                .iter()
+                .flat_map(|commit| &commit.parents)
+                .filter(|parent| seen.insert(parent.id.clone()))
+                .map(|parent| parent.id.clone())
+                .collect();
+
+            if parent_ids.is_empty() {
+                break;
+            }
+
+            generation = Vec::with_capacity(parent_ids.len());
+            for parent_id in parent_ids {
+                generation.push(self.fetch(&parent_id).await?);
+            }
+            ancestors.extend(generation.iter().cloned());
+        }
+
+        Ok((Some(ancestors), HeaderMap::new()))
+    }
+}
+
+crate::client::impl_with_client!(CommitAncestors);
+
+impl Api {
+    /// Creates a request to retrieve the ancestors of a commit, up to a number of generations.
+    ///
+    /// Each ancestor is fetched with [`Api::commit_get`]. All parents of merge commits are
+    /// followed, and commits reachable through several paths are only returned once.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `commit_id` - The ID of the commit to start from
+    /// * `depth` - The number of generations to walk, at most [`MAX_ANCESTOR_DEPTH`]
+    ///
+    /// # Returns
+    ///
+    /// A request for sending. Its output lists the ancestors generation by generation, parents
+    /// first; the starting commit is not included.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let ancestors = client
+    ///         .api()
+    ///         .commit_ancestors("PROJECT", "REPO", "8d51122def5632836d1cb1026e879069e10a1e13", 5)
+    ///         .send()
+    ///         .await?
+    ///         .unwrap_or_default();
+    ///
+    ///     for commit in ancestors {
+    ///         println!("{} {}", commit.display_id, commit.message.unwrap_or_default());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * One request is made per commit; keep `depth` small.
+    pub fn commit_ancestors(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        commit_id: &str,
+        depth: u32,
+    ) -> CommitAncestors {
+        CommitAncestors {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            commit_id: commit_id.to_owned(),
+            depth,
+        }
+    }
+}
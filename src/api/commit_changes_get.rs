@@ -0,0 +1,188 @@
+//! # Commit Changes API
+//!
+//! This module provides functionality to retrieve the changes made in a commit from Bitbucket Server.
+//! It allows fetching the list of files that were modified, added, or deleted by a single commit,
+//! independent of any pull request.
+
+use crate::api::common::{ChangeItem, Page};
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{
+    encode_path_segment, ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest,
+};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+/// Request builder for retrieving commit changes.
+///
+/// This struct is used to build and send requests to retrieve the changes in a commit.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct CommitChangesGet {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the commit to get the changes for
+    commit_id: String,
+
+    /// The commit to compare against. If not passed, the commit's first parent is used.
+    #[builder(setter(into, strip_option), default)]
+    since: Option<String>,
+
+    /// Start number for the page (inclusive). If not passed, first page is assumed.
+    #[builder(setter(into, strip_option), default)]
+    start: Option<u64>,
+
+    /// Number of items to return. If not passed, a page size of 25 is used.
+    #[builder(setter(into, strip_option), default)]
+    limit: Option<u64>,
+}
+
+impl CommitChangesGet {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for CommitChangesGet {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/commits/{}/changes",
+            self.project_key,
+            self.repository_slug,
+            encode_path_segment(&self.commit_id)
+        );
+
+        let mut params = BTreeMap::new();
+
+        if let Some(since) = &self.since {
+            params.insert("since".to_string(), since.clone());
+        }
+        if let Some(start) = &self.start {
+            params.insert("start".to_string(), start.to_string());
+        }
+        if let Some(limit) = &self.limit {
+            params.insert("limit".to_string(), limit.to_string());
+        }
+
+        self.client
+            .build_request(Method::GET, &request_uri, Some(params), None)
+    }
+}
+
+impl ApiRequest for CommitChangesGet {
+    type Output = Page<ChangeItem>;
+    const ENDPOINT_NAME: &'static str = "commit_changes_get";
+
+    /// Sends the request to retrieve commit changes.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either a page of changes or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for CommitChangesGet {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(CommitChangesGet);
+
+impl Api {
+    /// Creates a request builder for retrieving the changes made in a commit.
+    ///
+    /// This method returns a builder that can be used to configure and send a request
+    /// to retrieve the changes in a commit.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `commit_id` - The ID of the commit
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let response = client
+    ///         .api()
+    ///         .commit_changes_get("PROJECT", "REPO", "COMMIT_ID")
+    ///         .since("PARENT_COMMIT_ID")
+    ///         .limit(50u64)
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(page) = response {
+    ///         for change in page.values {
+    ///             println!("{}: {}", change.change_type, change.path.to_string);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// See the [Bitbucket Data Center REST API documentation](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-repository/#api-api-latest-projects-projectkey-repos-repositoryslug-commits-commitid-changes-get)
+    pub fn commit_changes_get(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        commit_id: &str,
+    ) -> CommitChangesGetBuilder {
+        let mut builder = CommitChangesGetBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .commit_id(commit_id.to_string());
+        builder
+    }
+}
@@ -0,0 +1,153 @@
+//! # Commit GET API
+//!
+//! This module provides functionality to retrieve a single commit from Bitbucket Server,
+//! including its author, message and parents.
+
+use crate::api::common::Commit;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{
+    encode_path_segment, ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest,
+};
+use crate::Error;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+/// Request for retrieving a commit.
+#[derive(Clone, Debug)]
+pub struct CommitGet {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the commit, or a ref that resolves to one
+    commit_id: String,
+}
+
+impl CommitGet {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for CommitGet {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/commits/{}",
+            self.project_key,
+            self.repository_slug,
+            encode_path_segment(&self.commit_id)
+        );
+
+        self.client
+            .build_request(Method::GET, &request_uri, None, None)
+    }
+}
+
+impl ApiRequest for CommitGet {
+    type Output = Commit;
+    const ENDPOINT_NAME: &'static str = "commit_get";
+
+    /// Sends the request to retrieve the commit.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the commit or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for CommitGet {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(CommitGet);
+
+impl Api {
+    /// Creates a request to retrieve a commit.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `commit_id` - The ID of the commit
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     if let Some(commit) = client
+    ///         .api()
+    ///         .commit_get("PROJECT", "REPO", "8d51122def5632836d1cb1026e879069e10a1e13")
+    ///         .send()
+    ///         .await?
+    ///     {
+    ///         println!("{} has {} parent(s)", commit.display_id, commit.parents.len());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-repository/#api-api-latest-projects-projectkey-repos-repositoryslug-commits-commitid-get)
+    pub fn commit_get(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        commit_id: &str,
+    ) -> CommitGet {
+        CommitGet {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            commit_id: commit_id.to_owned(),
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! # Commit List API
+//!
+//! This module provides functionality to list the commits of a repository in Bitbucket Server,
+//! newest first, optionally bounded to the commits between two commits or refs, e.g. the
+//! commits of a release.
+
+use crate::api::common::{Commit, Page};
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+/// Request builder for listing the commits of a repository.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct CommitList {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// Exclude this commit and its ancestors. A commit ID or a ref, e.g. `refs/tags/v1.0`.
+    #[builder(setter(into, strip_option), default)]
+    since: Option<String>,
+
+    /// List the commits reachable from this commit instead of the default branch. A commit ID
+    /// or a ref, e.g. `refs/heads/main`.
+    #[builder(setter(into, strip_option), default)]
+    until: Option<String>,
+
+    /// Only list the commits that modified this path
+    #[builder(setter(into, strip_option), default)]
+    path: Option<String>,
+
+    /// Whether to include merge commits (include, exclude or only). If not passed, merge
+    /// commits are included.
+    #[builder(setter(into, strip_option), default)]
+    merges: Option<String>,
+
+    /// Start number for the page (inclusive). If not passed, first page is assumed.
+    #[builder(setter(into, strip_option), default)]
+    start: Option<u64>,
+
+    /// Number of items to return. If not passed, a page size of 25 is used.
+    #[builder(setter(into, strip_option), default)]
+    limit: Option<u64>,
+}
+
+impl CommitList {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for CommitList {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/commits",
+            self.project_key, self.repository_slug
+        );
+
+        let mut params = BTreeMap::new();
+
+        if let Some(since) = &self.since {
+            params.insert("since".to_string(), since.clone());
+        }
+        if let Some(until) = &self.until {
+            params.insert("until".to_string(), until.clone());
+        }
+        if let Some(path) = &self.path {
+            params.insert("path".to_string(), path.clone());
+        }
+        if let Some(merges) = &self.merges {
+            params.insert("merges".to_string(), merges.clone());
+        }
+        if let Some(start) = &self.start {
+            params.insert("start".to_string(), start.to_string());
+        }
+        if let Some(limit) = &self.limit {
+            params.insert("limit".to_string(), limit.to_string());
+        }
+
+        self.client
+            .build_request(Method::GET, &request_uri, Some(params), None)
+    }
+}
+
+impl ApiRequest for CommitList {
+    type Output = Page<Commit>;
+    const ENDPOINT_NAME: &'static str = "commit_list";
+
+    /// Sends the request to list the commits.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either a page of commits, newest first, or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for CommitList {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(CommitList);
+
+impl Api {
+    /// Creates a request builder for listing the commits of a repository.
+    ///
+    /// Set `since` and `until` to list the commits between two commits or refs, like
+    /// `git log since..until`.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let response = client
+    ///         .api()
+    ///         .commit_list("PROJECT", "REPO")
+    ///         .since("refs/tags/v1.0")
+    ///         .until("refs/tags/v1.1")
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(page) = response {
+    ///         for commit in page.values {
+    ///             println!("{} {}", commit.display_id, commit.message.unwrap_or_default());
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * `since` and `until` accept commit IDs as well as refs, which are resolved by the
+    ///   server. The `since_id` and `until_id` of [`Api::pull_request_changes_get`] only
+    ///   accept commit IDs.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-repository/#api-api-latest-projects-projectkey-repos-repositoryslug-commits-get)
+    pub fn commit_list(self, project_key: &str, repository_slug: &str) -> CommitListBuilder {
+        let mut builder = CommitListBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string());
+        builder
+    }
+}
@@ -0,0 +1,383 @@
+//! # Common Types
+//!
+//! This module contains the entity types shared by several API endpoints, such as users,
+//! repositories, projects, commits, links and changes. Endpoint modules import these rather than
+//! defining their own.
+//! It also contains [`CommonQuery`] and the [`RequestExt`] trait, which provide the query
+//! flags that recur across many endpoints.
+
+use crate::timestamp::{ts_milliseconds_option, Timestamp};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+// `Page` moved to `pagination`; re-exported here for compatibility.
+pub use crate::api::pagination::Page;
+
+/// A user in Bitbucket Server.
+///
+/// Only `name` is needed to refer to a user in request payloads (e.g. pull request reviewers);
+/// the other fields are filled in when the user is returned by the API.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    /// The username of the user
+    pub name: String,
+
+    /// The numeric ID of the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    /// The URL-friendly slug of the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+
+    /// The email address of the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+
+    /// The display name of the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// Whether the user is active
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    /// The URL of the user's avatar, included when an avatar size is requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+
+    /// Links to the user's profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links: Option<Links>,
+}
+
+/// A project in Bitbucket Server, as returned by the API.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Project {
+    /// The key of the project
+    pub key: String,
+
+    /// The numeric ID of the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    /// The name of the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// A repository in Bitbucket Server, as returned by the API.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Repository {
+    /// The slug of the repository
+    pub slug: String,
+
+    /// The numeric ID of the repository
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    /// The name of the repository
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The project the repository belongs to
+    pub project: Project,
+
+    /// The fields of the response that are not modeled above, keyed by their JSON name, e.g.
+    /// `scmId` or `links`.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// A commit in Bitbucket Server, as returned by the API.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Commit {
+    /// The full hash of the commit
+    pub id: String,
+
+    /// The abbreviated hash of the commit
+    pub display_id: String,
+
+    /// The commit message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// The author of the commit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<User>,
+
+    /// The time the commit was authored, e.g. when it was first written before a rebase
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "ts_milliseconds_option")]
+    pub author_timestamp: Option<Timestamp>,
+
+    /// The committer of the commit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<User>,
+
+    /// The time the commit was committed, e.g. when it was rebased or cherry-picked
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "ts_milliseconds_option")]
+    pub committer_timestamp: Option<Timestamp>,
+
+    /// The parents of the commit. Parents only carry `id` and `display_id`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parents: Vec<Commit>,
+}
+
+/// A link to a resource, e.g. in the web UI or a clone URL.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct Link {
+    /// The URL of the link
+    pub href: String,
+
+    /// The name of the link, e.g. `http` or `ssh` for clone links
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The links of an entity, as returned by the API.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct Links {
+    /// Links to the entity itself, usually in the web UI
+    #[serde(rename = "self", default, skip_serializing_if = "Vec::is_empty")]
+    pub self_links: Vec<Link>,
+
+    /// Clone URLs of a repository
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub clone: Vec<Link>,
+}
+
+/// Represents a single change item, i.e. a file changed in a pull request or commit.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChangeItem {
+    /// The content ID of the change
+    #[serde(rename = "contentId")]
+    pub content_id: String,
+
+    /// The type of change (e.g., "ADD", "MODIFY", "DELETE")
+    #[serde(rename = "type")]
+    pub change_type: String,
+
+    /// The path of the file that was changed
+    #[serde(rename = "path")]
+    pub path: Path,
+}
+
+/// Represents the path of a file in a change.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Path {
+    /// The string representation of the path
+    #[serde(rename = "toString")]
+    pub to_string: String,
+}
+
+/// Query flags shared by many Bitbucket Server API endpoints.
+///
+/// Request builders that support these flags hold a `CommonQuery` and implement
+/// [`RequestExt`], rather than each declaring the same fields.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct CommonQuery {
+    /// If true, markup fields (e.g. descriptions and comments) are rendered to HTML
+    pub markup: Option<bool>,
+
+    /// The size in pixels of the avatar URLs to include for users
+    pub avatar_size: Option<u32>,
+
+    /// If true, additional attributes are included in the response
+    pub with_attributes: Option<bool>,
+}
+
+impl CommonQuery {
+    /// Adds the flags that are set to a request's query parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The query parameters of the request
+    pub fn append_to(&self, params: &mut BTreeMap<String, String>) {
+        if let Some(markup) = &self.markup {
+            params.insert("markup".to_string(), markup.to_string());
+        }
+        if let Some(avatar_size) = &self.avatar_size {
+            params.insert("avatarSize".to_string(), avatar_size.to_string());
+        }
+        if let Some(with_attributes) = &self.with_attributes {
+            params.insert("withAttributes".to_string(), with_attributes.to_string());
+        }
+    }
+}
+
+/// Extension trait for request builders that support the [`CommonQuery`] flags.
+///
+/// Endpoints opt in by implementing `common_query` on their builder; the setters
+/// are then provided by this trait.
+pub trait RequestExt {
+    /// Access the common query flags of the request.
+    fn common_query(&mut self) -> &mut CommonQuery;
+
+    /// Render markup fields to HTML.
+    fn markup(&mut self, markup: bool) -> &mut Self {
+        self.common_query().markup = Some(markup);
+        self
+    }
+
+    /// Set the size in pixels of the avatar URLs to include for users.
+    fn avatar_size(&mut self, avatar_size: u32) -> &mut Self {
+        self.common_query().avatar_size = Some(avatar_size);
+        self
+    }
+
+    /// Include additional attributes in the response.
+    fn with_attributes(&mut self, with_attributes: bool) -> &mut Self {
+        self.common_query().with_attributes = Some(with_attributes);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timestamp::epoch_seconds;
+
+    #[derive(Default)]
+    struct MockRequestBuilder {
+        common_query: Option<CommonQuery>,
+    }
+
+    impl RequestExt for MockRequestBuilder {
+        fn common_query(&mut self) -> &mut CommonQuery {
+            self.common_query.get_or_insert_with(CommonQuery::default)
+        }
+    }
+
+    #[test]
+    fn it_can_deserialize_user() {
+        let json = r#"{
+            "name": "jcitizen",
+            "emailAddress": "jane@example.com",
+            "active": true,
+            "displayName": "Jane Citizen",
+            "id": 101,
+            "slug": "jcitizen",
+            "type": "NORMAL"
+        }"#;
+
+        let user: User = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            user,
+            User {
+                name: "jcitizen".to_string(),
+                id: Some(101),
+                slug: Some("jcitizen".to_string()),
+                email_address: Some("jane@example.com".to_string()),
+                display_name: Some("Jane Citizen".to_string()),
+                active: Some(true),
+                avatar_url: None,
+                links: None,
+            }
+        );
+    } // end of it_can_deserialize_user
+
+    #[test]
+    fn it_can_serialize_minimal_user() {
+        let user = User {
+            name: "jcitizen".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(serde_json::to_string(&user).unwrap(), r#"{"name":"jcitizen"}"#);
+    } // end of it_can_serialize_minimal_user
+
+    #[test]
+    fn it_can_deserialize_commit() {
+        let json = r#"{
+            "id": "def0123abcdef4567abcdef8987abcdef6543abc",
+            "displayId": "def0123abcd",
+            "message": "More work on feature 1",
+            "author": {"name": "charlie", "emailAddress": "charlie@example.com"},
+            "authorTimestamp": 1738198923000,
+            "committer": {"name": "charlie", "emailAddress": "charlie@example.com"},
+            "committerTimestamp": 1738198924000,
+            "parents": [{"id": "abcdef0123abcdef4567abcdef8987abcdef6543", "displayId": "abcdef0"}]
+        }"#;
+
+        let commit: Commit = serde_json::from_str(json).unwrap();
+
+        assert_eq!(commit.display_id, "def0123abcd");
+        assert_eq!(commit.author.unwrap().name, "charlie");
+        // milliseconds, not seconds: 2025-01-30, not a date in the year 57000
+        assert_eq!(epoch_seconds(&commit.author_timestamp.unwrap()), 1738198923);
+        assert_eq!(epoch_seconds(&commit.committer_timestamp.unwrap()), 1738198924);
+        assert_eq!(
+            commit.parents,
+            vec![Commit {
+                id: "abcdef0123abcdef4567abcdef8987abcdef6543".to_string(),
+                display_id: "abcdef0".to_string(),
+                ..Default::default()
+            }]
+        );
+    } // end of it_can_deserialize_commit
+
+    #[test]
+    fn it_can_deserialize_links() {
+        let json = r#"{
+            "self": [{"href": "https://host/projects/PRJ/repos/my-repo/browse"}],
+            "clone": [
+                {"href": "ssh://git@host:7999/prj/my-repo.git", "name": "ssh"},
+                {"href": "https://host/scm/prj/my-repo.git", "name": "http"}
+            ]
+        }"#;
+
+        let links: Links = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            links.self_links[0].href,
+            "https://host/projects/PRJ/repos/my-repo/browse"
+        );
+        assert_eq!(links.clone.len(), 2);
+        assert_eq!(links.clone[0].name, Some("ssh".to_string()));
+    } // end of it_can_deserialize_links
+
+    #[test]
+    fn it_keeps_unmodeled_repository_fields() {
+        let json = r#"{
+            "slug": "my-repo",
+            "id": 1,
+            "project": {"key": "PRJ"},
+            "scmId": "git",
+            "forkable": true
+        }"#;
+
+        let repository: Repository = serde_json::from_str(json).unwrap();
+
+        assert_eq!(repository.slug, "my-repo");
+        assert_eq!(repository.extra.len(), 2);
+        assert_eq!(repository.extra["scmId"], "git");
+        assert_eq!(repository.extra["forkable"], true);
+    } // end of it_keeps_unmodeled_repository_fields
+
+    #[test]
+    fn it_can_set_common_query_flags() {
+        let mut builder = MockRequestBuilder::default();
+        builder.markup(true).avatar_size(64).with_attributes(false);
+
+        let mut params = BTreeMap::new();
+        builder.common_query.unwrap().append_to(&mut params);
+
+        assert_eq!(params.len(), 3);
+        assert_eq!(params["markup"], "true");
+        assert_eq!(params["avatarSize"], "64");
+        assert_eq!(params["withAttributes"], "false");
+    } // end of it_can_set_common_query_flags
+
+    #[test]
+    fn it_skips_unset_common_query_flags() {
+        let mut params = BTreeMap::new();
+        CommonQuery::default().append_to(&mut params);
+
+        assert!(params.is_empty());
+    } // end of it_skips_unset_common_query_flags
+}
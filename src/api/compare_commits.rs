@@ -0,0 +1,180 @@
+//! # Compare Commits API
+//!
+//! This module provides functionality to list the commits that one ref has and another doesn't
+//! in Bitbucket Server, e.g. the commits of a branch that are not merged yet.
+
+use crate::api::common::{Commit, Page};
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+/// Request builder for comparing the commits of two refs.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct CompareCommits {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ref with the commits to list, e.g. `refs/heads/feature`
+    from: String,
+
+    /// The ref whose commits are excluded, e.g. `refs/heads/main`
+    to: String,
+
+    /// Start number for the page (inclusive). If not passed, first page is assumed.
+    #[builder(setter(into, strip_option), default)]
+    start: Option<u64>,
+
+    /// Number of items to return. If not passed, a page size of 25 is used.
+    #[builder(setter(into, strip_option), default)]
+    limit: Option<u64>,
+}
+
+impl CompareCommits {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for CompareCommits {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/compare/commits",
+            self.project_key, self.repository_slug
+        );
+
+        let mut params = BTreeMap::new();
+        params.insert("from".to_string(), self.from.clone());
+        params.insert("to".to_string(), self.to.clone());
+
+        if let Some(start) = &self.start {
+            params.insert("start".to_string(), start.to_string());
+        }
+        if let Some(limit) = &self.limit {
+            params.insert("limit".to_string(), limit.to_string());
+        }
+
+        self.client
+            .build_request(Method::GET, &request_uri, Some(params), None)
+    }
+}
+
+impl ApiRequest for CompareCommits {
+    type Output = Page<Commit>;
+    const ENDPOINT_NAME: &'static str = "compare_commits";
+
+    /// Sends the request to compare the commits.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either a page of the commits on `from` that are not on `to`, or an
+    /// error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for CompareCommits {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(CompareCommits);
+
+impl Api {
+    /// Creates a request builder for listing the commits on one ref that are not on another.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `from` - The ref with the commits to list, e.g. `refs/heads/feature`
+    /// * `to` - The ref whose commits are excluded, e.g. `refs/heads/main`
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let response = client
+    ///         .api()
+    ///         .compare_commits("PROJECT", "REPO", "refs/heads/feature", "refs/heads/main")
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(page) = response {
+    ///         println!("{} commits ahead", page.size);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-repository/#api-api-latest-projects-projectkey-repos-repositoryslug-compare-commits-get)
+    pub fn compare_commits(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        from: &str,
+        to: &str,
+    ) -> CompareCommitsBuilder {
+        let mut builder = CompareCommitsBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .from(from.to_string())
+            .to(to.to_string());
+        builder
+    }
+}
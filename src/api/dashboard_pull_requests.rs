@@ -0,0 +1,184 @@
+//! # Dashboard Pull Requests API
+//!
+//! This module provides functionality to list the pull requests on the authenticated user's
+//! dashboard, i.e. the ones they authored, review or take part in, across all repositories.
+//! Personal tooling, such as "my open reviews", can use it instead of listing the pull requests
+//! of every repository.
+
+use crate::api::common::{CommonQuery, Page, RequestExt};
+use crate::api::pull_request::PullRequest;
+use crate::api::pull_request_participant::ParticipantRole;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+/// Request builder for listing the pull requests on the authenticated user's dashboard.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct DashboardPullRequests {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// OPEN, DECLINED or MERGED. If not passed, pull requests in any state are returned.
+    #[builder(setter(into, strip_option), default)]
+    state: Option<String>,
+
+    /// Only return the pull requests in which the user has this role. If not passed, all the
+    /// pull requests the user takes part in are returned.
+    #[builder(setter(strip_option), default)]
+    role: Option<ParticipantRole>,
+
+    /// OLDEST or NEWEST. If not passed, NEWEST is assumed.
+    #[builder(setter(into, strip_option), default)]
+    order: Option<String>,
+
+    /// Start number for the page (inclusive). If not passed, first page is assumed.
+    #[builder(setter(into, strip_option), default)]
+    start: Option<u64>,
+
+    /// Number of items to return. If not passed, a page size of 25 is used.
+    #[builder(setter(into, strip_option), default)]
+    limit: Option<u64>,
+
+    /// The common query flags of the request, set through [`RequestExt`]
+    #[builder(setter(custom), default)]
+    common_query: CommonQuery,
+}
+
+impl RequestExt for DashboardPullRequestsBuilder {
+    fn common_query(&mut self) -> &mut CommonQuery {
+        self.common_query.get_or_insert_with(CommonQuery::default)
+    }
+}
+
+impl DashboardPullRequests {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for DashboardPullRequests {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = "api/latest/dashboard/pull-requests";
+
+        let mut params = BTreeMap::new();
+
+        if let Some(state) = &self.state {
+            params.insert("state".to_string(), state.clone());
+        }
+        if let Some(role) = &self.role {
+            params.insert("role".to_string(), role.as_str().to_string());
+        }
+        if let Some(order) = &self.order {
+            params.insert("order".to_string(), order.clone());
+        }
+        if let Some(start) = &self.start {
+            params.insert("start".to_string(), start.to_string());
+        }
+        if let Some(limit) = &self.limit {
+            params.insert("limit".to_string(), limit.to_string());
+        }
+        self.common_query.append_to(&mut params);
+
+        self.client
+            .build_request(Method::GET, request_uri, Some(params), None)
+    }
+}
+
+impl ApiRequest for DashboardPullRequests {
+    type Output = Page<PullRequest>;
+    const ENDPOINT_NAME: &'static str = "dashboard_pull_requests";
+
+    /// Sends the request to list the dashboard's pull requests.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either a page of pull requests or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for DashboardPullRequests {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(DashboardPullRequests);
+
+impl Api {
+    /// Creates a request builder for listing the pull requests on the authenticated user's
+    /// dashboard.
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::api::pull_request_participant::ParticipantRole;
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     // List the open pull requests waiting for my review
+    ///     let response = client
+    ///         .api()
+    ///         .dashboard_pull_requests()
+    ///         .state("OPEN")
+    ///         .role(ParticipantRole::Reviewer)
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(page) = response {
+    ///         for pull_request in page.values {
+    ///             println!("#{}: {}", pull_request.id, pull_request.title);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// See the [Bitbucket Data Center REST API documentation](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-dashboard/#api-api-latest-dashboard-pull-requests-get)
+    pub fn dashboard_pull_requests(self) -> DashboardPullRequestsBuilder {
+        let mut builder = DashboardPullRequestsBuilder::default();
+        builder.client(self.client.clone());
+        builder
+    }
+}
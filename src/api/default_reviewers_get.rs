@@ -0,0 +1,186 @@
+//! # Default Reviewers API
+//!
+//! This module provides functionality to retrieve the reviewers Bitbucket Server suggests for a
+//! pull request, based on the default reviewer conditions configured for the repository and its
+//! project. Use it to populate the reviewers of pull requests created by automation.
+
+use crate::api::common::User;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+/// Request builder for retrieving the default reviewers of a pull request.
+///
+/// This struct is used to build and send requests to retrieve the reviewers suggested for a
+/// pull request from the source ref to the target ref.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct DefaultReviewersGet {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the target repository
+    project_key: String,
+
+    /// The slug of the target repository
+    repository_slug: String,
+
+    /// The numeric ID of the source repository
+    #[builder(setter(into))]
+    source_repo_id: u64,
+
+    /// The numeric ID of the target repository
+    #[builder(setter(into))]
+    target_repo_id: u64,
+
+    /// The source ref, e.g. `refs/heads/feature`
+    #[builder(setter(into))]
+    source_ref_id: String,
+
+    /// The target ref, e.g. `refs/heads/main`
+    #[builder(setter(into))]
+    target_ref_id: String,
+}
+
+impl DefaultReviewersGet {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for DefaultReviewersGet {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "default-reviewers/latest/projects/{}/repos/{}/reviewers",
+            self.project_key, self.repository_slug
+        );
+
+        let mut params = BTreeMap::new();
+        params.insert("sourceRepoId".to_string(), self.source_repo_id.to_string());
+        params.insert("targetRepoId".to_string(), self.target_repo_id.to_string());
+        params.insert("sourceRefId".to_string(), self.source_ref_id.clone());
+        params.insert("targetRefId".to_string(), self.target_ref_id.clone());
+
+        self.client
+            .build_request(Method::GET, &request_uri, Some(params), None)
+    }
+}
+
+impl ApiRequest for DefaultReviewersGet {
+    type Output = Vec<User>;
+    const ENDPOINT_NAME: &'static str = "default_reviewers_get";
+
+    /// Sends the request to retrieve the default reviewers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the suggested reviewers or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for DefaultReviewersGet {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(DefaultReviewersGet);
+
+impl Api {
+    /// Creates a request builder for retrieving the reviewers suggested for a pull request.
+    ///
+    /// The suggestion is computed from the default reviewer conditions that match the source
+    /// and target refs of the pull request.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the target repository
+    /// * `repository_slug` - The slug of the target repository
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request. The source and target repository IDs
+    /// and refs must be set before building.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let reviewers = client
+    ///         .api()
+    ///         .default_reviewers_get("PROJECT", "REPO")
+    ///         .source_repo_id(1u64)
+    ///         .target_repo_id(1u64)
+    ///         .source_ref_id("refs/heads/feature")
+    ///         .target_ref_id("refs/heads/main")
+    ///         .build()?
+    ///         .send()
+    ///         .await?
+    ///         .unwrap_or_default();
+    ///
+    ///     for reviewer in reviewers {
+    ///         println!("Suggested reviewer: {}", reviewer.name);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * This endpoint lives under `/rest/default-reviewers` rather than `/rest/api`. It is
+    ///   provided by the Default Reviewers plugin bundled with Bitbucket Server; if the plugin
+    ///   is disabled the server responds with a 404.
+    /// * The authenticated user must have REPO_READ permission for the target repository.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-default-reviewers/#api-default-reviewers-latest-projects-projectkey-repos-repositoryslug-reviewers-get)
+    pub fn default_reviewers_get(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+    ) -> DefaultReviewersGetBuilder {
+        let mut builder = DefaultReviewersGetBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string());
+        builder
+    }
+}
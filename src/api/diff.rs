@@ -0,0 +1,314 @@
+//! # Diff Common Types
+//!
+//! This module contains common types used by the diff API endpoints.
+//! Bitbucket Server truncates diffs that are too large; the `truncated` flags at each level
+//! must be checked to detect incomplete results.
+
+use crate::api::common::Path;
+use serde::{Deserialize, Serialize};
+
+/// A structured diff between two commits, as returned by the API.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Diffs {
+    /// The commit hash the diff is from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_hash: Option<String>,
+
+    /// The commit hash the diff is to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_hash: Option<String>,
+
+    /// The number of context lines around each change
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context_lines: Option<u32>,
+
+    /// The whitespace mode the diff was computed with, e.g. `ignore-all`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub whitespace: Option<String>,
+
+    /// The diffs of the changed files
+    #[serde(default)]
+    pub diffs: Vec<Diff>,
+
+    /// Whether the list of changed files was truncated because the diff is too large
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+impl Diffs {
+    /// Check whether any part of the diff was truncated.
+    ///
+    /// When this is `true` the result is incomplete; callers can fetch the affected files
+    /// individually to get their full diff.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the diff, or any file, hunk, segment or line in it, was truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated || self.diffs.iter().any(Diff::is_truncated)
+    }
+
+    /// Summarize the diff as the number of files changed and lines added and removed.
+    ///
+    /// Binary files count as changed files but have no line counts.
+    ///
+    /// # Returns
+    ///
+    /// The diff stat of the diff.
+    pub fn stat(&self) -> DiffStat {
+        let mut stat = DiffStat {
+            files_changed: self.diffs.len() as u32,
+            truncated: self.is_truncated(),
+            ..Default::default()
+        };
+
+        for diff in &self.diffs {
+            if diff.binary {
+                stat.binary_files += 1;
+            }
+            for segment in diff.hunks.iter().flat_map(|hunk| &hunk.segments) {
+                match segment.segment_type.as_str() {
+                    "ADDED" => stat.lines_added += segment.lines.len() as u32,
+                    "REMOVED" => stat.lines_removed += segment.lines.len() as u32,
+                    _ => {}
+                }
+            }
+        }
+
+        stat
+    }
+}
+
+/// A summary of a diff, e.g. "+120 −30, 4 files".
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffStat {
+    /// The number of files changed, including binary files
+    pub files_changed: u32,
+
+    /// The number of binary files changed, which have no line counts
+    pub binary_files: u32,
+
+    /// The number of lines added
+    pub lines_added: u32,
+
+    /// The number of lines removed
+    pub lines_removed: u32,
+
+    /// Whether the diff was truncated, in which case the counts are lower bounds
+    pub truncated: bool,
+}
+
+/// The diff of a single file.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Diff {
+    /// The path of the file before the change, absent for added files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Path>,
+
+    /// The path of the file after the change, absent for deleted files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<Path>,
+
+    /// The changed regions of the file, absent for binary files
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hunks: Vec<DiffHunk>,
+
+    /// Whether the file is binary, in which case there are no hunks
+    #[serde(default)]
+    pub binary: bool,
+
+    /// Whether the hunks of this file were truncated
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+impl Diff {
+    /// Check whether any part of this file's diff was truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated || self.hunks.iter().any(DiffHunk::is_truncated)
+    }
+}
+
+/// A changed region of a file.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffHunk {
+    /// The first line of the hunk in the source file
+    pub source_line: u32,
+
+    /// The number of lines of the hunk in the source file
+    pub source_span: u32,
+
+    /// The first line of the hunk in the destination file
+    pub destination_line: u32,
+
+    /// The number of lines of the hunk in the destination file
+    pub destination_span: u32,
+
+    /// The added, removed and context segments of the hunk
+    #[serde(default)]
+    pub segments: Vec<DiffSegment>,
+
+    /// Whether the segments of this hunk were truncated
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+impl DiffHunk {
+    /// Check whether any part of this hunk was truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated || self.segments.iter().any(DiffSegment::is_truncated)
+    }
+}
+
+/// A run of added, removed or context lines within a hunk.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffSegment {
+    /// The type of the segment (ADDED, REMOVED or CONTEXT)
+    #[serde(rename = "type")]
+    pub segment_type: String,
+
+    /// The lines of the segment
+    #[serde(default)]
+    pub lines: Vec<DiffLine>,
+
+    /// Whether the lines of this segment were truncated
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+impl DiffSegment {
+    /// Check whether any part of this segment was truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated || self.lines.iter().any(|line| line.truncated)
+    }
+}
+
+/// A single line of a diff.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffLine {
+    /// The line number in the source file
+    pub source: u32,
+
+    /// The line number in the destination file
+    pub destination: u32,
+
+    /// The content of the line
+    pub line: String,
+
+    /// Whether the content of the line was truncated
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_can_deserialize() {
+        let json = r#"{
+            "fromHash": "from_hash",
+            "toHash": "to_hash",
+            "contextLines": 10,
+            "whitespace": "",
+            "diffs": [{
+                "source": {"toString": "src/main.rs"},
+                "destination": {"toString": "src/main.rs"},
+                "hunks": [{
+                    "sourceLine": 1,
+                    "sourceSpan": 1,
+                    "destinationLine": 1,
+                    "destinationSpan": 2,
+                    "segments": [
+                        {"type": "CONTEXT", "lines": [{"source": 1, "destination": 1, "line": "fn main() {}", "truncated": false}], "truncated": false},
+                        {"type": "ADDED", "lines": [{"source": 1, "destination": 2, "line": "// done", "truncated": false}], "truncated": false}
+                    ],
+                    "truncated": false
+                }],
+                "truncated": false
+            }],
+            "truncated": false
+        }"#;
+
+        let diffs: Diffs = serde_json::from_str(json).unwrap();
+
+        assert_eq!(diffs.diffs.len(), 1);
+        assert_eq!(diffs.diffs[0].hunks[0].segments[1].segment_type, "ADDED");
+        assert_eq!(diffs.diffs[0].hunks[0].segments[1].lines[0].line, "// done");
+        assert!(!diffs.is_truncated());
+    } // end of it_can_deserialize
+
+    #[test]
+    fn it_detects_nested_truncation() {
+        let json = r#"{
+            "diffs": [{
+                "destination": {"toString": "big.txt"},
+                "hunks": [{
+                    "sourceLine": 0,
+                    "sourceSpan": 0,
+                    "destinationLine": 1,
+                    "destinationSpan": 1,
+                    "segments": [
+                        {"type": "ADDED", "lines": [{"source": 0, "destination": 1, "line": "aaaa", "truncated": true}]}
+                    ]
+                }]
+            }]
+        }"#;
+
+        let diffs: Diffs = serde_json::from_str(json).unwrap();
+
+        assert!(!diffs.truncated);
+        assert!(diffs.is_truncated());
+        assert!(diffs.diffs[0].source.is_none());
+    } // end of it_detects_nested_truncation
+
+    #[test]
+    fn it_can_compute_stat() {
+        let json = r#"{
+            "diffs": [
+                {
+                    "source": {"toString": "logo.png"},
+                    "destination": {"toString": "logo.png"},
+                    "binary": true
+                },
+                {
+                    "source": {"toString": "src/main.rs"},
+                    "destination": {"toString": "src/main.rs"},
+                    "hunks": [{
+                        "sourceLine": 1,
+                        "sourceSpan": 2,
+                        "destinationLine": 1,
+                        "destinationSpan": 3,
+                        "segments": [
+                            {"type": "CONTEXT", "lines": [{"source": 1, "destination": 1, "line": "fn main() {"}]},
+                            {"type": "REMOVED", "lines": [{"source": 2, "destination": 2, "line": "}"}]},
+                            {"type": "ADDED", "lines": [
+                                {"source": 2, "destination": 2, "line": "    run();"},
+                                {"source": 2, "destination": 3, "line": "}"}
+                            ]}
+                        ]
+                    }]
+                }
+            ]
+        }"#;
+
+        let diffs: Diffs = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            diffs.stat(),
+            DiffStat {
+                files_changed: 2,
+                binary_files: 1,
+                lines_added: 2,
+                lines_removed: 1,
+                truncated: false,
+            }
+        );
+    } // end of it_can_compute_stat
+}
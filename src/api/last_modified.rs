@@ -0,0 +1,237 @@
+//! # Last Modified API
+//!
+//! This module provides functionality to find, for each file in a directory of a repository in
+//! Bitbucket Server, the commit that last modified it. Tooling that annotates files with their
+//! last change uses this instead of walking the history.
+
+use crate::api::common::Commit;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{
+    encode_path, ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest,
+};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// The commits that last modified the files of a directory, as returned by the API.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LastModified {
+    /// The commit the directory was read at
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_commit: Option<Commit>,
+
+    /// The commit that last modified each file, keyed by the file name relative to the directory
+    #[serde(default)]
+    pub files: HashMap<String, Commit>,
+}
+
+/// Request builder for retrieving the last-modified commits of a directory's files.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct LastModifiedGet {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The path of the directory, relative to the root of the repository
+    path: String,
+
+    /// The commit ID or ref to read the directory at. If not passed, the default branch is used.
+    #[builder(setter(into, strip_option), default)]
+    at: Option<String>,
+}
+
+impl LastModifiedGet {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for LastModifiedGet {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/last-modified/{}",
+            self.project_key,
+            self.repository_slug,
+            encode_path(self.path.trim_matches('/'))
+        );
+
+        let mut params = BTreeMap::new();
+
+        if let Some(at) = &self.at {
+            params.insert("at".to_string(), at.clone());
+        }
+
+        self.client
+            .build_request(Method::GET, &request_uri, Some(params), None)
+    }
+}
+
+impl ApiRequest for LastModifiedGet {
+    type Output = LastModified;
+    const ENDPOINT_NAME: &'static str = "last_modified";
+
+    /// Sends the request to retrieve the last-modified commits.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the last-modified commits or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for LastModifiedGet {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(LastModifiedGet);
+
+impl Api {
+    /// Creates a request builder for retrieving the commit that last modified each file of a
+    /// directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `path` - The path of the directory, relative to the root of the repository; an empty
+    ///   path is the root directory
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let response = client
+    ///         .api()
+    ///         .last_modified("PROJECT", "REPO", "src")
+    ///         .at("refs/heads/main")
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(last_modified) = response {
+    ///         for (file, commit) in last_modified.files {
+    ///             println!("{file}: {}", commit.display_id);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * Only the direct children of the directory are returned; subdirectories are listed with
+    ///   the commit that last modified anything inside them.
+    ///
+    /// See the [Bitbucket Data Center REST API documentation](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-repository/#api-api-latest-projects-projectkey-repos-repositoryslug-last-modified-path-get)
+    pub fn last_modified(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        path: &str,
+    ) -> LastModifiedGetBuilder {
+        let mut builder = LastModifiedGetBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .path(path.to_string());
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timestamp::epoch_seconds;
+
+    #[test]
+    fn it_can_deserialize_directory() {
+        let json = r#"{
+            "latestCommit": {
+                "id": "8d51122def5632836d1cb1026e879069e10a1e13",
+                "displayId": "8d51122def5",
+                "message": "Add lib"
+            },
+            "files": {
+                "main.rs": {
+                    "id": "0a943a29376f2336b78312d99e65da17048951db",
+                    "displayId": "0a943a29376",
+                    "message": "Initial commit",
+                    "authorTimestamp": 1738198923000
+                },
+                "lib.rs": {
+                    "id": "8d51122def5632836d1cb1026e879069e10a1e13",
+                    "displayId": "8d51122def5",
+                    "message": "Add lib"
+                }
+            }
+        }"#;
+
+        let last_modified: LastModified = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            last_modified.latest_commit.unwrap().display_id,
+            "8d51122def5"
+        );
+        assert_eq!(last_modified.files.len(), 2);
+        assert_eq!(
+            last_modified.files["main.rs"].message.as_deref(),
+            Some("Initial commit")
+        );
+        assert_eq!(
+            epoch_seconds(&last_modified.files["main.rs"].author_timestamp.unwrap()),
+            1738198923
+        );
+        assert_eq!(last_modified.files["lib.rs"].display_id, "8d51122def5");
+    } // end of it_can_deserialize_directory
+}
@@ -7,11 +7,76 @@
 //!
 //! The API is organized into the following modules:
 //!
+//! - `attachment_get`: API for downloading repository attachments, e.g. images in comments
+//! - `avatar_get`: API for downloading the avatar images of users and projects
+//! - `branch`: Common types for branches
+//! - `branch_delete`: API for deleting branches
+//! - `branch_is_merged`: Checks whether a branch is fully merged into another
+//! - `branch_list`: API for listing the branches of a repository
+//! - `branch_model_get`: API for retrieving the branching model of a repository, e.g. its git-flow prefixes
+//! - `branch_prune_merged`: Deletes the branches matching a pattern once they are merged into the default branch
+//! - `build_reporter`: Posts the build statuses of a commit, optionally rejecting invalid state transitions
 //! - `build_status`: Common types and utilities for build status operations
+//! - `build_status_ci`: Fills a build status payload from CI environment variables (feature `ci`)
 //! - `build_status_get`: API for retrieving build status information
+//! - `build_status_list`: API for listing all the build statuses of a commit
 //! - `build_status_post`: API for posting build status updates
+//! - `build_status_post_for_pr`: API for posting a build status to the latest commit of a pull request
+//! - `build_status_post_for_pr_commits`: Posts a build status to every commit a pull request introduces, skipping the commits that already have it
+//! - `commit_ancestors`: API for walking the ancestors of a commit
+//! - `commit_changes_get`: API for retrieving the changes made in a commit
+//! - `commit_get`: API for retrieving a commit
+//! - `commit_list`: API for listing the commits of a repository, optionally between two commits or refs
+//! - `common`: Entity types shared by several API endpoints, such as `User`, `Repository` and
+//!   `Commit`
+//! - `compare_commits`: API for listing the commits on one ref that are not on another
+//! - `dashboard_pull_requests`: API for listing the pull requests the authenticated user authored, reviews or takes part in
+//! - `default_reviewers_get`: API for retrieving the reviewers suggested for a pull request
+//! - `diff`: Common types for structured diffs
+//! - `last_modified`: API for finding the commit that last modified each file of a directory
+//! - `my_repo_permission`: Finds the highest permission the authenticated user holds on a repository
+//! - `pagination`: The `Page` envelope returned by all list endpoints, and a stream over all of their pages
+//! - `pull_request`: Common types for reading pull requests
 //! - `pull_request_changes_get`: API for retrieving pull request changes
+//! - `pull_request_comment`: Common types for pull request comments and their reply threads
+//! - `pull_request_comment_list`: API for listing the comments of a pull request
+//! - `pull_request_comment_post`: API for commenting on pull requests and replying to comments
+//! - `pull_request_comment_threads`: Fetches all the comments of a pull request on a file with their replies nested
+//! - `pull_request_create_if_ahead`: Creates a pull request only if its source branch has commits ahead of its target branch
+//! - `pull_request_create_or_get`: API for creating pull requests without duplicates
+//! - `pull_request_diff_get`: API for retrieving the structured diff of a pull request
+//! - `pull_request_diffstat`: API for counting the files and lines changed by a pull request
+//! - `pull_request_gate_context`: Fetches the changes of a pull request and the build statuses of its head commit concurrently, for merge gates
+//! - `pull_request_get`: API for retrieving a pull request, with optional expansions
+//! - `pull_request_incoming_commits`: Lists the commits a pull request introduces since its merge base
+//! - `pull_request_jira_issues`: API for retrieving the Jira issues linked to a pull request
+//! - `pull_request_label`: Common types for pull request labels
+//! - `pull_request_label_delete`: API for removing a label from a pull request
+//! - `pull_request_label_list`: API for listing the labels of a pull request
+//! - `pull_request_label_post`: API for adding a label to a pull request
+//! - `pull_request_list`: API for listing pull requests
+//! - `pull_request_merge`: API for merging pull requests, right away or with auto-merge
+//! - `pull_request_merge_base`: API for retrieving the common ancestor of a pull request's source and target branches
+//! - `pull_request_merge_if_green`: Merges a pull request only if no merge check vetoes it and its builds are green
+//! - `pull_request_merge_status_get`: API for checking whether a pull request can be merged
+//! - `pull_request_participant`: Common types for pull request participants and their review status
+//! - `pull_request_participant_status_put`: API for approving pull requests or marking them as needing work
+//! - `pull_request_patch`: API for retrieving the raw patch of a pull request
 //! - `pull_request_post`: API for creating pull requests
+//! - `pull_request_rescoped`: API for checking whether a pull request got new commits since a head commit was seen
+//! - `pull_request_review`: Posts several comments on a pull request at once, e.g. the inline comments of a review
+//! - `pull_request_settings`: Common types for repository pull request settings and merge strategies
+//! - `pull_request_settings_get`: API for reading a repository's pull request settings
+//! - `pull_request_settings_update`: API for updating a repository's pull request settings and merge checks
+//! - `pull_requests_get_many`: API for retrieving several pull requests concurrently
+//! - `repo_default_reviewers_for_pr`: Builds a pull request payload with the default reviewers set
+//! - `repository_list`: API for listing the repositories the authenticated user can see
+//! - `search`: API for searching code, repositories and commits across the instance
+//! - `webhook`: Common types for webhooks, and helpers for verifying signed deliveries
+//! - `webhook_list`: API for listing the webhooks of a repository
+//! - `webhook_post`: API for creating repository webhooks
+//! - `webhook_test`: API for testing repository webhooks
+//! - `whoami`: API for retrieving the authenticated user
 //!
 //! ## Usage Pattern
 //!
@@ -47,11 +112,76 @@
 
 use crate::client::Client;
 
+pub mod attachment_get;
+pub mod avatar_get;
+pub mod branch;
+pub mod branch_delete;
+pub mod branch_is_merged;
+pub mod branch_list;
+pub mod branch_model_get;
+pub mod branch_prune_merged;
+pub mod build_reporter;
 pub mod build_status;
+#[cfg(feature = "ci")]
+pub mod build_status_ci;
 pub mod build_status_get;
+pub mod build_status_list;
 pub mod build_status_post;
+pub mod build_status_post_for_pr;
+pub mod build_status_post_for_pr_commits;
+pub mod commit_ancestors;
+pub mod commit_changes_get;
+pub mod commit_get;
+pub mod commit_list;
+pub mod common;
+pub mod compare_commits;
+pub mod dashboard_pull_requests;
+pub mod default_reviewers_get;
+pub mod diff;
+pub mod last_modified;
+pub mod my_repo_permission;
+pub mod pagination;
+pub mod pull_request;
 pub mod pull_request_changes_get;
+pub mod pull_request_comment;
+pub mod pull_request_comment_list;
+pub mod pull_request_comment_post;
+pub mod pull_request_comment_threads;
+pub mod pull_request_create_if_ahead;
+pub mod pull_request_create_or_get;
+pub mod pull_request_diff_get;
+pub mod pull_request_diffstat;
+pub mod pull_request_gate_context;
+pub mod pull_request_get;
+pub mod pull_request_incoming_commits;
+pub mod pull_request_jira_issues;
+pub mod pull_request_label;
+pub mod pull_request_label_delete;
+pub mod pull_request_label_list;
+pub mod pull_request_label_post;
+pub mod pull_request_list;
+pub mod pull_request_merge;
+pub mod pull_request_merge_base;
+pub mod pull_request_merge_if_green;
+pub mod pull_request_merge_status_get;
+pub mod pull_request_participant;
+pub mod pull_request_participant_status_put;
+pub mod pull_request_patch;
 pub mod pull_request_post;
+pub mod pull_request_rescoped;
+pub mod pull_request_review;
+pub mod pull_request_settings;
+pub mod pull_request_settings_get;
+pub mod pull_request_settings_update;
+pub mod pull_requests_get_many;
+pub mod repo_default_reviewers_for_pr;
+pub mod repository_list;
+pub mod search;
+pub mod webhook;
+pub mod webhook_list;
+pub mod webhook_post;
+pub mod webhook_test;
+pub mod whoami;
 
 // Note: We intentionally avoid re-exporting types from submodules here
 // to prevent potential namespace collisions. Users should import types
@@ -66,3 +196,32 @@ pub struct Api {
     /// the base URL, the HTTP client, and the API token.
     pub client: Client,
 }
+
+impl Api {
+    /// Access the API endpoints without a client, to build request templates.
+    ///
+    /// The requests built from a template have no base URL or API token, so they must be sent
+    /// with [`send_with`](crate::client::WithClient::send_with), which supplies the client at
+    /// send time. Sending them with `send` returns an `Error::RequestError` instead.
+    ///
+    /// # Returns
+    ///
+    /// An `Api` struct whose requests are sent with `send_with`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::api::Api;
+    ///
+    /// let template = Api::template()
+    ///     .pull_request_list("PROJECT", "REPO")
+    ///     .state("OPEN")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn template() -> Api {
+        Api {
+            client: Client::default(),
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! # My Repository Permission
+//!
+//! This module provides a helper that finds the highest permission the authenticated user
+//! holds on a repository, so that automation can check it may write before it starts, and
+//! fail with a clear message instead of an HTTP 403 halfway through.
+//!
+//! The permission is derived from the repositories the authenticated user is listed with each
+//! permission on, so it includes the permissions granted through groups, public access and
+//! global permissions, and needs no administration rights.
+
+use crate::api::pagination::paged_stream;
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+use futures_util::{future, TryStreamExt};
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+/// A permission on a repository, from the lowest to the highest.
+///
+/// When serialized, it uses the permission names of the API.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum RepositoryPermission {
+    /// Clone and browse the repository
+    #[serde(rename = "REPO_READ")]
+    Read,
+
+    /// Push to the repository and merge pull requests
+    #[serde(rename = "REPO_WRITE")]
+    Write,
+
+    /// Administer the repository, e.g. its settings and permissions
+    #[serde(rename = "REPO_ADMIN")]
+    Admin,
+}
+
+impl RepositoryPermission {
+    /// The repository permission granted by a repository or project permission name.
+    ///
+    /// # Arguments
+    ///
+    /// * `permission` - The name of the permission, e.g. `REPO_WRITE` or `PROJECT_READ`
+    ///
+    /// # Returns
+    ///
+    /// The repository permission, or `None` if the name doesn't grant one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::api::my_repo_permission::RepositoryPermission;
+    ///
+    /// assert_eq!(
+    ///     RepositoryPermission::from_permission("PROJECT_WRITE"),
+    ///     Some(RepositoryPermission::Write)
+    /// );
+    /// assert_eq!(RepositoryPermission::from_permission("LICENSED_USER"), None);
+    /// ```
+    pub fn from_permission(permission: &str) -> Option<Self> {
+        match permission {
+            "REPO_READ" | "PROJECT_READ" => Some(RepositoryPermission::Read),
+            "REPO_WRITE" | "PROJECT_WRITE" => Some(RepositoryPermission::Write),
+            "REPO_ADMIN" | "PROJECT_ADMIN" => Some(RepositoryPermission::Admin),
+            _ => None,
+        }
+    }
+
+    /// The name of the permission, as used in query parameters.
+    ///
+    /// # Returns
+    ///
+    /// `REPO_READ`, `REPO_WRITE` or `REPO_ADMIN`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RepositoryPermission::Read => "REPO_READ",
+            RepositoryPermission::Write => "REPO_WRITE",
+            RepositoryPermission::Admin => "REPO_ADMIN",
+        }
+    }
+
+    /// Check whether the permission allows writing to the repository.
+    ///
+    /// # Returns
+    ///
+    /// `true` for `Write` and `Admin`.
+    pub fn can_write(&self) -> bool {
+        *self >= RepositoryPermission::Write
+    }
+}
+
+/// Request for finding the authenticated user's permission on a repository.
+#[derive(Clone, Debug)]
+pub struct MyRepoPermission {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+}
+
+impl MyRepoPermission {
+    /// Checks whether the authenticated user holds at least a permission on the repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `permission` - The permission to check
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either whether the repository is listed with the permission, or an
+    /// error.
+    async fn is_granted(&self, permission: RepositoryPermission) -> Result<bool, Error> {
+        let repositories = paged_stream(|start| {
+            Api {
+                client: self.client.clone(),
+            }
+            .repository_list()
+            .project_key(self.project_key.clone())
+            .permission(permission)
+            .start(start)
+            .build()
+        })
+        .try_filter(|repository| {
+            future::ready(
+                repository.project.key.eq_ignore_ascii_case(&self.project_key)
+                    && repository.slug.eq_ignore_ascii_case(&self.repository_slug),
+            )
+        });
+        futures_util::pin_mut!(repositories);
+
+        // the pages after the repository are not fetched
+        Ok(repositories.try_next().await?.is_some())
+    }
+}
+
+impl ApiRequest for MyRepoPermission {
+    type Output = RepositoryPermission;
+    const ENDPOINT_NAME: &'static str = "my_repo_permission";
+
+    /// Sends the requests to find the permission.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the highest permission, `None` if the user has none, or an
+    /// error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the requests; as several requests are made, the headers are empty.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        for permission in [
+            RepositoryPermission::Admin,
+            RepositoryPermission::Write,
+            RepositoryPermission::Read,
+        ] {
+            if self.is_granted(permission).await? {
+                return Ok((Some(permission), HeaderMap::new()));
+            }
+        }
+
+        Ok((None, HeaderMap::new()))
+    }
+}
+
+crate::client::impl_with_client!(MyRepoPermission);
+
+impl Api {
+    /// Creates a request to find the highest permission the authenticated user holds on a
+    /// repository.
+    ///
+    /// The repositories of the project are listed with [`Api::repository_list`] for each
+    /// permission, from the highest to the lowest, and the first permission the repository is
+    /// listed with is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let permission = client
+    ///         .api()
+    ///         .my_repo_permission("PROJECT", "REPO")
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if !permission.is_some_and(|permission| permission.can_write()) {
+    ///         return Err("The token can't push to PROJECT/REPO".into());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * No permission is needed: any user can list the repositories they can see.
+    /// * Permissions granted through groups, public access and global permissions are included.
+    pub fn my_repo_permission(self, project_key: &str, repository_slug: &str) -> MyRepoPermission {
+        MyRepoPermission {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_orders_permissions() {
+        assert!(RepositoryPermission::Read < RepositoryPermission::Write);
+        assert!(RepositoryPermission::Write < RepositoryPermission::Admin);
+        assert!(!RepositoryPermission::Read.can_write());
+        assert!(RepositoryPermission::Admin.can_write());
+    } // end of it_orders_permissions
+}
@@ -0,0 +1,457 @@
+//! # Pagination
+//!
+//! This module contains [`Page`], the envelope Bitbucket Server wraps the results of every list
+//! endpoint in: `size`, `limit`, `isLastPage`, `start`, `nextPageStart` and the `values` of the
+//! page. List endpoints return a `Page` of their entity type, so callers page through all of
+//! them the same way, or let [`paged_stream`] fetch the pages as the items are consumed.
+//! [`page_stream`] does the same for callers that need the pages themselves.
+
+use crate::client::ApiRequest;
+use crate::Error;
+use futures_util::stream::{self, Stream, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// A page of results returned by a paginated Bitbucket Server API endpoint.
+///
+/// List endpoints return their results one page at a time. Use [`Page::next_start`] as the
+/// `start` parameter of the next request to fetch the following page.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+    /// The number of items in this page
+    pub size: u64,
+
+    /// The maximum number of items requested for this page
+    pub limit: u64,
+
+    /// Whether this is the last page of results
+    pub is_last_page: bool,
+
+    /// The items in this page
+    pub values: Vec<T>,
+
+    /// The index of the first item in this page
+    pub start: u64,
+
+    /// The index to use as `start` to fetch the next page, if there is one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_start: Option<u64>,
+}
+
+impl<T> Page<T> {
+    /// The `start` of the page following this one.
+    ///
+    /// Uses `next_page_start`, falling back to `start + size` if the server didn't send it.
+    /// The arithmetic is overflow-checked, and a start that doesn't move forward is rejected,
+    /// so paging loops always end.
+    ///
+    /// An empty page also ends paging: when `start` is past the end of the results, servers
+    /// return an empty page, and some of them don't set `isLastPage` on it.
+    ///
+    /// # Returns
+    ///
+    /// The start of the next page, or `None` if this is the last page.
+    pub fn next_start(&self) -> Option<u64> {
+        next_start(
+            self.start,
+            self.size,
+            self.is_last_page || self.values.is_empty(),
+            self.next_page_start,
+        )
+    }
+
+    /// Consumes the page and returns its items sorted by a key.
+    ///
+    /// The sort is stable, so items with equal keys keep the order returned by the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Extracts the key to sort by from an item, e.g. `|commit| commit.author_timestamp`
+    ///
+    /// # Returns
+    ///
+    /// The items of the page, sorted in ascending order of their key.
+    pub fn into_sorted_by_key<K: Ord>(self, key: impl FnMut(&T) -> K) -> Vec<T> {
+        Self::collect_sorted_by_key(std::iter::once(self), key)
+    }
+
+    /// Collects the items of several pages into a single list sorted by a key.
+    ///
+    /// # Arguments
+    ///
+    /// * `pages` - The pages to collect, e.g. all the pages of a listing
+    /// * `key` - Extracts the key to sort by from an item
+    ///
+    /// # Returns
+    ///
+    /// The items of all pages, sorted in ascending order of their key.
+    pub fn collect_sorted_by_key<K: Ord>(
+        pages: impl IntoIterator<Item = Page<T>>,
+        key: impl FnMut(&T) -> K,
+    ) -> Vec<T> {
+        let mut values: Vec<T> = pages.into_iter().flat_map(|page| page.values).collect();
+        values.sort_by_key(key);
+        values
+    }
+}
+
+/// A page of results that [`paged_stream`] can follow.
+///
+/// Implemented by [`Page`], and by the responses that return the paging fields alongside
+/// others, such as [`PullRequestChanges`](crate::api::pull_request_changes_get::PullRequestChanges).
+pub trait Paged {
+    /// The type of the items in the page
+    type Item;
+
+    /// The `start` of the page following this one, or `None` if this is the last page.
+    fn next_start(&self) -> Option<u64>;
+
+    /// Consumes the page and returns its items.
+    fn into_values(self) -> Vec<Self::Item>;
+}
+
+impl<T> Paged for Page<T> {
+    type Item = T;
+
+    fn next_start(&self) -> Option<u64> {
+        Page::next_start(self)
+    }
+
+    fn into_values(self) -> Vec<T> {
+        self.values
+    }
+}
+
+/// Streams all the pages of a list endpoint, fetching each page as the previous one is
+/// consumed.
+///
+/// Use this rather than [`paged_stream`] when the fields sent alongside the items matter, such
+/// as the `fromHash` of [`PullRequestChanges`](crate::api::pull_request_changes_get::PullRequestChanges).
+/// The request for each page is built by `build_request` from the `start` of the page, `0` for
+/// the first one. The stream ends after the last page, and after the first error, which is
+/// yielded as its last item.
+///
+/// # Arguments
+///
+/// * `build_request` - Builds the request for the page starting at the given index, e.g. by
+///   setting `start` on a request builder and building it
+///
+/// # Returns
+///
+/// A stream of all the pages, or of the error that ended it.
+pub fn page_stream<R, E, F>(build_request: F) -> impl Stream<Item = Result<R::Output, Error>>
+where
+    R: ApiRequest,
+    R::Output: Paged,
+    E: Display,
+    F: FnMut(u64) -> Result<R, E>,
+{
+    stream::try_unfold(
+        (build_request, Some(0)),
+        |(mut build_request, start)| async move {
+            let Some(start) = start else {
+                return Ok(None);
+            };
+
+            let request = build_request(start)
+                .map_err(Error::from_builder)?;
+            let Some(page) = request.send().await? else {
+                return Ok(None);
+            };
+
+            let next_start = page.next_start();
+            Ok(Some((page, (build_request, next_start))))
+        },
+    )
+}
+
+/// Streams the items of all the pages of a list endpoint, fetching each page as the items of
+/// the previous one are consumed.
+///
+/// The request for each page is built by `build_request` from the `start` of the page, `0` for
+/// the first one. The stream ends after the last page, and after the first error, which is
+/// yielded as its last item.
+///
+/// # Arguments
+///
+/// * `build_request` - Builds the request for the page starting at the given index, e.g. by
+///   setting `start` on a request builder and building it
+///
+/// # Returns
+///
+/// A stream of the items of all pages, or of the error that ended it.
+///
+/// # Example
+///
+/// ```no_run
+/// use bitbucket_server_rs::api::pagination::paged_stream;
+/// use bitbucket_server_rs::client::new;
+/// use futures_util::TryStreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+///
+///     let changes = paged_stream(|start| {
+///         client
+///             .clone()
+///             .api()
+///             .pull_request_changes_get("PROJECT", "REPO", "123")
+///             .start(start)
+///             .limit(500u64)
+///             .build()
+///     });
+///     futures_util::pin_mut!(changes);
+///
+///     while let Some(change) = changes.try_next().await? {
+///         println!("{}", change.path.to_string);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub fn paged_stream<R, E, F>(
+    build_request: F,
+) -> impl Stream<Item = Result<<R::Output as Paged>::Item, Error>>
+where
+    R: ApiRequest,
+    R::Output: Paged,
+    E: Display,
+    F: FnMut(u64) -> Result<R, E>,
+{
+    page_stream(build_request)
+        .map_ok(|page| stream::iter(page.into_values().into_iter().map(Ok)))
+        .try_flatten()
+}
+
+/// The `start` of the page following a page, if there is one.
+///
+/// Shared by [`Page`] and the endpoints that return the paging fields alongside others, so
+/// they all stop paging on the same conditions.
+///
+/// # Arguments
+///
+/// * `start` - The index of the first item of the page
+/// * `size` - The number of items in the page
+/// * `is_last_page` - Whether the page is known to be the last one
+/// * `next_page_start` - The `nextPageStart` sent by the server, if any
+pub(crate) fn next_start(
+    start: u64,
+    size: u64,
+    is_last_page: bool,
+    next_page_start: Option<u64>,
+) -> Option<u64> {
+    if is_last_page {
+        return None;
+    }
+
+    next_page_start
+        .or_else(|| start.checked_add(size))
+        .filter(|next_start| *next_start > start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::common::Repository;
+
+    fn page(start: u64, values: Vec<(u32, &str)>) -> Page<(u32, String)> {
+        Page {
+            size: values.len() as u64,
+            limit: 25,
+            is_last_page: false,
+            values: values
+                .into_iter()
+                .map(|(id, name)| (id, name.to_string()))
+                .collect(),
+            start,
+            next_page_start: None,
+        }
+    }
+
+    #[test]
+    fn it_can_page_past_u32_max() {
+        let json = r#"{
+            "size": 2,
+            "limit": 2,
+            "isLastPage": false,
+            "values": [[1, "a"], [2, "b"]],
+            "start": 4294967295,
+            "nextPageStart": 4294967297
+        }"#;
+
+        let mut page: Page<(u32, String)> = serde_json::from_str(json).unwrap();
+        assert_eq!(page.next_start(), Some(u32::MAX as u64 + 2));
+
+        page.next_page_start = None;
+        assert_eq!(page.next_start(), Some(u32::MAX as u64 + 2));
+    } // end of it_can_page_past_u32_max
+
+    #[test]
+    fn it_stops_paging_instead_of_overflowing() {
+        let mut last_page = page(0, vec![(1, "a")]);
+        last_page.is_last_page = true;
+        last_page.next_page_start = Some(1);
+        assert_eq!(last_page.next_start(), None);
+
+        let overflowing = page(u64::MAX, vec![(1, "a")]);
+        assert_eq!(overflowing.next_start(), None);
+
+        let mut stuck = page(10, vec![(1, "a")]);
+        stuck.next_page_start = Some(10);
+        assert_eq!(stuck.next_start(), None);
+    } // end of it_stops_paging_instead_of_overflowing
+
+    #[test]
+    fn it_stops_paging_past_the_end() {
+        let json = r#"{"size":0,"limit":25,"isLastPage":true,"values":[],"start":100}"#;
+        let past_end: Page<(u32, String)> = serde_json::from_str(json).unwrap();
+        assert_eq!(past_end.next_start(), None);
+
+        // an empty page ends paging even if the server doesn't mark it as the last one
+        let mut not_marked = page(100, vec![]);
+        not_marked.next_page_start = Some(125);
+        assert_eq!(not_marked.next_start(), None);
+    } // end of it_stops_paging_past_the_end
+
+    #[test]
+    fn it_stops_paging_without_next_page_start_on_last_page() {
+        let json = r#"{"size":1,"limit":25,"isLastPage":true,"values":[[1,"a"]],"start":25}"#;
+        let last_page: Page<(u32, String)> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(last_page.next_start(), None);
+    } // end of it_stops_paging_without_next_page_start_on_last_page
+
+    #[test]
+    fn it_stops_paging_when_next_page_start_does_not_advance() {
+        let json = r#"{
+            "size": 1,
+            "limit": 25,
+            "isLastPage": false,
+            "values": [[1, "a"]],
+            "start": 25,
+            "nextPageStart": 25
+        }"#;
+        let mut stuck: Page<(u32, String)> = serde_json::from_str(json).unwrap();
+        assert_eq!(stuck.next_start(), None);
+
+        stuck.next_page_start = Some(0);
+        assert_eq!(stuck.next_start(), None);
+    } // end of it_stops_paging_when_next_page_start_does_not_advance
+
+    #[test]
+    fn it_can_sort_page_by_key() {
+        let sorted = page(0, vec![(3, "c"), (1, "a"), (2, "b")]).into_sorted_by_key(|(id, _)| *id);
+
+        assert_eq!(
+            sorted.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+    } // end of it_can_sort_page_by_key
+
+    #[test]
+    fn it_can_collect_pages_sorted_by_key() {
+        let pages = vec![
+            page(0, vec![(4, "b"), (1, "a")]),
+            page(2, vec![(2, "b"), (3, "a")]),
+        ];
+
+        let sorted = Page::collect_sorted_by_key(pages, |(_, name)| name.clone());
+
+        // stable: equal keys keep their original order
+        assert_eq!(
+            sorted.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            vec![1, 3, 4, 2]
+        );
+    } // end of it_can_collect_pages_sorted_by_key
+
+    #[test]
+    fn it_can_deserialize_page() {
+        let json = r#"{
+            "size": 2,
+            "limit": 2,
+            "isLastPage": false,
+            "values": ["a", "b"],
+            "start": 0,
+            "nextPageStart": 2
+        }"#;
+
+        let page: Page<String> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            page,
+            Page {
+                size: 2,
+                limit: 2,
+                is_last_page: false,
+                values: vec!["a".to_string(), "b".to_string()],
+                start: 0,
+                next_page_start: Some(2),
+            }
+        );
+    } // end of it_can_deserialize_page
+
+    #[test]
+    fn it_can_deserialize_last_page() {
+        let json = r#"{"size":1,"limit":25,"isLastPage":true,"values":["a"],"start":0}"#;
+
+        let page: Page<String> = serde_json::from_str(json).unwrap();
+
+        assert!(page.is_last_page);
+        assert_eq!(page.next_page_start, None);
+    } // end of it_can_deserialize_last_page
+
+    #[test]
+    fn it_can_deserialize_bitbucket_page() {
+        // as returned by GET /rest/api/latest/projects/PRJ/repos?limit=1
+        let json = r#"{
+            "size": 1,
+            "limit": 1,
+            "isLastPage": false,
+            "values": [
+                {
+                    "slug": "my-repo",
+                    "id": 1,
+                    "name": "My repo",
+                    "hierarchyId": "e3c939f9ef4a7fae272e",
+                    "scmId": "git",
+                    "state": "AVAILABLE",
+                    "statusMessage": "Available",
+                    "forkable": true,
+                    "project": {
+                        "key": "PRJ",
+                        "id": 1,
+                        "name": "My Cool Project",
+                        "description": "The description for my cool project.",
+                        "public": true,
+                        "type": "NORMAL",
+                        "links": {"self": [{"href": "http://link/to/project"}]}
+                    },
+                    "public": true,
+                    "links": {
+                        "clone": [
+                            {"href": "ssh://git@<baseURL>/PRJ/my-repo.git", "name": "ssh"},
+                            {"href": "https://<baseURL>/scm/PRJ/my-repo.git", "name": "http"}
+                        ],
+                        "self": [{"href": "http://link/to/repository"}]
+                    }
+                }
+            ],
+            "start": 0,
+            "nextPageStart": 1
+        }"#;
+
+        let page: Page<Repository> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(page.size, 1);
+        assert_eq!(page.limit, 1);
+        assert!(!page.is_last_page);
+        assert_eq!(page.start, 0);
+        assert_eq!(page.next_page_start, Some(1));
+        assert_eq!(page.values.len(), 1);
+        assert_eq!(page.values[0].slug, "my-repo");
+        assert_eq!(page.values[0].project.key, "PRJ");
+        assert_eq!(page.next_start(), Some(1));
+    } // end of it_can_deserialize_bitbucket_page
+}
@@ -0,0 +1,394 @@
+//! # Pull Request Common Types
+//!
+//! This module contains common types used by the pull request API endpoints when
+//! reading pull requests back from Bitbucket Server. The create-side payload types
+//! live in the `pull_request_post` module.
+
+use crate::api::common::{Links, Repository};
+use crate::api::pull_request_participant::Participant;
+use crate::timestamp::{ts_milliseconds_option, Timestamp};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Represents the state of a pull request in Bitbucket Server.
+///
+/// When serialized, it uses uppercase strings as required by the API. States this crate doesn't
+/// know about deserialize to `Unknown`.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PullRequestState {
+    /// The pull request is open
+    #[serde(rename = "OPEN")]
+    Open,
+
+    /// The pull request was merged
+    #[serde(rename = "MERGED")]
+    Merged,
+
+    /// The pull request was declined
+    #[serde(rename = "DECLINED")]
+    Declined,
+
+    /// The state is unknown or not set
+    #[default]
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+impl From<String> for PullRequestState {
+    /// Converts a string to a PullRequestState.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The string representation of the pull request state
+    ///
+    /// # Returns
+    ///
+    /// The corresponding PullRequestState, or Unknown if the string doesn't match any known state
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "OPEN" => PullRequestState::Open,
+            "MERGED" => PullRequestState::Merged,
+            "DECLINED" => PullRequestState::Declined,
+            _ => PullRequestState::Unknown,
+        }
+    }
+}
+
+/// A pull request, as returned by the API.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequest {
+    /// The ID of the pull request, unique within its repository
+    pub id: u64,
+
+    /// The version of the pull request, used for optimistic locking on updates
+    pub version: i32,
+
+    /// The title of the pull request
+    pub title: String,
+
+    /// The description of the pull request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The description of the pull request rendered to HTML, included when markup is requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_as_html: Option<String>,
+
+    /// The state of the pull request (OPEN, MERGED or DECLINED)
+    pub state: PullRequestState,
+
+    /// Whether the pull request is open
+    pub open: bool,
+
+    /// Whether the pull request is closed
+    pub closed: bool,
+
+    /// The date when the pull request was created
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "ts_milliseconds_option")]
+    pub created_date: Option<Timestamp>,
+
+    /// The date when the pull request was last updated
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "ts_milliseconds_option")]
+    pub updated_date: Option<Timestamp>,
+
+    /// The source branch of the pull request
+    pub from_ref: PullRequestRef,
+
+    /// The target branch of the pull request
+    pub to_ref: PullRequestRef,
+
+    /// The author of the pull request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<Participant>,
+
+    /// The reviewers of the pull request
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reviewers: Vec<Participant>,
+
+    /// The participants of the pull request that are neither the author nor reviewers
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub participants: Vec<Participant>,
+
+    /// Links to the pull request in the web UI
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links: Option<Links>,
+
+    /// Computed metadata of the pull request, such as comment and task counts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<PullRequestProperties>,
+
+    /// The fields of the response that are not modeled above, keyed by their JSON name.
+    ///
+    /// This gives access to fields added by newer servers or plugins without a crate update.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl PullRequest {
+    /// Check whether the pull request was rescoped since a head commit was seen.
+    ///
+    /// Pushing to the source branch rescopes a pull request, changing the latest commit of
+    /// its `from_ref`.
+    ///
+    /// # Arguments
+    ///
+    /// * `head_commit` - The latest commit of the source branch when the pull request was last seen
+    ///
+    /// # Returns
+    ///
+    /// `true` if the source branch now points to a different commit, `false` otherwise.
+    pub fn is_rescoped_since(&self, head_commit: &str) -> bool {
+        self.from_ref.latest_commit != head_commit
+    }
+}
+
+/// The `properties` of a pull request, as returned by the API.
+///
+/// The set of properties depends on the server version and installed plugins, so all of them
+/// are kept as JSON values; the common counts have typed accessors.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PullRequestProperties(pub Map<String, Value>);
+
+impl PullRequestProperties {
+    /// Get a property by key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the property, e.g. `mergeResult`
+    ///
+    /// # Returns
+    ///
+    /// The value of the property, or `None` if it isn't present.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.0.get(key)
+    }
+
+    /// The number of comments on the pull request.
+    pub fn comment_count(&self) -> Option<u64> {
+        self.get("commentCount").and_then(Value::as_u64)
+    }
+
+    /// The number of unresolved tasks on the pull request.
+    pub fn open_task_count(&self) -> Option<u64> {
+        self.get("openTaskCount").and_then(Value::as_u64)
+    }
+
+    /// The number of resolved tasks on the pull request.
+    pub fn resolved_task_count(&self) -> Option<u64> {
+        self.get("resolvedTaskCount").and_then(Value::as_u64)
+    }
+}
+
+/// A Git reference (branch) of a pull request, as returned by the API.
+///
+/// Unlike the create-side `RefInfo`, this includes the display ID and the latest commit
+/// of the reference, so build statuses can be posted to the pull request's head commit
+/// without a second request.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestRef {
+    /// The fully qualified ID of the reference e.g. refs/heads/feature
+    pub id: String,
+
+    /// The short display ID of the reference e.g. feature
+    pub display_id: String,
+
+    /// The hash of the latest commit on the reference
+    pub latest_commit: String,
+
+    /// The repository the reference belongs to
+    pub repository: Repository,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timestamp::epoch_seconds;
+    use crate::api::common::Project;
+
+    #[test]
+    fn it_can_deserialize_ref() {
+        let json = r#"{
+            "id": "refs/heads/feature-branch",
+            "displayId": "feature-branch",
+            "latestCommit": "8d51122def5632836d1cb1026e879069e10a1e13",
+            "repository": {
+                "slug": "my-repo",
+                "id": 1,
+                "name": "My Repo",
+                "project": {
+                    "key": "PRJ",
+                    "id": 2,
+                    "name": "My Project"
+                }
+            }
+        }"#;
+
+        let pull_request_ref: PullRequestRef = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            pull_request_ref,
+            PullRequestRef {
+                id: "refs/heads/feature-branch".to_string(),
+                display_id: "feature-branch".to_string(),
+                latest_commit: "8d51122def5632836d1cb1026e879069e10a1e13".to_string(),
+                repository: Repository {
+                    slug: "my-repo".to_string(),
+                    id: Some(1),
+                    name: Some("My Repo".to_string()),
+                    project: Project {
+                        key: "PRJ".to_string(),
+                        id: Some(2),
+                        name: Some("My Project".to_string()),
+                    },
+                    extra: HashMap::new(),
+                },
+            }
+        );
+    } // end of it_can_deserialize_ref
+
+    #[test]
+    fn it_can_deserialize_pull_request() {
+        let json = r#"{
+            "id": 1,
+            "version": 0,
+            "title": "Add new feature",
+            "description": "Implements the new feature",
+            "state": "OPEN",
+            "open": true,
+            "closed": false,
+            "createdDate": 1738198923000,
+            "updatedDate": 1738198924000,
+            "fromRef": {
+                "id": "refs/heads/feature-branch",
+                "displayId": "feature-branch",
+                "latestCommit": "8d51122def5632836d1cb1026e879069e10a1e13",
+                "repository": {"slug": "my-repo", "project": {"key": "PRJ"}}
+            },
+            "toRef": {
+                "id": "refs/heads/main",
+                "displayId": "main",
+                "latestCommit": "0a943a29376f2336b78312d99e65da17048951db",
+                "repository": {"slug": "my-repo", "project": {"key": "PRJ"}}
+            }
+        }"#;
+
+        let pull_request: PullRequest = serde_json::from_str(json).unwrap();
+
+        assert_eq!(pull_request.id, 1);
+        assert_eq!(pull_request.title, "Add new feature");
+        assert_eq!(pull_request.state, PullRequestState::Open);
+        assert_eq!(
+            epoch_seconds(&pull_request.created_date.unwrap()),
+            1738198923
+        );
+        assert_eq!(
+            pull_request.from_ref.latest_commit,
+            "8d51122def5632836d1cb1026e879069e10a1e13"
+        );
+        assert_eq!(pull_request.to_ref.display_id, "main");
+    } // end of it_can_deserialize_pull_request
+
+    #[test]
+    fn it_detects_rescope_by_head_commit() {
+        let pull_request = PullRequest {
+            from_ref: PullRequestRef {
+                latest_commit: "8d51122def5632836d1cb1026e879069e10a1e13".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert!(!pull_request.is_rescoped_since("8d51122def5632836d1cb1026e879069e10a1e13"));
+        assert!(pull_request.is_rescoped_since("0a943a29376f2336b78312d99e65da17048951db"));
+    } // end of it_detects_rescope_by_head_commit
+
+    #[test]
+    fn it_keeps_unmodeled_fields() {
+        let json = r#"{
+            "id": 1,
+            "version": 0,
+            "title": "Add new feature",
+            "state": "OPEN",
+            "open": true,
+            "closed": false,
+            "draft": true,
+            "fromRef": {
+                "id": "refs/heads/feature-branch",
+                "displayId": "feature-branch",
+                "latestCommit": "8d51122def5632836d1cb1026e879069e10a1e13",
+                "repository": {"slug": "my-repo", "project": {"key": "PRJ"}}
+            },
+            "toRef": {
+                "id": "refs/heads/main",
+                "displayId": "main",
+                "latestCommit": "0a943a29376f2336b78312d99e65da17048951db",
+                "repository": {"slug": "my-repo", "project": {"key": "PRJ"}}
+            }
+        }"#;
+
+        let pull_request: PullRequest = serde_json::from_str(json).unwrap();
+
+        assert_eq!(pull_request.extra.len(), 1);
+        assert_eq!(pull_request.extra["draft"], true);
+        assert!(pull_request.from_ref.repository.extra.is_empty());
+        assert_eq!(
+            serde_json::to_value(&pull_request).unwrap(),
+            serde_json::from_str::<Value>(json).unwrap()
+        );
+    } // end of it_keeps_unmodeled_fields
+
+    #[test]
+    fn it_can_read_properties() {
+        let json = r#"{
+            "commentCount": 4,
+            "openTaskCount": 2,
+            "resolvedTaskCount": 1,
+            "mergeResult": {"outcome": "CLEAN", "current": true}
+        }"#;
+
+        let properties: PullRequestProperties = serde_json::from_str(json).unwrap();
+
+        assert_eq!(properties.comment_count(), Some(4));
+        assert_eq!(properties.open_task_count(), Some(2));
+        assert_eq!(properties.resolved_task_count(), Some(1));
+        assert_eq!(properties.get("mergeResult").unwrap()["outcome"], "CLEAN");
+        assert_eq!(
+            serde_json::to_value(&properties).unwrap(),
+            serde_json::from_str::<Value>(json).unwrap()
+        );
+    } // end of it_can_read_properties
+
+    #[test]
+    fn it_has_no_counts_without_properties() {
+        let properties = PullRequestProperties::default();
+
+        assert_eq!(properties.comment_count(), None);
+        assert_eq!(properties.open_task_count(), None);
+    } // end of it_has_no_counts_without_properties
+
+    #[test]
+    fn it_can_deserialize_states() {
+        let states = vec![
+            ("\"OPEN\"", PullRequestState::Open),
+            ("\"MERGED\"", PullRequestState::Merged),
+            ("\"DECLINED\"", PullRequestState::Declined),
+            ("\"SUPERSEDED\"", PullRequestState::Unknown),
+        ];
+
+        for (json, state) in states {
+            assert_eq!(serde_json::from_str::<PullRequestState>(json).unwrap(), state);
+        }
+        assert_eq!(
+            serde_json::to_string(&PullRequestState::Merged).unwrap(),
+            "\"MERGED\""
+        );
+        assert_eq!(
+            PullRequestState::from("DECLINED".to_string()),
+            PullRequestState::Declined
+        );
+    } // end of it_can_deserialize_states
+}
@@ -3,17 +3,23 @@
 //! This module provides functionality to retrieve changes in pull requests from Bitbucket Server.
 //! It allows fetching the list of files that were modified, added, or deleted in a pull request.
 
+use crate::api::pagination::{next_start, Paged};
 use crate::api::Api;
-use crate::client::{ApiRequest, ApiResponse, Client};
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
 use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// Represents the changes in a pull request.
 ///
 /// This struct contains information about the changes between the source and target branches
 /// of a pull request, including the list of modified files.
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PullRequestChanges {
     /// The commit hash of the source branch
@@ -22,41 +28,86 @@ pub struct PullRequestChanges {
     /// The commit hash of the target branch
     pub to_hash: String,
     
-    /// Array of changes (files that were modified, added, or deleted)
-    #[serde(rename = "values", skip_serializing_if = "Option::is_none")]
-    pub values: Option<Vec<ChangeItem>>,
+    /// The changes (files that were modified, added, or deleted) in this page.
+    ///
+    /// Empty if the pull request has no changes; a response without `values` is treated the
+    /// same way.
+    #[serde(default)]
+    pub values: Vec<ChangeItem>,
+
+    /// The number of changes in this page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+
+    /// The maximum number of changes requested for this page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// Whether this is the last page of changes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_last_page: Option<bool>,
+
+    /// The index of the first change in this page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<u64>,
+
+    /// The index to use as `start` to fetch the next page, if there is one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_page_start: Option<u64>,
 }
 
-/// Represents a single change item in a pull request.
-///
-/// This struct contains information about a single file that was changed in a pull request.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct ChangeItem {
-    /// The content ID of the change
-    #[serde(rename = "contentId")]
-    pub content_id: String,
-    
-    /// The type of change (e.g., "ADD", "MODIFY", "DELETE")
-    #[serde(rename = "type")]
-    pub change_type: String,
-    
-    /// The path of the file that was changed
-    #[serde(rename = "path")]
-    pub path: Path,
+impl PullRequestChanges {
+    /// The `start` of the page of changes following this one, as by [`Page::next_start`].
+    ///
+    /// A response without paging fields is treated as the last page. The `size` sent by the
+    /// server is used rather than the number of `values`, as `filter_path_prefix` may have
+    /// removed all the changes of a page that isn't the last one.
+    ///
+    /// # Returns
+    ///
+    /// The start of the next page, or `None` if this is the last page.
+    ///
+    /// [`Page::next_start`]: crate::api::pagination::Page::next_start
+    pub fn next_start(&self) -> Option<u64> {
+        let size = self.size.unwrap_or(self.values.len() as u64);
+
+        next_start(
+            self.start.unwrap_or_default(),
+            size,
+            self.is_last_page.unwrap_or(true) || size == 0,
+            self.next_page_start,
+        )
+    }
+
+    /// Keep only the changes to files whose path starts with the given prefix.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The path prefix, e.g. `services/billing/`
+    pub fn retain_path_prefix(&mut self, prefix: &str) {
+        self.values.retain(|change| change.path.to_string.starts_with(prefix));
+    }
 }
 
-/// Represents the path of a file in a change.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Path {
-    /// The string representation of the path
-    #[serde(rename = "toString")]
-    pub to_string: String,
+impl Paged for PullRequestChanges {
+    type Item = ChangeItem;
+
+    fn next_start(&self) -> Option<u64> {
+        PullRequestChanges::next_start(self)
+    }
+
+    fn into_values(self) -> Vec<ChangeItem> {
+        self.values
+    }
 }
 
+// `ChangeItem` and `Path` are shared with other endpoints; re-exported here for compatibility.
+pub use crate::api::common::{ChangeItem, Path};
+
 /// Request builder for retrieving pull request changes.
 ///
 /// This struct is used to build and send requests to retrieve changes in a pull request.
-#[derive(Debug, Default, Builder)]
+#[derive(Clone, Debug, Default, Builder)]
 pub struct PullRequestChangesGet {
     /// The HTTP client to use for making requests
     client: Client,
@@ -70,7 +121,8 @@ pub struct PullRequestChangesGet {
     /// The slug of the repository
     repository_slug: String,
     
-    /// The "since" commit hash to stream changes for a RANGE arbitrary change scope
+    /// The "since" commit hash to stream changes for a RANGE arbitrary change scope. Unlike
+    /// the `since` of `commit_list`, refs are not accepted.
     #[builder(setter(into, strip_option), default)]
     since_id: Option<String>,
     
@@ -80,38 +132,55 @@ pub struct PullRequestChangesGet {
     #[builder(setter(into, strip_option), default)]
     change_scope: Option<String>,
     
-    /// The "until" commit hash to stream changes for a RANGE arbitrary change scope
+    /// The "until" commit hash to stream changes for a RANGE arbitrary change scope. Unlike
+    /// the `until` of `commit_list`, refs are not accepted.
     #[builder(setter(into, strip_option), default)]
     until_id: Option<String>,
     
     /// Start number for the page (inclusive). If not passed, first page is assumed.
     #[builder(setter(into, strip_option), default)]
-    start: Option<u32>,
+    start: Option<u64>,
     
     /// Number of items to return. If not passed, a page size of 25 is used.
     #[builder(setter(into, strip_option), default)]
-    limit: Option<u32>,
+    limit: Option<u64>,
     
     /// If true, the response will include all comments on the changed files
     #[builder(setter(into, strip_option), default)]
     with_comments: Option<bool>,
+
+    /// Only return the changes to files whose path starts with this prefix.
+    ///
+    /// The server can't filter changes by path, so the filter is applied to each page once it
+    /// has been received; pages may contain fewer than `limit` changes.
+    #[builder(setter(into, strip_option), default)]
+    filter_path_prefix: Option<String>,
 }
 
-impl ApiRequest for PullRequestChangesGet {
-    type Output = PullRequestChanges;
+impl PullRequestChangesGet {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
 
-    /// Sends the request to retrieve pull request changes.
+impl DescribeRequest for PullRequestChangesGet {
+    /// Builds the request, without sending it.
     ///
     /// # Returns
     ///
-    /// A Result containing either the pull request changes or an error.
-    async fn send(&self) -> ApiResponse<Self::Output> {
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
         let request_uri = format!(
             "api/latest/projects/{}/repos/{}/pull-requests/{}/changes",
             self.project_key, self.repository_slug, self.pull_request_id
         );
 
-        let mut params = HashMap::new();
+        let mut params = BTreeMap::new();
 
         if let Some(since_id) = &self.since_id {
             params.insert("sinceId".to_string(), since_id.clone());
@@ -132,10 +201,59 @@ impl ApiRequest for PullRequestChangesGet {
             params.insert("withComments".to_string(), with_comments.to_string());
         }
 
-        self.client.get::<Self>(&request_uri, Some(params)).await
+        self.client
+            .build_request(Method::GET, &request_uri, Some(params), None)
     }
 }
 
+impl ApiRequest for PullRequestChangesGet {
+    type Output = PullRequestChanges;
+    const ENDPOINT_NAME: &'static str = "pull_request_changes_get";
+
+    /// Sends the request to retrieve pull request changes.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the pull request changes or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        let (changes, headers) = self.execute::<PullRequestChanges>().await?;
+
+        let changes = changes.map(|mut changes| {
+            if let Some(prefix) = &self.filter_path_prefix {
+                changes.retain_path_prefix(prefix);
+            }
+            changes
+        });
+
+        Ok((changes, headers))
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for PullRequestChangesGet {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// The path prefix filter is not applied.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(PullRequestChangesGet);
+
 impl Api {
     /// Creates a request builder for retrieving changes in a pull request.
     ///
@@ -164,7 +282,8 @@ impl Api {
     ///     let response = client
     ///         .api()
     ///         .pull_request_changes_get("PROJECT", "REPO", "123")
-    ///         .limit(50u32)
+    ///         .limit(50u64)
+    ///         .filter_path_prefix("services/billing/")
     ///         .build()?
     ///         .send()
     ///         .await?;
@@ -202,6 +321,76 @@ mod tests {
         assert_eq!(pull_request_changes, mock_struct());
     } // end of it_can_deserialize
 
+    #[test]
+    fn it_can_deserialize_empty_or_missing_values() {
+        let empty: PullRequestChanges =
+            serde_json::from_str(r#"{"fromHash":"from_hash","toHash":"to_hash","values":[]}"#)
+                .unwrap();
+        let missing: PullRequestChanges =
+            serde_json::from_str(r#"{"fromHash":"from_hash","toHash":"to_hash"}"#).unwrap();
+
+        assert!(empty.values.is_empty());
+        assert_eq!(empty, missing);
+    } // end of it_can_deserialize_empty_or_missing_values
+
+    #[test]
+    fn it_can_deserialize_many_values() {
+        let values: Vec<String> = (0..1000)
+            .map(|i| {
+                format!(r#"{{"contentId":"{i}","type":"MODIFY","path":{{"toString":"src/file{i}.rs"}}}}"#)
+            })
+            .collect();
+        let json = format!(
+            r#"{{"fromHash":"from_hash","toHash":"to_hash","values":[{}]}}"#,
+            values.join(",")
+        );
+
+        let pull_request_changes: PullRequestChanges = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(pull_request_changes.values.len(), 1000);
+        assert_eq!(pull_request_changes.values[999].path.to_string, "src/file999.rs");
+    } // end of it_can_deserialize_many_values
+
+    #[test]
+    fn it_can_deserialize_paging_fields() {
+        let json = r#"{
+            "fromHash": "from_hash",
+            "toHash": "to_hash",
+            "size": 1,
+            "limit": 1,
+            "isLastPage": false,
+            "start": 0,
+            "nextPageStart": 1,
+            "values": [{"contentId": "12345", "type": "ADD", "path": {"toString": "path/to/file"}}]
+        }"#;
+
+        let pull_request_changes: PullRequestChanges = serde_json::from_str(json).unwrap();
+
+        assert_eq!(pull_request_changes.is_last_page, Some(false));
+        assert_eq!(pull_request_changes.next_start(), Some(1));
+        assert_eq!(mock_struct().next_start(), None);
+    } // end of it_can_deserialize_paging_fields
+
+    #[test]
+    fn it_keeps_paging_past_a_filtered_out_page() {
+        let json = r#"{
+            "fromHash": "from_hash",
+            "toHash": "to_hash",
+            "size": 1,
+            "limit": 1,
+            "isLastPage": false,
+            "start": 0,
+            "nextPageStart": 1,
+            "values": [{"contentId": "12345", "type": "ADD", "path": {"toString": "path/to/file"}}]
+        }"#;
+
+        let mut pull_request_changes: PullRequestChanges = serde_json::from_str(json).unwrap();
+        pull_request_changes.retain_path_prefix("services/billing/");
+
+        assert!(pull_request_changes.values.is_empty());
+        assert_eq!(pull_request_changes.next_start(), Some(1));
+    } // end of it_keeps_paging_past_a_filtered_out_page
+
     #[test]
     fn it_can_serialize() {
         let pull_request_changes_struct = mock_struct();
@@ -214,7 +403,7 @@ mod tests {
         PullRequestChanges {
             from_hash: "from_hash".to_string(),
             to_hash: "to_hash".to_string(),
-            values: Some(vec![
+            values: vec![
                 ChangeItem {
                     content_id: "12345".to_string(),
                     change_type: "ADD".to_string(),
@@ -229,7 +418,8 @@ mod tests {
                         to_string: "another/target".to_string(),
                     },
                 },
-            ]),
+            ],
+            ..Default::default()
         }
     }
 
@@ -0,0 +1,380 @@
+//! # Pull Request Comment Common Types
+//!
+//! This module contains common types used by the pull request comment API endpoints.
+//! Comments form threads: replies are nested in the `comments` of the comment they reply to.
+
+use crate::api::common::User;
+use crate::timestamp::{ts_milliseconds_option, Timestamp};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// A top-level pull request comment with all its replies nested in `comments`.
+pub type CommentThread = Comment;
+
+/// A pull request comment, as returned by the API, with its replies nested in `comments`.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Comment {
+    /// The ID of the comment
+    pub id: u64,
+
+    /// The version of the comment, required to update or delete it
+    #[serde(default)]
+    pub version: i32,
+
+    /// The text of the comment, in markdown
+    pub text: String,
+
+    /// The author of the comment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<User>,
+
+    /// The date the comment was created
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "ts_milliseconds_option")]
+    pub created_date: Option<Timestamp>,
+
+    /// The date the comment was last updated
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "ts_milliseconds_option")]
+    pub updated_date: Option<Timestamp>,
+
+    /// The severity of the comment (NORMAL or BLOCKER)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+
+    /// The state of the comment (OPEN, PENDING or RESOLVED)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+
+    /// The comment this comment replies to, when it is returned outside of its thread
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<CommentParent>,
+
+    /// The file and line an inline comment is attached to; `None` for a general comment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<CommentAnchor>,
+
+    /// The replies to this comment, oldest first
+    #[serde(default)]
+    pub comments: Vec<Comment>,
+}
+
+impl Comment {
+    /// Find a comment by ID in this comment's thread, including the comment itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the comment to find
+    ///
+    /// # Returns
+    ///
+    /// The comment, or `None` if it isn't part of this thread.
+    pub fn find(&self, id: u64) -> Option<&Comment> {
+        self.reply_chain(id).and_then(|chain| chain.last().copied())
+    }
+
+    /// The chain of comments from this comment down to the comment with the given ID.
+    ///
+    /// The first element is this comment and the last one is the comment with the given ID;
+    /// each element is a reply to the one before it.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the comment at the end of the chain
+    ///
+    /// # Returns
+    ///
+    /// The reply chain, or `None` if the comment isn't part of this thread.
+    pub fn reply_chain(&self, id: u64) -> Option<Vec<&Comment>> {
+        if self.id == id {
+            return Some(vec![self]);
+        }
+
+        self.comments.iter().find_map(|reply| {
+            reply.reply_chain(id).map(|mut chain| {
+                chain.insert(0, self);
+                chain
+            })
+        })
+    }
+
+    /// All comments of this thread in depth-first order, starting with this comment.
+    ///
+    /// # Returns
+    ///
+    /// The comments of the thread, each one followed by its replies.
+    pub fn thread(&self) -> Vec<&Comment> {
+        let mut thread = vec![self];
+        for reply in &self.comments {
+            thread.extend(reply.thread());
+        }
+        thread
+    }
+
+    /// Rebuild the reply trees of comments, e.g. of all the pages of a comment listing.
+    ///
+    /// Each reply is attached to the comment it replies to, given by its `parent` or by
+    /// already being nested in that comment's `comments`. A comment appearing more than once
+    /// is kept once, and a reply whose parent isn't among the comments becomes a thread of
+    /// its own, so no comment is lost.
+    ///
+    /// # Arguments
+    ///
+    /// * `comments` - The comments, flat or partially nested, oldest first
+    ///
+    /// # Returns
+    ///
+    /// The top-level comments with their replies nested, in the order they were given.
+    pub fn build_threads(comments: Vec<Comment>) -> Vec<CommentThread> {
+        // flatten depth-first, keeping the order of the comments and recording their parent
+        let mut flat = Vec::new();
+        let mut seen = HashSet::new();
+        let mut stack: Vec<(Option<u64>, Comment)> =
+            comments.into_iter().rev().map(|comment| (None, comment)).collect();
+        while let Some((nested_in, mut comment)) = stack.pop() {
+            let replies = std::mem::take(&mut comment.comments);
+            stack.extend(replies.into_iter().rev().map(|reply| (Some(comment.id), reply)));
+
+            if seen.insert(comment.id) {
+                let parent = comment.parent.as_ref().map(|parent| parent.id).or(nested_in);
+                flat.push((parent, comment));
+            }
+        }
+
+        let mut roots = Vec::new();
+        let mut replies: HashMap<u64, Vec<Comment>> = HashMap::new();
+        for (parent, comment) in flat {
+            match parent.filter(|parent| *parent != comment.id && seen.contains(parent)) {
+                Some(parent) => replies.entry(parent).or_default().push(comment),
+                None => roots.push(comment),
+            }
+        }
+
+        roots
+            .into_iter()
+            .map(|root| root.with_replies(&mut replies))
+            .collect()
+    }
+
+    /// Nest the replies to this comment, and theirs, taking them from `replies`.
+    fn with_replies(mut self, replies: &mut HashMap<u64, Vec<Comment>>) -> Comment {
+        if let Some(comments) = replies.remove(&self.id) {
+            self.comments = comments
+                .into_iter()
+                .map(|reply| reply.with_replies(replies))
+                .collect();
+        }
+        self
+    }
+}
+
+/// A reference to the comment a reply is posted under.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentParent {
+    /// The ID of the parent comment
+    pub id: u64,
+}
+
+/// The position of an inline comment in the diff of a pull request.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentAnchor {
+    /// The path of the file, relative to the repository root
+    pub path: String,
+
+    /// The line the comment is attached to; `None` for a comment on the whole file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+
+    /// The type of the line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_type: Option<LineType>,
+
+    /// The side of the diff the line number refers to (FROM or TO)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_type: Option<String>,
+
+    /// The previous path of the file, if it was moved or renamed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_path: Option<String>,
+
+    /// The diff the comment was made on (EFFECTIVE, RANGE or COMMIT)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff_type: Option<String>,
+
+    /// The commit the diff starts from, for comments on a commit or a range of commits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_hash: Option<String>,
+
+    /// The commit the diff ends at, for comments on a commit or a range of commits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_hash: Option<String>,
+}
+
+impl CommentAnchor {
+    /// Creates the anchor of a comment on a line of the pull request's diff.
+    ///
+    /// The side of the diff is derived from the line type: removed lines are numbered in the
+    /// source file, added and context lines in the destination file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file
+    /// * `line` - The line number
+    /// * `line_type` - The type of the line
+    ///
+    /// # Returns
+    ///
+    /// An anchor on the line.
+    pub fn line(path: &str, line: u32, line_type: LineType) -> Self {
+        let file_type = match line_type {
+            LineType::Removed => "FROM",
+            LineType::Added | LineType::Context => "TO",
+        };
+
+        Self {
+            path: path.to_owned(),
+            line: Some(line),
+            line_type: Some(line_type),
+            file_type: Some(file_type.to_owned()),
+            ..Default::default()
+        }
+    }
+}
+
+/// The type of a line in the diff of a pull request.
+///
+/// When serialized, it uses the uppercase line types of the API.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LineType {
+    /// A line added by the pull request
+    #[serde(rename = "ADDED")]
+    Added,
+
+    /// A line removed by the pull request
+    #[serde(rename = "REMOVED")]
+    Removed,
+
+    /// A line the pull request doesn't change, shown around the changes
+    #[serde(rename = "CONTEXT")]
+    Context,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timestamp::epoch_seconds;
+
+    fn thread() -> Comment {
+        serde_json::from_str(
+            r#"{
+                "id": 1,
+                "version": 0,
+                "text": "Why is this needed?",
+                "author": {"name": "jdoe"},
+                "createdDate": 1738198923000,
+                "updatedDate": 1738198923000,
+                "comments": [
+                    {
+                        "id": 2,
+                        "version": 0,
+                        "text": "For the migration.",
+                        "comments": [
+                            {"id": 4, "version": 1, "text": "Makes sense, thanks.", "comments": []}
+                        ]
+                    },
+                    {"id": 3, "version": 0, "text": "+1 on the question"}
+                ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn it_can_deserialize_thread() {
+        let comment = thread();
+
+        assert_eq!(comment.author.as_ref().unwrap().name, "jdoe");
+        assert_eq!(epoch_seconds(&comment.created_date.unwrap()), 1738198923);
+        assert_eq!(comment.comments.len(), 2);
+        assert_eq!(comment.comments[0].comments[0].text, "Makes sense, thanks.");
+        assert!(comment.comments[1].comments.is_empty());
+    } // end of it_can_deserialize_thread
+
+    #[test]
+    fn it_can_walk_reply_chain() {
+        let comment = thread();
+
+        let chain: Vec<u64> = comment
+            .reply_chain(4)
+            .unwrap()
+            .iter()
+            .map(|comment| comment.id)
+            .collect();
+        assert_eq!(chain, vec![1, 2, 4]);
+        assert_eq!(comment.find(3).unwrap().text, "+1 on the question");
+        assert!(comment.find(5).is_none());
+
+        let thread: Vec<u64> = comment.thread().iter().map(|comment| comment.id).collect();
+        assert_eq!(thread, vec![1, 2, 4, 3]);
+    } // end of it_can_walk_reply_chain
+
+    #[test]
+    fn it_anchors_lines_on_the_side_of_their_type() {
+        let removed = CommentAnchor::line("src/main.rs", 2, LineType::Removed);
+        let added = CommentAnchor::line("src/main.rs", 3, LineType::Added);
+
+        assert_eq!(
+            serde_json::to_value(&removed).unwrap(),
+            serde_json::json!({"path": "src/main.rs", "line": 2, "lineType": "REMOVED", "fileType": "FROM"})
+        );
+        assert_eq!(added.line_type, Some(LineType::Added));
+        assert_eq!(added.file_type.as_deref(), Some("TO"));
+        assert_eq!(
+            CommentAnchor::line("src/main.rs", 4, LineType::Context).file_type.as_deref(),
+            Some("TO")
+        );
+    } // end of it_anchors_lines_on_the_side_of_their_type
+
+    #[test]
+    fn it_can_build_two_level_thread_from_flat_comments() {
+        let comments: Vec<Comment> = serde_json::from_str(
+            r#"[
+                {"id": 1, "text": "Why is this needed?"},
+                {"id": 2, "text": "For the migration.", "parent": {"id": 1}},
+                {"id": 5, "text": "Typo in the title"},
+                {"id": 4, "text": "Makes sense, thanks.", "parent": {"id": 2}},
+                {"id": 3, "text": "+1 on the question", "parent": {"id": 1}}
+            ]"#,
+        )
+        .unwrap();
+
+        let threads = Comment::build_threads(comments);
+
+        assert_eq!(threads.len(), 2);
+        assert_eq!(threads[0].id, 1);
+        assert_eq!(threads[1].id, 5);
+        let thread: Vec<u64> = threads[0].thread().iter().map(|comment| comment.id).collect();
+        assert_eq!(thread, vec![1, 2, 4, 3]);
+        assert_eq!(threads[0].reply_chain(4).unwrap().len(), 3);
+        assert!(threads[1].comments.is_empty());
+    } // end of it_can_build_two_level_thread_from_flat_comments
+
+    #[test]
+    fn it_can_build_threads_from_nested_and_duplicated_comments() {
+        let nested = thread();
+        // the reply also shows up on its own, e.g. on a later page
+        let duplicate = nested.comments[0].comments[0].clone();
+        let orphan: Comment =
+            serde_json::from_str(r#"{"id": 9, "text": "Late reply", "parent": {"id": 42}}"#)
+                .unwrap();
+
+        let threads = Comment::build_threads(vec![nested, duplicate, orphan]);
+
+        let ids: Vec<Vec<u64>> = threads
+            .iter()
+            .map(|thread| thread.thread().iter().map(|comment| comment.id).collect())
+            .collect();
+        assert_eq!(ids, vec![vec![1, 2, 4, 3], vec![9]]);
+    } // end of it_can_build_threads_from_nested_and_duplicated_comments
+}
@@ -0,0 +1,206 @@
+//! # Pull Request Comment List API
+//!
+//! This module provides functionality to list the comments of a pull request in Bitbucket
+//! Server, one page at a time. Use the `pull_request_comment_threads` module to fetch all
+//! pages of the comments on a file with the replies nested under the comments they reply to.
+
+use crate::api::common::{CommonQuery, Page, RequestExt};
+use crate::api::pull_request_comment::Comment;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+/// Request builder for listing the comments of a pull request.
+///
+/// The common expansions are set through [`RequestExt`], e.g. `avatar_size` includes the
+/// `avatarUrl` of the comment authors.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct PullRequestCommentList {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+
+    /// Only return the comments on this file path
+    #[builder(setter(into, strip_option), default)]
+    path: Option<String>,
+
+    /// Only return comments in this state (OPEN, PENDING or RESOLVED)
+    #[builder(setter(into, strip_option), default)]
+    state: Option<String>,
+
+    /// Start number for the page (inclusive). If not passed, first page is assumed.
+    #[builder(setter(into, strip_option), default)]
+    start: Option<u64>,
+
+    /// Number of items to return. If not passed, a page size of 25 is used.
+    #[builder(setter(into, strip_option), default)]
+    limit: Option<u64>,
+
+    /// The common query flags of the request, set through [`RequestExt`]
+    #[builder(setter(custom), default)]
+    common_query: CommonQuery,
+}
+
+impl RequestExt for PullRequestCommentListBuilder {
+    fn common_query(&mut self) -> &mut CommonQuery {
+        self.common_query.get_or_insert_with(CommonQuery::default)
+    }
+}
+
+impl PullRequestCommentList {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for PullRequestCommentList {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/pull-requests/{}/comments",
+            self.project_key, self.repository_slug, self.pull_request_id
+        );
+
+        let mut params = BTreeMap::new();
+
+        if let Some(path) = &self.path {
+            params.insert("path".to_string(), path.clone());
+        }
+        if let Some(state) = &self.state {
+            params.insert("state".to_string(), state.clone());
+        }
+        if let Some(start) = &self.start {
+            params.insert("start".to_string(), start.to_string());
+        }
+        if let Some(limit) = &self.limit {
+            params.insert("limit".to_string(), limit.to_string());
+        }
+        self.common_query.append_to(&mut params);
+
+        self.client
+            .build_request(Method::GET, &request_uri, Some(params), None)
+    }
+}
+
+impl ApiRequest for PullRequestCommentList {
+    type Output = Page<Comment>;
+    const ENDPOINT_NAME: &'static str = "pull_request_comment_list";
+
+    /// Sends the request to list the comments.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either a page of comments or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for PullRequestCommentList {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(PullRequestCommentList);
+
+impl Api {
+    /// Creates a request builder for listing the comments of a pull request.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let response = client
+    ///         .api()
+    ///         .pull_request_comment_list("PROJECT", "REPO", "1")
+    ///         .path("src/main.rs")
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(page) = response {
+    ///         for comment in page.values {
+    ///             println!("{}: {}", comment.id, comment.text);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * Depending on the server version, replies are either nested in the `comments` of the
+    ///   comment they reply to or listed on their own with a `parent`.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-pull-requests/#api-api-latest-projects-projectkey-repos-repositoryslug-pull-requests-pullrequestid-comments-get)
+    pub fn pull_request_comment_list(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+    ) -> PullRequestCommentListBuilder {
+        let mut builder = PullRequestCommentListBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .pull_request_id(pull_request_id.to_string());
+        builder
+    }
+}
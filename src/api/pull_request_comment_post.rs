@@ -0,0 +1,273 @@
+//! # Pull Request Comment POST API
+//!
+//! This module provides functionality to comment on pull requests in Bitbucket Server,
+//! either as a new top-level comment or as a reply to an existing comment.
+
+use crate::api::pull_request_comment::{Comment, CommentAnchor, CommentParent, LineType};
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// The payload for adding a comment to a pull request.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentPostPayload {
+    /// The text of the comment, in markdown
+    pub text: String,
+
+    /// The comment to reply to; `None` for a top-level comment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<CommentParent>,
+
+    /// The severity of the comment (NORMAL or BLOCKER)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+
+    /// The file and line to attach the comment to; `None` for a general comment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<CommentAnchor>,
+}
+
+impl CommentPostPayload {
+    /// Creates the payload of a reply to an existing comment.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent_id` - The ID of the comment to reply to
+    /// * `text` - The text of the reply
+    ///
+    /// # Returns
+    ///
+    /// A payload with `parent.id` set.
+    pub fn reply(parent_id: u64, text: &str) -> Self {
+        Self {
+            text: text.to_owned(),
+            parent: Some(CommentParent { id: parent_id }),
+            ..Default::default()
+        }
+    }
+
+    /// Creates the payload of an inline comment on a line of the pull request's diff.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file
+    /// * `line` - The line number
+    /// * `line_type` - The type of the line
+    /// * `text` - The text of the comment
+    ///
+    /// # Returns
+    ///
+    /// A payload with the anchor set, see [`CommentAnchor::line`].
+    pub fn inline(path: &str, line: u32, line_type: LineType, text: &str) -> Self {
+        Self {
+            text: text.to_owned(),
+            anchor: Some(CommentAnchor::line(path, line, line_type)),
+            ..Default::default()
+        }
+    }
+}
+
+/// Request for adding a comment to a pull request.
+#[derive(Clone, Debug)]
+pub struct PullRequestCommentPost {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+
+    /// The comment payload to post
+    comment: CommentPostPayload,
+}
+
+impl PullRequestCommentPost {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for PullRequestCommentPost {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/pull-requests/{}/comments",
+            self.project_key, self.repository_slug, self.pull_request_id
+        );
+
+        self.client.build_request(
+            Method::POST,
+            &request_uri,
+            None,
+            Some(&self.client.to_body(&self.comment)),
+        )
+    }
+}
+
+impl ApiRequest for PullRequestCommentPost {
+    type Output = Comment;
+    const ENDPOINT_NAME: &'static str = "pull_request_comment_post";
+
+    /// Sends the request to add the comment.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the created comment, with any replies nested in it, or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for PullRequestCommentPost {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(PullRequestCommentPost);
+
+impl Api {
+    /// Creates a request to add a comment to a pull request.
+    ///
+    /// To reply to an existing comment, set `parent` in the payload, e.g. with
+    /// [`CommentPostPayload::reply`].
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    /// * `comment` - The comment payload
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::pull_request_comment_post::CommentPostPayload;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let reply = client
+    ///         .api()
+    ///         .pull_request_comment_post(
+    ///             "PROJECT",
+    ///             "REPO",
+    ///             "123",
+    ///             &CommentPostPayload::reply(42, "Fixed in the latest commit"),
+    ///         )
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(reply) = reply {
+    ///         println!("Posted comment {}", reply.id);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-pull-requests/#api-api-latest-projects-projectkey-repos-repositoryslug-pull-requests-pullrequestid-comments-post)
+    pub fn pull_request_comment_post(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+        comment: &CommentPostPayload,
+    ) -> PullRequestCommentPost {
+        PullRequestCommentPost {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            pull_request_id: pull_request_id.to_owned(),
+            comment: comment.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_can_serialize_reply() {
+        let payload = CommentPostPayload::reply(42, "Done");
+
+        assert_eq!(
+            serde_json::to_value(&payload).unwrap(),
+            serde_json::json!({"text": "Done", "parent": {"id": 42}})
+        );
+    } // end of it_can_serialize_reply
+
+    #[test]
+    fn it_can_serialize_top_level_comment() {
+        let payload = CommentPostPayload {
+            text: "Looks good".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(serde_json::to_string(&payload).unwrap(), r#"{"text":"Looks good"}"#);
+    } // end of it_can_serialize_top_level_comment
+
+    #[test]
+    fn it_can_serialize_inline_comment() {
+        let payload = CommentPostPayload::inline("src/main.rs", 12, LineType::Removed, "Why remove this?");
+
+        assert_eq!(
+            serde_json::to_value(&payload).unwrap(),
+            serde_json::json!({
+                "text": "Why remove this?",
+                "anchor": {
+                    "path": "src/main.rs",
+                    "line": 12,
+                    "lineType": "REMOVED",
+                    "fileType": "FROM"
+                }
+            })
+        );
+    } // end of it_can_serialize_inline_comment
+}
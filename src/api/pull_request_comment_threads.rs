@@ -0,0 +1,154 @@
+//! # Pull Request Comment Threads
+//!
+//! This module provides a helper that fetches all the comments of a pull request on a file,
+//! following pagination, and rebuilds their reply trees, e.g. for tools mirroring review
+//! discussions that need the full threaded structure rather than a flat page.
+//!
+//! The comments endpoint requires the path of a file, so the threads are listed one file at a
+//! time; general comments on the pull request, which aren't on any file, are not returned.
+
+use crate::api::pagination::paged_stream;
+use crate::api::pull_request_comment::{Comment, CommentThread};
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+use derive_builder::Builder;
+use futures_util::TryStreamExt;
+use reqwest::header::HeaderMap;
+
+/// Request builder for retrieving the comment threads of a pull request.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct PullRequestCommentThreads {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+
+    /// The path of the file to return the threads of
+    path: String,
+}
+
+impl PullRequestCommentThreads {
+    /// Lists all the comments of the pull request on the file, following pagination.
+    async fn comments(&self) -> Result<Vec<Comment>, Error> {
+        paged_stream(|start| {
+            Api {
+                client: self.client.clone(),
+            }
+            .pull_request_comment_list(
+                &self.project_key,
+                &self.repository_slug,
+                &self.pull_request_id,
+            )
+            .path(self.path.clone())
+            .start(start)
+            .build()
+        })
+        .try_collect()
+        .await
+    }
+}
+
+impl ApiRequest for PullRequestCommentThreads {
+    type Output = Vec<CommentThread>;
+    const ENDPOINT_NAME: &'static str = "pull_request_comment_threads";
+
+    /// Sends the requests to list all the comments and rebuilds their threads.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the comment threads or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the requests; as several requests are made, the headers are empty.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        let comments = self.comments().await?;
+
+        Ok((Some(Comment::build_threads(comments)), HeaderMap::new()))
+    }
+}
+
+crate::client::impl_with_client!(PullRequestCommentThreads);
+
+impl Api {
+    /// Creates a request builder for retrieving the comment threads of a pull request on a file.
+    ///
+    /// All pages of comments on the file are listed with [`Api::pull_request_comment_list`],
+    /// and the replies are nested under the comments they reply to with
+    /// [`Comment::build_threads`].
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    /// * `path` - The path of the file to return the threads of, as the comments endpoint
+    ///   requires one
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let threads = client
+    ///         .api()
+    ///         .pull_request_comment_threads("PROJECT", "REPO", "1", "src/main.rs")
+    ///         .build()?
+    ///         .send()
+    ///         .await?
+    ///         .unwrap_or_default();
+    ///
+    ///     for thread in threads {
+    ///         for comment in thread.thread() {
+    ///             println!("{}: {}", comment.id, comment.text);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * General comments on the pull request, which aren't on any file, are not returned.
+    /// * Comments posted while the pages are being listed may be missing or appear twice;
+    ///   duplicates are kept once.
+    pub fn pull_request_comment_threads(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+        path: &str,
+    ) -> PullRequestCommentThreadsBuilder {
+        let mut builder = PullRequestCommentThreadsBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .pull_request_id(pull_request_id.to_string())
+            .path(path.to_string());
+        builder
+    }
+}
@@ -0,0 +1,192 @@
+//! # Pull Request Create-if-Ahead API
+//!
+//! This module provides a way to create pull requests only if their source branch has commits
+//! that the target branch doesn't. Bitbucket Server rejects pull requests between identical
+//! branches with an unhelpful error; this checks first and reports `Error::NoCommitsBetween`
+//! instead, which bots can handle by skipping the pull request.
+
+use crate::api::pull_request::PullRequest;
+use crate::api::pull_request_post::PullRequestPostPayload;
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+
+/// Request for creating a pull request, unless its source branch has no commits ahead of its
+/// target branch.
+#[derive(Clone, Debug)]
+pub struct PullRequestCreateIfAhead {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The pull request payload to post if its source branch is ahead
+    pull_request: PullRequestPostPayload,
+}
+
+impl PullRequestCreateIfAhead {
+    /// Checks whether the source branch of the payload has commits the target branch doesn't.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing whether the source branch is ahead, or an error.
+    async fn is_ahead(&self) -> Result<bool, Error> {
+        let page = Api {
+            client: self.client.clone(),
+        }
+        .compare_commits(
+            &self.project_key,
+            &self.repository_slug,
+            &self.pull_request.from_ref.id,
+            &self.pull_request.to_ref.id,
+        )
+        .limit(1u64)
+        .build()
+        .map_err(Error::from_builder)?
+        .send()
+        .await?;
+
+        Ok(page.is_some_and(|page| !page.values.is_empty()))
+    }
+
+    /// Checks whether the source and target branches are in different repositories, e.g. a
+    /// pull request from a fork, which can't be compared within one repository.
+    fn is_cross_repository(&self) -> bool {
+        let from = &self.pull_request.from_ref.repository;
+        let to = &self.pull_request.to_ref.repository;
+
+        from.slug != to.slug || from.project.key != to.project.key
+    }
+}
+
+impl ApiRequest for PullRequestCreateIfAhead {
+    type Output = PullRequest;
+    const ENDPOINT_NAME: &'static str = "pull_request_create_if_ahead";
+
+    /// Sends the request to create the pull request if its source branch is ahead.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the created or existing pull request, or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// When an existing pull request is returned, the headers are empty.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        if !self.is_cross_repository() && !self.is_ahead().await? {
+            return Err(Error::NoCommitsBetween {
+                from: self.pull_request.from_ref.id.clone(),
+                to: self.pull_request.to_ref.id.clone(),
+            });
+        }
+
+        Api {
+            client: self.client.clone(),
+        }
+        .pull_request_create_or_get(&self.project_key, &self.repository_slug, &self.pull_request)
+        .send_with_headers()
+        .await
+    }
+}
+
+crate::client::impl_with_client!(PullRequestCreateIfAhead);
+
+impl Api {
+    /// Creates a request to create a pull request, only if its source branch has commits ahead
+    /// of its target branch.
+    ///
+    /// The commits on the source branch that are not on the target branch are listed first
+    /// (see [`Api::compare_commits`]). If there are none, `Error::NoCommitsBetween` is returned
+    /// without posting anything. Otherwise the pull request is created as with
+    /// [`Api::pull_request_create_or_get`], so an equivalent open pull request is returned
+    /// instead of a duplicate.
+    ///
+    /// Pull requests between repositories, e.g. from forks, are not checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request` - The pull request payload
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::pull_request_post::{
+    ///     PullRequestPostPayload, RefInfo, RepositoryInfo, ProjectInfo
+    /// };
+    /// use bitbucket_server_rs::Error;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let repository = RepositoryInfo {
+    ///         slug: "my-repo".to_string(),
+    ///         project: ProjectInfo {
+    ///             key: "PROJECT".to_string(),
+    ///         },
+    ///     };
+    ///
+    ///     let pull_request = PullRequestPostPayload {
+    ///         title: "Bump dependencies".to_string(),
+    ///         from_ref: RefInfo {
+    ///             id: "refs/heads/bot/bump-dependencies".to_string(),
+    ///             repository: repository.clone(),
+    ///         },
+    ///         to_ref: RefInfo {
+    ///             id: "refs/heads/main".to_string(),
+    ///             repository,
+    ///         },
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let response = client
+    ///         .api()
+    ///         .pull_request_create_if_ahead("PROJECT", "my-repo", &pull_request)
+    ///         .send()
+    ///         .await;
+    ///
+    ///     match response {
+    ///         Ok(Some(pull_request)) => println!("Pull request #{}", pull_request.id),
+    ///         Err(Error::NoCommitsBetween { .. }) => println!("Nothing to bump"),
+    ///         other => { other?; }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_WRITE permission for the repository to create pull requests.
+    pub fn pull_request_create_if_ahead(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request: &PullRequestPostPayload,
+    ) -> PullRequestCreateIfAhead {
+        PullRequestCreateIfAhead {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            pull_request: pull_request.to_owned(),
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! # Pull Request Create-or-Get API
+//!
+//! This module provides an idempotent way to create pull requests in Bitbucket Server.
+//! Before creating a pull request, the open pull requests of the repository are searched
+//! for one with the same source and target branches, and that one is returned instead of
+//! creating a duplicate. This makes automation that re-runs safe.
+
+use crate::api::pagination::paged_stream;
+use crate::api::pull_request::PullRequest;
+use crate::api::pull_request_post::PullRequestPostPayload;
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+use futures_util::{future, TryStreamExt};
+use reqwest::header::HeaderMap;
+
+/// Request for creating a pull request unless an equivalent one is already open.
+///
+/// This struct is used to send requests that create a pull request, or return the existing
+/// open pull request with the same source and target branches.
+#[derive(Clone, Debug)]
+pub struct PullRequestCreateOrGet {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The pull request payload to post if no matching pull request is open
+    pull_request: PullRequestPostPayload,
+}
+
+impl PullRequestCreateOrGet {
+    /// Finds an open pull request with the same source and target branches as the payload.
+    ///
+    /// The open pull requests into the target branch are listed, and the one from the same
+    /// branch of the same source repository, which may be a fork, is kept.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the matching pull request if one is open, or an error.
+    async fn find_existing(&self) -> Result<Option<PullRequest>, Error> {
+        let from_ref = &self.pull_request.from_ref;
+        let to_ref = &self.pull_request.to_ref;
+
+        let existing = paged_stream(|start| {
+            Api {
+                client: self.client.clone(),
+            }
+            .pull_request_list(&self.project_key, &self.repository_slug)
+            // the pull requests are listed in the target repository, where the source branch
+            // of a pull request from a fork doesn't exist
+            .direction("INCOMING")
+            .at(to_ref.id.as_str())
+            .state("OPEN")
+            .start(start)
+            .build()
+        })
+        .try_filter(|pull_request| {
+            future::ready(
+                pull_request.from_ref.id == from_ref.id
+                    && pull_request.from_ref.repository.slug == from_ref.repository.slug
+                    && pull_request.from_ref.repository.project.key
+                        == from_ref.repository.project.key
+                    && pull_request.to_ref.id == to_ref.id
+                    && pull_request.to_ref.repository.slug == to_ref.repository.slug
+                    && pull_request.to_ref.repository.project.key
+                        == to_ref.repository.project.key,
+            )
+        });
+        futures_util::pin_mut!(existing);
+
+        // the pages after the matching pull request are not fetched
+        existing.try_next().await
+    }
+}
+
+/// Check whether an error is Bitbucket's "pull request already exists" conflict (HTTP 409).
+fn is_conflict(error: &Error) -> bool {
+    matches!(error, Error::HttpClientError { status: 409, .. })
+}
+
+impl ApiRequest for PullRequestCreateOrGet {
+    type Output = PullRequest;
+    const ENDPOINT_NAME: &'static str = "pull_request_create_or_get";
+
+    /// Sends the request to create the pull request, or get the existing one.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the created or existing pull request, or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// When an existing pull request is returned, the headers are empty.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        if let Some(existing) = self.find_existing().await? {
+            return Ok((Some(existing), HeaderMap::new()));
+        }
+
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/pull-requests",
+            self.project_key, self.repository_slug
+        );
+
+        let created = self
+            .client
+            .post_with_headers::<PullRequest>(
+                &request_uri,
+                &self.client.to_body(&self.pull_request),
+            )
+            .await;
+
+        match created {
+            // another run created the pull request in the meantime
+            Err(e) if is_conflict(&e) => match self.find_existing().await? {
+                Some(existing) => Ok((Some(existing), HeaderMap::new())),
+                None => Err(e),
+            },
+            created => created,
+        }
+    }
+}
+
+crate::client::impl_with_client!(PullRequestCreateOrGet);
+
+impl Api {
+    /// Creates a request to create a pull request, unless an equivalent one is already open.
+    ///
+    /// The open pull requests of the repository are listed first (see [`Api::pull_request_list`]).
+    /// If one has the same source and target branches as the payload, it is returned. Otherwise
+    /// the pull request is created as with [`Api::pull_request_post`]. If creation fails because
+    /// the pull request already exists (HTTP 409), the existing pull request is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request` - The pull request payload
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::pull_request_post::{
+    ///     PullRequestPostPayload, RefInfo, RepositoryInfo, ProjectInfo
+    /// };
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let repository = RepositoryInfo {
+    ///         slug: "my-repo".to_string(),
+    ///         project: ProjectInfo {
+    ///             key: "PROJECT".to_string(),
+    ///         },
+    ///     };
+    ///
+    ///     let pull_request = PullRequestPostPayload {
+    ///         title: "Bump dependencies".to_string(),
+    ///         from_ref: RefInfo {
+    ///             id: "refs/heads/bot/bump-dependencies".to_string(),
+    ///             repository: repository.clone(),
+    ///         },
+    ///         to_ref: RefInfo {
+    ///             id: "refs/heads/main".to_string(),
+    ///             repository,
+    ///         },
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     // Safe to call on every run: no duplicate pull request is created
+    ///     let response = client
+    ///         .api()
+    ///         .pull_request_create_or_get("PROJECT", "my-repo", &pull_request)
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(pull_request) = response {
+    ///         println!("Pull request #{}", pull_request.id);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_WRITE permission for the repository to create pull requests.
+    pub fn pull_request_create_or_get(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request: &PullRequestPostPayload,
+    ) -> PullRequestCreateOrGet {
+        PullRequestCreateOrGet {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            pull_request: pull_request.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_can_detect_conflict() {
+        assert!(is_conflict(&Error::HttpClientError {
+            status: 409,
+            body: "already exists".to_string(),
+        }));
+        assert!(!is_conflict(&Error::HttpClientError {
+            status: 400,
+            body: "bad request".to_string(),
+        }));
+        assert!(!is_conflict(&Error::Forbidden {
+            status: 403,
+            body: String::new(),
+        }));
+    } // end of it_can_detect_conflict
+}
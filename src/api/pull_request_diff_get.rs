@@ -0,0 +1,187 @@
+//! # Pull Request Diff API
+//!
+//! This module provides functionality to retrieve the structured diff of a pull request from
+//! Bitbucket Server. Large diffs are truncated by the server; check [`Diffs::is_truncated`]
+//! to detect incomplete results.
+
+use crate::api::diff::Diffs;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+/// Request builder for retrieving the diff of a pull request.
+///
+/// This struct is used to build and send requests to retrieve a pull request's diff.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct PullRequestDiffGet {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+
+    /// The number of context lines to include around added/removed lines
+    #[builder(setter(into, strip_option), default)]
+    context_lines: Option<u32>,
+
+    /// The "since" commit hash to diff from
+    #[builder(setter(into, strip_option), default)]
+    since_id: Option<String>,
+
+    /// The "until" commit hash to diff to
+    #[builder(setter(into, strip_option), default)]
+    until_id: Option<String>,
+
+    /// Use `ignore-all` to ignore whitespace changes
+    #[builder(setter(into, strip_option), default)]
+    whitespace: Option<String>,
+}
+
+impl PullRequestDiffGet {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for PullRequestDiffGet {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/pull-requests/{}/diff",
+            self.project_key, self.repository_slug, self.pull_request_id
+        );
+
+        let mut params = BTreeMap::new();
+
+        if let Some(context_lines) = &self.context_lines {
+            params.insert("contextLines".to_string(), context_lines.to_string());
+        }
+        if let Some(since_id) = &self.since_id {
+            params.insert("sinceId".to_string(), since_id.clone());
+        }
+        if let Some(until_id) = &self.until_id {
+            params.insert("untilId".to_string(), until_id.clone());
+        }
+        if let Some(whitespace) = &self.whitespace {
+            params.insert("whitespace".to_string(), whitespace.clone());
+        }
+
+        self.client
+            .build_request(Method::GET, &request_uri, Some(params), None)
+    }
+}
+
+impl ApiRequest for PullRequestDiffGet {
+    type Output = Diffs;
+    const ENDPOINT_NAME: &'static str = "pull_request_diff_get";
+
+    /// Sends the request to retrieve the pull request diff.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the diff or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for PullRequestDiffGet {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(PullRequestDiffGet);
+
+impl Api {
+    /// Creates a request builder for retrieving the structured diff of a pull request.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let response = client
+    ///         .api()
+    ///         .pull_request_diff_get("PROJECT", "REPO", "123")
+    ///         .context_lines(3u32)
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(diffs) = response {
+    ///         if diffs.is_truncated() {
+    ///             eprintln!("The diff is too large and was truncated");
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// See the [Bitbucket Data Center REST API documentation](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-pull-requests/#api-api-latest-projects-projectkey-repos-repositoryslug-pull-requests-pullrequestid-diff-get)
+    pub fn pull_request_diff_get(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+    ) -> PullRequestDiffGetBuilder {
+        let mut builder = PullRequestDiffGetBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .pull_request_id(pull_request_id.to_string());
+        builder
+    }
+}
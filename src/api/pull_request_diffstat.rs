@@ -0,0 +1,127 @@
+//! # Pull Request Diff Stat API
+//!
+//! This module provides functionality to size a pull request, i.e. count the files it changes
+//! and the lines it adds and removes. The counts are computed from the pull request's diff.
+
+use crate::api::diff::DiffStat;
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+
+/// Request for retrieving the diff stat of a pull request.
+#[derive(Clone, Debug)]
+pub struct PullRequestDiffstat {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+}
+
+impl ApiRequest for PullRequestDiffstat {
+    type Output = DiffStat;
+    const ENDPOINT_NAME: &'static str = "pull_request_diffstat";
+
+    /// Sends the request to retrieve the diff and summarizes it.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the diff stat or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the headers of the diff response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the diff stat and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        let (diffs, headers) = Api {
+            client: self.client.clone(),
+        }
+        .pull_request_diff_get(
+            &self.project_key,
+            &self.repository_slug,
+            &self.pull_request_id,
+        )
+        // only the changed lines are counted
+        .context_lines(0u32)
+        .build()
+        .map_err(Error::from_builder)?
+        .send_with_headers()
+        .await?;
+
+        Ok((diffs.map(|diffs| diffs.stat()), headers))
+    }
+}
+
+crate::client::impl_with_client!(PullRequestDiffstat);
+
+impl Api {
+    /// Creates a request to retrieve the diff stat of a pull request.
+    ///
+    /// The diff of the pull request is fetched with [`Api::pull_request_diff_get`] and its
+    /// added and removed lines are counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     if let Some(stat) = client
+    ///         .api()
+    ///         .pull_request_diffstat("PROJECT", "REPO", "123")
+    ///         .send()
+    ///         .await?
+    ///     {
+    ///         println!(
+    ///             "+{} −{}, {} files",
+    ///             stat.lines_added, stat.lines_removed, stat.files_changed
+    ///         );
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * Binary files count as changed files but have no line counts.
+    /// * If the diff is too large it is truncated by the server, and the counts are lower bounds;
+    ///   check `truncated`.
+    pub fn pull_request_diffstat(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+    ) -> PullRequestDiffstat {
+        PullRequestDiffstat {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            pull_request_id: pull_request_id.to_owned(),
+        }
+    }
+}
@@ -0,0 +1,253 @@
+//! # Pull Request Gate Context API
+//!
+//! This module provides the context merge-gate bots decide on in a single call: the changes of
+//! a pull request, for path-based policy checks, and the build statuses of its head commit.
+//! Both are fetched concurrently instead of one after the other.
+
+use crate::api::build_status::BuildStatusState;
+use crate::api::build_status_get::BuildStatus;
+use crate::api::common::ChangeItem;
+use crate::api::pagination::{page_stream, paged_stream};
+use crate::api::pull_request::PullRequest;
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+use derive_builder::Builder;
+use futures_util::{future, StreamExt, TryStreamExt};
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+/// The changes and build statuses of a pull request, as needed to gate its merge.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GateContext {
+    /// The pull request
+    pub pull_request: PullRequest,
+
+    /// The head commit of the pull request, which the changes and build statuses are for
+    pub commit_id: String,
+
+    /// The files changed by the pull request, across all pages
+    pub changes: Vec<ChangeItem>,
+
+    /// The build statuses of the head commit, across all pages
+    pub build_statuses: Vec<BuildStatus>,
+}
+
+impl GateContext {
+    /// Check whether the head commit is green, i.e. it has build statuses and the latest one
+    /// of each key is successful, so that a failed build which was rerun successfully doesn't
+    /// count.
+    pub fn is_green(&self) -> bool {
+        !self.build_statuses.is_empty()
+            && BuildStatus::latest_per_key(self.build_statuses.iter().cloned())
+                .iter()
+                .all(|build_status| build_status.state == BuildStatusState::Successful)
+    }
+}
+
+/// Request builder for reading the gate context of a pull request.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct PullRequestGateContext {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+
+    /// Only keep the changes to files whose path starts with this prefix.
+    #[builder(setter(into, strip_option), default)]
+    filter_path_prefix: Option<String>,
+
+    /// Number of changes and build statuses per page. If not passed, a page size of 25 is used.
+    #[builder(setter(into, strip_option), default)]
+    limit: Option<u64>,
+}
+
+impl PullRequestGateContext {
+    /// Lists all the changes of the pull request, following pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `commit_id` - The head commit the changes must be computed for
+    async fn changes(&self, commit_id: &str) -> Result<Vec<ChangeItem>, Error> {
+        page_stream(|start| {
+            let mut builder = Api {
+                client: self.client.clone(),
+            }
+            .pull_request_changes_get(
+                &self.project_key,
+                &self.repository_slug,
+                &self.pull_request_id,
+            );
+            builder.start(start);
+            if let Some(limit) = self.limit {
+                builder.limit(limit);
+            }
+            if let Some(prefix) = &self.filter_path_prefix {
+                builder.filter_path_prefix(prefix.clone());
+            }
+            builder.build()
+        })
+        .map(|page| {
+            let page = page?;
+            // the pull request got new commits since it was read
+            if page.from_hash != commit_id {
+                return Err(Error::Unexpected(format!(
+                    "Pull request {} was rescoped from {} to {} while reading its gate context",
+                    self.pull_request_id, commit_id, page.from_hash
+                )));
+            }
+            Ok(page.values)
+        })
+        .try_concat()
+        .await
+    }
+
+    /// Lists all the build statuses of a commit, following pagination.
+    async fn build_statuses(&self, commit_id: &str) -> Result<Vec<BuildStatus>, Error> {
+        paged_stream(|start| {
+            let mut builder = Api {
+                client: self.client.clone(),
+            }
+            .build_status_list(commit_id);
+            builder.start(start);
+            if let Some(limit) = self.limit {
+                builder.limit(limit);
+            }
+            builder.build()
+        })
+        .try_collect()
+        .await
+    }
+}
+
+impl ApiRequest for PullRequestGateContext {
+    type Output = GateContext;
+    const ENDPOINT_NAME: &'static str = "pull_request_gate_context";
+
+    /// Sends the requests to read the gate context of the pull request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the gate context or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the requests; the headers are empty, as the context combines several responses.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        let pull_request = Api {
+            client: self.client.clone(),
+        }
+        .pull_request_get(
+            &self.project_key,
+            &self.repository_slug,
+            &self.pull_request_id,
+        )
+        .build()
+        .map_err(Error::from_builder)?
+        .send()
+        .await?
+        .ok_or_else(|| {
+            Error::Unexpected(format!(
+                "Pull request {} was not returned",
+                self.pull_request_id
+            ))
+        })?;
+
+        let commit_id = pull_request.from_ref.latest_commit.clone();
+
+        // the first error drops the other future, cancelling its request in flight
+        let (changes, build_statuses) =
+            future::try_join(self.changes(&commit_id), self.build_statuses(&commit_id)).await?;
+
+        Ok((
+            Some(GateContext {
+                pull_request,
+                commit_id,
+                changes,
+                build_statuses,
+            }),
+            HeaderMap::new(),
+        ))
+    }
+}
+
+crate::client::impl_with_client!(PullRequestGateContext);
+
+impl Api {
+    /// Creates a request builder for reading the changes and build statuses of a pull request.
+    ///
+    /// The pull request is read first for its head commit. Then all the pages of its changes
+    /// (see [`Api::pull_request_changes_get`]) and of the build statuses of the head commit (see
+    /// [`Api::build_status_list`]) are fetched concurrently, with at most one request of each in
+    /// flight. The first error is returned and the other requests are cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let context = client
+    ///         .api()
+    ///         .pull_request_gate_context("PROJECT", "REPO", "123")
+    ///         .filter_path_prefix("services/billing/")
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(context) = context {
+    ///         if !context.changes.is_empty() && !context.is_green() {
+    ///             println!("Billing changes need green builds");
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * If new commits are pushed while the context is read, an `Error::Unexpected` is
+    ///   returned rather than changes and build statuses of different commits.
+    pub fn pull_request_gate_context(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+    ) -> PullRequestGateContextBuilder {
+        let mut builder = PullRequestGateContextBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .pull_request_id(pull_request_id.to_string());
+        builder
+    }
+}
@@ -0,0 +1,217 @@
+//! # Pull Request GET API
+//!
+//! This module provides functionality to retrieve a single pull request from Bitbucket Server.
+//! Expansions such as avatar URLs and the rendered description can be requested so tooling
+//! rendering pull requests gets everything it needs in one call.
+
+use crate::api::common::{CommonQuery, RequestExt};
+use crate::api::pull_request::PullRequest;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{
+    ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, Conditional, DescribeRequest,
+};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+/// Request builder for retrieving a pull request.
+///
+/// The common expansions are set through [`RequestExt`]: `avatar_size` includes the
+/// `avatarUrl` of the author and reviewers, and `markup` includes the description
+/// rendered to HTML.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct PullRequestGet {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+
+    /// Whether to include the properties of the pull request, e.g. its comment count
+    #[builder(setter(into, strip_option), default)]
+    with_properties: Option<bool>,
+
+    /// The `ETag` of a previous response; if the pull request hasn't changed since, no data is
+    /// returned. See [`PullRequestGet::send_conditional`].
+    #[builder(setter(into, strip_option), default)]
+    if_none_match: Option<String>,
+
+    /// The common query flags of the request, set through [`RequestExt`]
+    #[builder(setter(custom), default)]
+    common_query: CommonQuery,
+}
+
+impl RequestExt for PullRequestGetBuilder {
+    fn common_query(&mut self) -> &mut CommonQuery {
+        self.common_query.get_or_insert_with(CommonQuery::default)
+    }
+}
+
+impl PullRequestGet {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+
+    /// Sends the request, telling an unchanged pull request apart from a changed one.
+    ///
+    /// Use it to poll a pull request: keep the `ETag` of each result and set it with
+    /// `if_none_match` on the next request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the pull request and its `ETag`, a not-modified marker, or an error.
+    pub async fn send_conditional(&self) -> Result<Conditional<PullRequest>, Error> {
+        let (data, headers) = self.execute::<PullRequest>().await?;
+
+        Ok(Conditional::from_response(
+            data,
+            &headers,
+            self.if_none_match.as_deref(),
+        ))
+    }
+}
+
+impl DescribeRequest for PullRequestGet {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/pull-requests/{}",
+            self.project_key, self.repository_slug, self.pull_request_id
+        );
+
+        let mut params = BTreeMap::new();
+
+        if let Some(with_properties) = &self.with_properties {
+            params.insert("withProperties".to_string(), with_properties.to_string());
+        }
+        self.common_query.append_to(&mut params);
+
+        match &self.if_none_match {
+            Some(etag) => self
+                .client
+                .build_conditional_request(&request_uri, Some(params), etag),
+            None => self
+                .client
+                .build_request(Method::GET, &request_uri, Some(params), None),
+        }
+    }
+}
+
+impl ApiRequest for PullRequestGet {
+    type Output = PullRequest;
+    const ENDPOINT_NAME: &'static str = "pull_request_get";
+
+    /// Sends the request to retrieve the pull request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the pull request or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for PullRequestGet {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(PullRequestGet);
+
+impl Api {
+    /// Creates a request builder for retrieving a pull request.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::common::RequestExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let pull_request = client
+    ///         .api()
+    ///         .pull_request_get("PROJECT", "REPO", "123")
+    ///         .avatar_size(64)
+    ///         .markup(true)
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(author) = pull_request.and_then(|pull_request| pull_request.author) {
+    ///         println!("Author avatar: {:?}", author.user.avatar_url);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * To poll a pull request cheaply, set `if_none_match` to the `ETag` of the previous
+    ///   response and use [`PullRequestGet::send_conditional`].
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-pull-requests/#api-api-latest-projects-projectkey-repos-repositoryslug-pull-requests-pullrequestid-get)
+    pub fn pull_request_get(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+    ) -> PullRequestGetBuilder {
+        let mut builder = PullRequestGetBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .pull_request_id(pull_request_id.to_string());
+        builder
+    }
+}
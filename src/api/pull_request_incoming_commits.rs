@@ -0,0 +1,185 @@
+//! # Pull Request Incoming Commits API
+//!
+//! This module provides functionality to list the commits a pull request introduces: the
+//! commits of its source branch since the merge base, rather than the full history of the
+//! source branch. These are the commits reviewers look at and changelog tooling should use.
+
+use crate::api::common::Commit;
+use crate::api::pagination::paged_stream;
+use crate::api::pull_request::PullRequestState;
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+use futures_util::TryStreamExt;
+use reqwest::header::HeaderMap;
+
+/// Request for listing the commits a pull request introduces.
+#[derive(Clone, Debug)]
+pub struct PullRequestIncomingCommits {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+}
+
+impl PullRequestIncomingCommits {
+    /// Lists all the commits reachable from `until` but not from `since`, following pagination.
+    async fn commits_between(&self, since: &str, until: &str) -> Result<Vec<Commit>, Error> {
+        paged_stream(|start| {
+            Api {
+                client: self.client.clone(),
+            }
+            .commit_list(&self.project_key, &self.repository_slug)
+            .since(since)
+            .until(until)
+            .start(start)
+            .build()
+        })
+        .try_collect()
+        .await
+    }
+}
+
+impl ApiRequest for PullRequestIncomingCommits {
+    type Output = Vec<Commit>;
+    const ENDPOINT_NAME: &'static str = "pull_request_incoming_commits";
+
+    /// Sends the requests to list the commits the pull request introduces.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the commits, newest first, or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the requests; as several requests are made, the headers are empty.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        let api = || Api {
+            client: self.client.clone(),
+        };
+
+        let pull_request = api()
+            .pull_request_get(
+                &self.project_key,
+                &self.repository_slug,
+                &self.pull_request_id,
+            )
+            .build()
+            .map_err(Error::from_builder)?
+            .send()
+            .await?
+            .ok_or_else(|| {
+                Error::Unexpected(format!(
+                    "Pull request {} was not returned",
+                    self.pull_request_id
+                ))
+            })?;
+
+        let since = match pull_request.state {
+            // once merged, the source branch is an ancestor of the target branch, so the merge
+            // base is its own head; the target commit it was merged onto is kept instead
+            PullRequestState::Merged => pull_request.to_ref.latest_commit,
+            _ => {
+                api()
+                    .pull_request_merge_base(
+                        &self.project_key,
+                        &self.repository_slug,
+                        &self.pull_request_id,
+                    )
+                    .send()
+                    .await?
+                    .ok_or_else(|| {
+                        Error::Unexpected(format!(
+                            "Pull request {} has no merge base",
+                            self.pull_request_id
+                        ))
+                    })?
+                    .id
+            }
+        };
+
+        let commits = self
+            .commits_between(&since, &pull_request.from_ref.latest_commit)
+            .await?;
+
+        Ok((Some(commits), HeaderMap::new()))
+    }
+}
+
+crate::client::impl_with_client!(PullRequestIncomingCommits);
+
+impl Api {
+    /// Creates a request to list the commits a pull request introduces.
+    ///
+    /// The pull request is read for its head commit, then its merge base is resolved with
+    /// [`Api::pull_request_merge_base`], and the commits between them are listed with
+    /// [`Api::commit_list`]. Commits only on the target branch are left out, as are the
+    /// commits of the source branch that were already on the target branch when it was
+    /// created.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let commits = client
+    ///         .api()
+    ///         .pull_request_incoming_commits("PROJECT", "REPO", "1")
+    ///         .send()
+    ///         .await?
+    ///         .unwrap_or_default();
+    ///
+    ///     for commit in commits {
+    ///         println!("{} {}", commit.display_id, commit.message.unwrap_or_default());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * The commits are listed newest first, as by [`Api::commit_list`].
+    /// * For a merged pull request, the commits are listed from the target commit it was merged
+    ///   onto, as the merge base no longer separates them.
+    pub fn pull_request_incoming_commits(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+    ) -> PullRequestIncomingCommits {
+        PullRequestIncomingCommits {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            pull_request_id: pull_request_id.to_owned(),
+        }
+    }
+}
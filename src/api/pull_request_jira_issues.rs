@@ -0,0 +1,188 @@
+//! # Pull Request Jira Issues API
+//!
+//! This module provides functionality to retrieve the Jira issues linked to a pull request,
+//! through the REST module of Bitbucket Server's Jira integration. Release tooling can use it to
+//! build changelogs from issue keys.
+
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// A Jira issue linked to a pull request.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraIssue {
+    /// The key of the issue, e.g. `PRJ-123`
+    pub key: String,
+
+    /// The URL of the issue in Jira
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Request for retrieving the Jira issues linked to a pull request.
+#[derive(Clone, Debug)]
+pub struct PullRequestJiraIssues {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+}
+
+impl PullRequestJiraIssues {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for PullRequestJiraIssues {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "jira/latest/projects/{}/repos/{}/pull-requests/{}/issues",
+            self.project_key, self.repository_slug, self.pull_request_id
+        );
+
+        self.client
+            .build_request(Method::GET, &request_uri, None, None)
+    }
+}
+
+impl ApiRequest for PullRequestJiraIssues {
+    type Output = Vec<JiraIssue>;
+    const ENDPOINT_NAME: &'static str = "pull_request_jira_issues";
+
+    /// Sends the request to retrieve the linked Jira issues.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the linked issues or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for PullRequestJiraIssues {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(PullRequestJiraIssues);
+
+impl Api {
+    /// Creates a request to retrieve the Jira issues linked to a pull request.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let issues = client
+    ///         .api()
+    ///         .pull_request_jira_issues("PROJECT", "REPO", "123")
+    ///         .send()
+    ///         .await?
+    ///         .unwrap_or_default();
+    ///
+    ///     for issue in issues {
+    ///         println!("{}", issue.key);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * The Jira integration must be configured; the endpoint lives under `/rest/jira`, next to
+    ///   `/rest/api`, so it follows the base path of the client.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-jira-integration/#api-jira-latest-projects-projectkey-repos-repositoryslug-pull-requests-pullrequestid-issues-get)
+    pub fn pull_request_jira_issues(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+    ) -> PullRequestJiraIssues {
+        PullRequestJiraIssues {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            pull_request_id: pull_request_id.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_can_deserialize_issues() {
+        let json = r#"[
+            {"key": "PRJ-1", "url": "https://jira.example.com/browse/PRJ-1"},
+            {"key": "PRJ-2", "url": "https://jira.example.com/browse/PRJ-2"}
+        ]"#;
+
+        let issues: Vec<JiraIssue> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].key, "PRJ-1");
+        assert_eq!(
+            issues[1].url,
+            Some("https://jira.example.com/browse/PRJ-2".to_string())
+        );
+    } // end of it_can_deserialize_issues
+}
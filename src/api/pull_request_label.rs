@@ -0,0 +1,72 @@
+//! # Pull Request Label Common Types
+//!
+//! This module contains common types used by the pull request label API endpoints.
+//!
+//! Labels are only available on newer Bitbucket Server versions. On older versions the label
+//! endpoints don't exist, and requests fail with `Error::Unsupported`.
+
+use crate::Error;
+use serde::{Deserialize, Serialize};
+
+/// A label attached to a pull request, e.g. `dependencies` or `needs-review`.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct Label {
+    /// The name of the label
+    pub name: String,
+}
+
+/// Maps the 404 of a server without label support to `Error::Unsupported`.
+///
+/// A 404 for a project, repository or pull request that doesn't exist reports the missing
+/// entity's `NoSuch...Exception` in the errors of its body, so it is kept as is.
+pub(crate) fn unsupported_if_not_found(error: Error) -> Error {
+    let is_missing_entity = error.server_errors().iter().any(|server_error| {
+        server_error
+            .exception_simple_name()
+            .is_some_and(|name| name.starts_with("NoSuch"))
+    });
+
+    match error {
+        Error::HttpClientError { status: 404, .. } if !is_missing_entity => {
+            Error::Unsupported("pull request labels".to_string())
+        }
+        error => error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_maps_missing_endpoint_to_unsupported() {
+        let error = unsupported_if_not_found(Error::HttpClientError {
+            status: 404,
+            body: "<html>Not Found</html>".to_string(),
+        });
+
+        assert!(error.is_unsupported());
+    } // end of it_maps_missing_endpoint_to_unsupported
+
+    #[test]
+    fn it_ignores_no_such_outside_exception_names() {
+        let error = unsupported_if_not_found(Error::HttpClientError {
+            status: 404,
+            body: "<html>NoSuchPage: the requested URL was not found</html>".to_string(),
+        });
+
+        assert!(error.is_unsupported());
+    } // end of it_ignores_no_such_outside_exception_names
+
+    #[test]
+    fn it_keeps_missing_pull_request_errors() {
+        let error = unsupported_if_not_found(Error::HttpClientError {
+            status: 404,
+            body: r#"{"errors":[{"exceptionName":"com.atlassian.bitbucket.pull.NoSuchPullRequestException"}]}"#
+                .to_string(),
+        });
+
+        assert!(error.is_http_client_error());
+        assert_eq!(error.status(), Some(404));
+    } // end of it_keeps_missing_pull_request_errors
+}
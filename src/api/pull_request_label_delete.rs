@@ -0,0 +1,135 @@
+//! # Pull Request Label DELETE API
+//!
+//! This module provides functionality to remove a label from a pull request in Bitbucket Server.
+
+use crate::api::pull_request_label::unsupported_if_not_found;
+use crate::api::Api;
+use crate::client::{
+    encode_path_segment, ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest,
+};
+use crate::Error;
+use reqwest::Method;
+
+/// Request for removing a label from a pull request.
+#[derive(Clone, Debug)]
+pub struct PullRequestLabelDelete {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+
+    /// The name of the label to remove
+    name: String,
+}
+
+impl ApiRequest for PullRequestLabelDelete {
+    // response has no content
+    type Output = ();
+    const ENDPOINT_NAME: &'static str = "pull_request_label_delete";
+
+    /// Sends the request to remove the label.
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or failure.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.client
+            .send_request::<Self::Output>(self.build_request()?)
+            .await
+            .map_err(unsupported_if_not_found)
+    }
+}
+
+impl DescribeRequest for PullRequestLabelDelete {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/pull-requests/{}/labels/{}",
+            self.project_key,
+            self.repository_slug,
+            self.pull_request_id,
+            encode_path_segment(&self.name)
+        );
+
+        self.client
+            .build_request(Method::DELETE, &request_uri, None, None)
+    }
+}
+
+crate::client::impl_with_client!(PullRequestLabelDelete);
+
+impl Api {
+    /// Creates a request to remove a label from a pull request.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    /// * `name` - The name of the label, e.g. `needs-review`
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     client
+    ///         .api()
+    ///         .pull_request_label_delete("PROJECT", "REPO", "1", "needs-review")
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * Servers without label support return `Error::Unsupported`.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-pull-requests/#api-api-latest-projects-projectkey-repos-repositoryslug-pull-requests-pullrequestid-labels-labelname-delete)
+    pub fn pull_request_label_delete(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+        name: &str,
+    ) -> PullRequestLabelDelete {
+        PullRequestLabelDelete {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            pull_request_id: pull_request_id.to_owned(),
+            name: name.to_owned(),
+        }
+    }
+}
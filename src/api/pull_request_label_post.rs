@@ -0,0 +1,162 @@
+//! # Pull Request Label POST API
+//!
+//! This module provides functionality to add a label to a pull request in Bitbucket Server,
+//! e.g. for triage automation to mark dependency updates.
+
+use crate::api::pull_request_label::{unsupported_if_not_found, Label};
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+/// Request for adding a label to a pull request.
+#[derive(Clone, Debug)]
+pub struct PullRequestLabelPost {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+
+    /// The label to add
+    label: Label,
+}
+
+impl PullRequestLabelPost {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client
+            .send_request::<O>(self.build_request()?)
+            .await
+            .map_err(unsupported_if_not_found)
+    }
+}
+
+impl DescribeRequest for PullRequestLabelPost {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/pull-requests/{}/labels",
+            self.project_key, self.repository_slug, self.pull_request_id
+        );
+
+        self.client.build_request(
+            Method::POST,
+            &request_uri,
+            None,
+            Some(&self.client.to_body(&self.label)),
+        )
+    }
+}
+
+impl ApiRequest for PullRequestLabelPost {
+    type Output = Label;
+    const ENDPOINT_NAME: &'static str = "pull_request_label_post";
+
+    /// Sends the request to add the label.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the added label or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for PullRequestLabelPost {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(PullRequestLabelPost);
+
+impl Api {
+    /// Creates a request to add a label to a pull request.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    /// * `name` - The name of the label, e.g. `dependencies`
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     client
+    ///         .api()
+    ///         .pull_request_label_post("PROJECT", "REPO", "1", "dependencies")
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * Servers without label support return `Error::Unsupported`.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-pull-requests/#api-api-latest-projects-projectkey-repos-repositoryslug-pull-requests-pullrequestid-labels-post)
+    pub fn pull_request_label_post(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+        name: &str,
+    ) -> PullRequestLabelPost {
+        PullRequestLabelPost {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            pull_request_id: pull_request_id.to_owned(),
+            label: Label {
+                name: name.to_owned(),
+            },
+        }
+    }
+}
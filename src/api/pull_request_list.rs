@@ -0,0 +1,221 @@
+//! # Pull Request List API
+//!
+//! This module provides functionality to list the pull requests of a repository in Bitbucket Server.
+//! The results can be filtered by state, by the branch they come from or go to, and paginated.
+
+use crate::api::common::{CommonQuery, Page, RequestExt};
+use crate::api::pull_request::PullRequest;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+/// Request builder for listing pull requests.
+///
+/// This struct is used to build and send requests to list the pull requests of a repository.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct PullRequestList {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// INCOMING to match `at` against the target branch; OUTGOING to match it against
+    /// the source branch. If not passed, INCOMING is assumed.
+    #[builder(setter(into, strip_option), default)]
+    direction: Option<String>,
+
+    /// A fully-qualified branch ID to find pull requests to or from, e.g. refs/heads/master
+    #[builder(setter(into, strip_option), default)]
+    at: Option<String>,
+
+    /// OPEN, DECLINED, MERGED or ALL. If not passed, OPEN is assumed.
+    #[builder(setter(into, strip_option), default)]
+    state: Option<String>,
+
+    /// OLDEST or NEWEST. If not passed, NEWEST is assumed.
+    #[builder(setter(into, strip_option), default)]
+    order: Option<String>,
+
+    /// Start number for the page (inclusive). If not passed, first page is assumed.
+    #[builder(setter(into, strip_option), default)]
+    start: Option<u64>,
+
+    /// Number of items to return. If not passed, a page size of 25 is used.
+    #[builder(setter(into, strip_option), default)]
+    limit: Option<u64>,
+
+    /// Whether to include the properties of each pull request, e.g. its comment count.
+    /// Computing them is expensive on large instances; pass `false` for lighter responses.
+    #[builder(setter(into, strip_option), default)]
+    with_properties: Option<bool>,
+
+    /// The common query flags of the request, set through [`RequestExt`]
+    #[builder(setter(custom), default)]
+    common_query: CommonQuery,
+}
+
+impl RequestExt for PullRequestListBuilder {
+    fn common_query(&mut self) -> &mut CommonQuery {
+        self.common_query.get_or_insert_with(CommonQuery::default)
+    }
+}
+
+impl PullRequestList {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for PullRequestList {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/pull-requests",
+            self.project_key, self.repository_slug
+        );
+
+        let mut params = BTreeMap::new();
+
+        if let Some(direction) = &self.direction {
+            params.insert("direction".to_string(), direction.clone());
+        }
+        if let Some(at) = &self.at {
+            params.insert("at".to_string(), at.clone());
+        }
+        if let Some(state) = &self.state {
+            params.insert("state".to_string(), state.clone());
+        }
+        if let Some(order) = &self.order {
+            params.insert("order".to_string(), order.clone());
+        }
+        if let Some(start) = &self.start {
+            params.insert("start".to_string(), start.to_string());
+        }
+        if let Some(limit) = &self.limit {
+            params.insert("limit".to_string(), limit.to_string());
+        }
+        if let Some(with_properties) = &self.with_properties {
+            params.insert("withProperties".to_string(), with_properties.to_string());
+        }
+        self.common_query.append_to(&mut params);
+
+        self.client
+            .build_request(Method::GET, &request_uri, Some(params), None)
+    }
+}
+
+impl ApiRequest for PullRequestList {
+    type Output = Page<PullRequest>;
+    const ENDPOINT_NAME: &'static str = "pull_request_list";
+
+    /// Sends the request to list pull requests.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either a page of pull requests or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for PullRequestList {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(PullRequestList);
+
+impl Api {
+    /// Creates a request builder for listing the pull requests of a repository.
+    ///
+    /// This method returns a builder that can be used to configure and send a request
+    /// to list pull requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     // List open pull requests coming from a branch
+    ///     let response = client
+    ///         .api()
+    ///         .pull_request_list("PROJECT", "REPO")
+    ///         .direction("OUTGOING")
+    ///         .at("refs/heads/feature-branch")
+    ///         .state("OPEN")
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(page) = response {
+    ///         for pull_request in page.values {
+    ///             println!("#{}: {}", pull_request.id, pull_request.title);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// See the [Bitbucket Data Center REST API documentation](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-pull-requests/#api-api-latest-projects-projectkey-repos-repositoryslug-pull-requests-get)
+    pub fn pull_request_list(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+    ) -> PullRequestListBuilder {
+        let mut builder = PullRequestListBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string());
+        builder
+    }
+}
@@ -0,0 +1,269 @@
+//! # Pull Request Merge API
+//!
+//! This module provides functionality to merge pull requests in Bitbucket Server, either right
+//! away or, with auto-merge, as soon as the pull request's merge checks (e.g. required builds)
+//! pass.
+
+use crate::api::pull_request::{PullRequest, PullRequestState};
+use crate::api::pull_request_settings::MergeStrategyId;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{
+    with_query, ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest,
+};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// The payload for merging a pull request.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestMergePayload {
+    /// Merge once the merge checks pass instead of right away
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_merge: Option<bool>,
+
+    /// The commit message of the merge commit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// The merge strategy to use; the repository's default strategy is used if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_id: Option<MergeStrategyId>,
+}
+
+/// The outcome of a merge request.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MergeOutcome {
+    /// The pull request was merged
+    Merged(PullRequest),
+
+    /// Auto-merge was set up; the pull request will be merged once its merge checks pass
+    AutoMergeScheduled(PullRequest),
+}
+
+impl MergeOutcome {
+    /// The pull request, as returned by the server.
+    pub fn pull_request(&self) -> &PullRequest {
+        match self {
+            MergeOutcome::Merged(pull_request) => pull_request,
+            MergeOutcome::AutoMergeScheduled(pull_request) => pull_request,
+        }
+    }
+
+    /// Check whether the pull request was merged.
+    pub fn is_merged(&self) -> bool {
+        matches!(self, MergeOutcome::Merged(_))
+    }
+}
+
+impl<'de> Deserialize<'de> for MergeOutcome {
+    /// Deserializes the pull request returned by the server; it is only merged if its state is
+    /// `MERGED`, otherwise auto-merge was scheduled.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pull_request = PullRequest::deserialize(deserializer)?;
+
+        Ok(if pull_request.state == PullRequestState::Merged {
+            MergeOutcome::Merged(pull_request)
+        } else {
+            MergeOutcome::AutoMergeScheduled(pull_request)
+        })
+    }
+}
+
+/// Request builder for merging a pull request.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct PullRequestMerge {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+
+    /// The current version of the pull request, as returned when reading it
+    version: i32,
+
+    /// Merge once the merge checks pass instead of right away
+    #[builder(setter(into, strip_option), default)]
+    auto_merge: Option<bool>,
+
+    /// The commit message of the merge commit
+    #[builder(setter(into, strip_option), default)]
+    message: Option<String>,
+
+    /// The merge strategy to use
+    #[builder(setter(into, strip_option), default)]
+    strategy_id: Option<MergeStrategyId>,
+}
+
+impl PullRequestMerge {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for PullRequestMerge {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = with_query(
+            &format!(
+                "api/latest/projects/{}/repos/{}/pull-requests/{}/merge",
+                self.project_key, self.repository_slug, self.pull_request_id
+            ),
+            &[("version", &self.version.to_string())],
+        );
+
+        let payload = PullRequestMergePayload {
+            auto_merge: self.auto_merge,
+            message: self.message.clone(),
+            strategy_id: self.strategy_id.clone(),
+        };
+
+        self.client.build_request(
+            Method::POST,
+            &request_uri,
+            None,
+            Some(&self.client.to_body(&payload)),
+        )
+    }
+}
+
+impl ApiRequest for PullRequestMerge {
+    type Output = MergeOutcome;
+    const ENDPOINT_NAME: &'static str = "pull_request_merge";
+
+    /// Sends the request to merge the pull request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the outcome of the merge or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for PullRequestMerge {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(PullRequestMerge);
+
+impl Api {
+    /// Creates a request builder for merging a pull request.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request. The version of the pull request
+    /// must be set before building.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::pull_request_merge::MergeOutcome;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let outcome = client
+    ///         .api()
+    ///         .pull_request_merge("PROJECT", "REPO", "123")
+    ///         .version(3)
+    ///         .auto_merge(true)
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     match outcome {
+    ///         Some(MergeOutcome::Merged(_)) => println!("Merged"),
+    ///         Some(MergeOutcome::AutoMergeScheduled(_)) => println!("Will merge when green"),
+    ///         None => {}
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_WRITE permission for the repository.
+    /// * Auto-merge requires Bitbucket Data Center 8.15 or later and must be enabled for the
+    ///   repository. If the merge checks already pass, the pull request is merged right away.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-pull-requests/#api-api-latest-projects-projectkey-repos-repositoryslug-pull-requests-pullrequestid-merge-post)
+    pub fn pull_request_merge(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+    ) -> PullRequestMergeBuilder {
+        let mut builder = PullRequestMergeBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .pull_request_id(pull_request_id.to_string());
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_can_serialize_payload() {
+        let payload = PullRequestMergePayload {
+            auto_merge: Some(true),
+            strategy_id: Some(MergeStrategyId::Squash),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_string(&payload).unwrap(),
+            r#"{"autoMerge":true,"strategyId":"squash"}"#
+        );
+    } // end of it_can_serialize_payload
+}
@@ -0,0 +1,153 @@
+//! # Pull Request Merge Base API
+//!
+//! This module provides functionality to retrieve the merge base of a pull request from
+//! Bitbucket Server: the common ancestor of the latest commits of its source and target
+//! branches. Diffing against the merge base rather than the target branch tip leaves out
+//! changes made on the target branch since the source branch was created.
+
+use crate::api::common::Commit;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+/// Request for retrieving the merge base of a pull request.
+#[derive(Clone, Debug)]
+pub struct PullRequestMergeBase {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+}
+
+impl PullRequestMergeBase {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for PullRequestMergeBase {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/pull-requests/{}/merge-base",
+            self.project_key, self.repository_slug, self.pull_request_id
+        );
+
+        self.client
+            .build_request(Method::GET, &request_uri, None, None)
+    }
+}
+
+impl ApiRequest for PullRequestMergeBase {
+    type Output = Commit;
+    const ENDPOINT_NAME: &'static str = "pull_request_merge_base";
+
+    /// Sends the request to retrieve the merge base.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the merge base commit or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for PullRequestMergeBase {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(PullRequestMergeBase);
+
+impl Api {
+    /// Creates a request to retrieve the merge base of a pull request.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     if let Some(merge_base) = client
+    ///         .api()
+    ///         .pull_request_merge_base("PROJECT", "REPO", "1")
+    ///         .send()
+    ///         .await?
+    ///     {
+    ///         println!("Diffing against {}", merge_base.id);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * The merge base is computed from the latest commits of both branches, so it changes
+    ///   when the target branch is merged into the source branch.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-pull-requests/#api-api-latest-projects-projectkey-repos-repositoryslug-pull-requests-pullrequestid-merge-base-get)
+    pub fn pull_request_merge_base(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+    ) -> PullRequestMergeBase {
+        PullRequestMergeBase {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            pull_request_id: pull_request_id.to_owned(),
+        }
+    }
+}
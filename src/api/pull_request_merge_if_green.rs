@@ -0,0 +1,262 @@
+//! # Pull Request Merge If Green API
+//!
+//! This module provides a "safe auto-merge" policy for bots: a pull request is only merged if
+//! no merge check vetoes it and every build status of its head commit is successful. When it
+//! declines to merge, the reason is returned instead of an error.
+
+use crate::api::build_status::BuildStatusState;
+use crate::api::build_status_get::BuildStatus;
+use crate::api::pagination::paged_stream;
+use crate::api::pull_request::PullRequest;
+use crate::api::pull_request_merge_status_get::MergeStatus;
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+use futures_util::TryStreamExt;
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+/// The outcome of a guarded merge.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all_fields = "camelCase")]
+pub enum MergeIfGreenOutcome {
+    /// The pull request was merged
+    Merged(Box<PullRequest>),
+
+    /// A merge check vetoed the merge; see the vetoes of the merge status
+    BlockedByVeto(MergeStatus),
+
+    /// The head commit has no build statuses, or the latest status of some build is not
+    /// successful
+    BlockedByBuild {
+        /// The head commit of the pull request
+        commit_id: String,
+
+        /// The build statuses that are not successful; empty if no build reported a status
+        build_statuses: Vec<BuildStatus>,
+    },
+}
+
+impl MergeIfGreenOutcome {
+    /// Check whether the pull request was merged.
+    pub fn is_merged(&self) -> bool {
+        matches!(self, MergeIfGreenOutcome::Merged(_))
+    }
+}
+
+/// Request for merging a pull request only if its merge checks and builds pass.
+#[derive(Clone, Debug)]
+pub struct PullRequestMergeIfGreen {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+
+    /// The current version of the pull request, as returned when reading it
+    version: i32,
+}
+
+impl PullRequestMergeIfGreen {
+    /// Lists the latest build status of each key of a commit, following pagination.
+    ///
+    /// A build that failed and was then rerun successfully reports both statuses under the
+    /// same key; only the rerun counts.
+    async fn build_statuses(&self, commit_id: &str) -> Result<Vec<BuildStatus>, Error> {
+        let build_statuses: Vec<BuildStatus> = paged_stream(|start| {
+            Api {
+                client: self.client.clone(),
+            }
+            .build_status_list(commit_id)
+            .start(start)
+            .build()
+        })
+        .try_collect()
+        .await?;
+
+        Ok(BuildStatus::latest_per_key(build_statuses))
+    }
+}
+
+impl ApiRequest for PullRequestMergeIfGreen {
+    type Output = MergeIfGreenOutcome;
+    const ENDPOINT_NAME: &'static str = "pull_request_merge_if_green";
+
+    /// Sends the requests to check the pull request and merge it if it is green.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the outcome of the guarded merge or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the requests, keeping the headers of the merge response.
+    ///
+    /// When the merge is declined, no merge request is sent and the headers are empty.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        let api = || Api {
+            client: self.client.clone(),
+        };
+
+        let merge_status = api()
+            .pull_request_merge_status_get(
+                &self.project_key,
+                &self.repository_slug,
+                &self.pull_request_id,
+            )
+            .send()
+            .await?
+            .unwrap_or_default();
+
+        if !merge_status.can_merge {
+            return Ok((
+                Some(MergeIfGreenOutcome::BlockedByVeto(merge_status)),
+                HeaderMap::new(),
+            ));
+        }
+
+        let pull_request = api()
+            .pull_request_get(
+                &self.project_key,
+                &self.repository_slug,
+                &self.pull_request_id,
+            )
+            .build()
+            .map_err(Error::from_builder)?
+            .send()
+            .await?
+            .ok_or_else(|| {
+                Error::Unexpected(format!(
+                    "Pull request {} was not returned",
+                    self.pull_request_id
+                ))
+            })?;
+
+        let commit_id = pull_request.from_ref.latest_commit;
+        let build_statuses = self.build_statuses(&commit_id).await?;
+
+        if build_statuses.is_empty()
+            || build_statuses
+                .iter()
+                .any(|build_status| build_status.state != BuildStatusState::Successful)
+        {
+            return Ok((
+                Some(MergeIfGreenOutcome::BlockedByBuild {
+                    commit_id,
+                    build_statuses: build_statuses
+                        .into_iter()
+                        .filter(|build_status| build_status.state != BuildStatusState::Successful)
+                        .collect(),
+                }),
+                HeaderMap::new(),
+            ));
+        }
+
+        let (merge_outcome, headers) = api()
+            .pull_request_merge(
+                &self.project_key,
+                &self.repository_slug,
+                &self.pull_request_id,
+            )
+            .version(self.version)
+            .build()
+            .map_err(Error::from_builder)?
+            .send_with_headers()
+            .await?;
+
+        Ok((
+            merge_outcome
+                .map(|merge_outcome| MergeIfGreenOutcome::Merged(Box::new(merge_outcome.pull_request().clone()))),
+            headers,
+        ))
+    }
+}
+
+crate::client::impl_with_client!(PullRequestMergeIfGreen);
+
+impl Api {
+    /// Creates a request to merge a pull request only if it is green.
+    ///
+    /// The mergeability of the pull request is checked with
+    /// [`Api::pull_request_merge_status_get`]; if no merge check vetoes it, the build statuses
+    /// of its head commit are listed with [`Api::build_status_list`]. Only if the latest status
+    /// of every build key is successful is the pull request merged with
+    /// [`Api::pull_request_merge`].
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    /// * `version` - The current version of the pull request, as returned when reading it
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::pull_request_merge_if_green::MergeIfGreenOutcome;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let outcome = client
+    ///         .api()
+    ///         .pull_request_merge_if_green("PROJECT", "REPO", "123", 3)
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     match outcome {
+    ///         Some(MergeIfGreenOutcome::Merged(_)) => println!("Merged"),
+    ///         Some(MergeIfGreenOutcome::BlockedByVeto(status)) => {
+    ///             for veto in status.vetoes {
+    ///                 println!("Vetoed: {}", veto.summary_message);
+    ///             }
+    ///         }
+    ///         Some(MergeIfGreenOutcome::BlockedByBuild { build_statuses, .. }) => {
+    ///             println!("{} builds are not green", build_statuses.len());
+    ///         }
+    ///         None => {}
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_WRITE permission for the repository.
+    /// * A head commit without any build status is not considered green.
+    /// * If new commits are pushed while the checks run, the merge fails with a version
+    ///   conflict rather than merging unchecked commits.
+    pub fn pull_request_merge_if_green(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+        version: i32,
+    ) -> PullRequestMergeIfGreen {
+        PullRequestMergeIfGreen {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            pull_request_id: pull_request_id.to_owned(),
+            version,
+        }
+    }
+}
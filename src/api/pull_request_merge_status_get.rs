@@ -0,0 +1,210 @@
+//! # Pull Request Merge Status API
+//!
+//! This module provides functionality to check whether a pull request can be merged in
+//! Bitbucket Server, and if not, which merge checks veto it.
+
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// A merge check preventing a pull request from being merged.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeVeto {
+    /// A short summary of why the merge is vetoed
+    pub summary_message: String,
+
+    /// A detailed explanation of why the merge is vetoed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detailed_message: Option<String>,
+}
+
+/// The mergeability of a pull request, as returned by the API.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeStatus {
+    /// Whether the pull request can be merged
+    pub can_merge: bool,
+
+    /// Whether the pull request has merge conflicts
+    #[serde(default)]
+    pub conflicted: bool,
+
+    /// The outcome of a dry-run merge (CLEAN, CONFLICTED or UNKNOWN)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outcome: Option<String>,
+
+    /// The merge checks preventing the merge
+    #[serde(default)]
+    pub vetoes: Vec<MergeVeto>,
+}
+
+/// Request for checking whether a pull request can be merged.
+#[derive(Clone, Debug)]
+pub struct PullRequestMergeStatusGet {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+}
+
+impl PullRequestMergeStatusGet {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for PullRequestMergeStatusGet {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/pull-requests/{}/merge",
+            self.project_key, self.repository_slug, self.pull_request_id
+        );
+
+        self.client
+            .build_request(Method::GET, &request_uri, None, None)
+    }
+}
+
+impl ApiRequest for PullRequestMergeStatusGet {
+    type Output = MergeStatus;
+    const ENDPOINT_NAME: &'static str = "pull_request_merge_status_get";
+
+    /// Sends the request to check the mergeability of the pull request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the merge status or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for PullRequestMergeStatusGet {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(PullRequestMergeStatusGet);
+
+impl Api {
+    /// Creates a request to check whether a pull request can be merged.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let merge_status = client
+    ///         .api()
+    ///         .pull_request_merge_status_get("PROJECT", "REPO", "123")
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(merge_status) = merge_status {
+    ///         for veto in merge_status.vetoes {
+    ///             println!("Blocked: {}", veto.summary_message);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-pull-requests/#api-api-latest-projects-projectkey-repos-repositoryslug-pull-requests-pullrequestid-merge-get)
+    pub fn pull_request_merge_status_get(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+    ) -> PullRequestMergeStatusGet {
+        PullRequestMergeStatusGet {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            pull_request_id: pull_request_id.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_can_deserialize_vetoes() {
+        let json = r#"{
+            "canMerge": false,
+            "conflicted": false,
+            "outcome": "CLEAN",
+            "vetoes": [
+                {
+                    "summaryMessage": "Not enough approvals",
+                    "detailedMessage": "2 approvals are required, 1 given"
+                }
+            ]
+        }"#;
+
+        let merge_status: MergeStatus = serde_json::from_str(json).unwrap();
+
+        assert!(!merge_status.can_merge);
+        assert_eq!(merge_status.outcome, Some("CLEAN".to_string()));
+        assert_eq!(merge_status.vetoes[0].summary_message, "Not enough approvals");
+    } // end of it_can_deserialize_vetoes
+}
@@ -0,0 +1,121 @@
+//! # Pull Request Participant Common Types
+//!
+//! This module contains common types used by the pull request participant API endpoints,
+//! such as the role and review status of a participant.
+
+use crate::api::common::User;
+use serde::{Deserialize, Serialize};
+
+/// The review status of a pull request participant.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ParticipantStatus {
+    /// The participant has not reviewed the pull request, or withdrew their review
+    #[default]
+    #[serde(rename = "UNAPPROVED")]
+    Unapproved,
+
+    /// The participant approved the pull request
+    #[serde(rename = "APPROVED")]
+    Approved,
+
+    /// The participant thinks the pull request needs more work
+    #[serde(rename = "NEEDS_WORK")]
+    NeedsWork,
+}
+
+/// The role of a user in a pull request.
+///
+/// When serialized, it uses the uppercase role names of the API.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParticipantRole {
+    /// The user opened the pull request
+    #[serde(rename = "AUTHOR")]
+    Author,
+
+    /// The user was asked to review the pull request
+    #[serde(rename = "REVIEWER")]
+    Reviewer,
+
+    /// The user took part in the pull request without being asked to review it, e.g. by
+    /// commenting
+    #[serde(rename = "PARTICIPANT")]
+    Participant,
+}
+
+impl ParticipantRole {
+    /// The name of the role, as used in query parameters.
+    ///
+    /// # Returns
+    ///
+    /// `AUTHOR`, `REVIEWER` or `PARTICIPANT`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ParticipantRole::Author => "AUTHOR",
+            ParticipantRole::Reviewer => "REVIEWER",
+            ParticipantRole::Participant => "PARTICIPANT",
+        }
+    }
+}
+
+/// A participant of a pull request, as returned by the API.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Participant {
+    /// The user taking part in the pull request
+    pub user: User,
+
+    /// The role of the participant (AUTHOR, REVIEWER or PARTICIPANT)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+
+    /// Whether the participant approved the pull request
+    #[serde(default)]
+    pub approved: bool,
+
+    /// The review status of the participant
+    #[serde(default)]
+    pub status: ParticipantStatus,
+
+    /// The latest commit the participant reviewed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_reviewed_commit: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_can_deserialize() {
+        let json = r#"{
+            "user": {"name": "jdoe", "id": 101, "slug": "jdoe"},
+            "lastReviewedCommit": "7549846524f8aed2bd1c0249993ae1bf9d3c9998",
+            "role": "REVIEWER",
+            "approved": false,
+            "status": "NEEDS_WORK"
+        }"#;
+
+        let participant: Participant = serde_json::from_str(json).unwrap();
+
+        assert_eq!(participant.user.name, "jdoe");
+        assert_eq!(participant.status, ParticipantStatus::NeedsWork);
+        assert_eq!(
+            participant.last_reviewed_commit,
+            Some("7549846524f8aed2bd1c0249993ae1bf9d3c9998".to_string())
+        );
+    } // end of it_can_deserialize
+
+    #[test]
+    fn it_names_roles_like_the_api() {
+        for role in [
+            ParticipantRole::Author,
+            ParticipantRole::Reviewer,
+            ParticipantRole::Participant,
+        ] {
+            assert_eq!(
+                serde_json::to_string(&role).unwrap(),
+                format!("\"{}\"", role.as_str())
+            );
+        }
+    }
+}
@@ -0,0 +1,231 @@
+//! # Pull Request Participant Status PUT API
+//!
+//! This module provides functionality to set the review status of a pull request participant in
+//! Bitbucket Server, i.e. to approve a pull request, mark it as needing work, or withdraw a review.
+//!
+//! Setting the status is idempotent, so the request is safe to retry on server errors. When the
+//! `lastReviewedCommit` sent is no longer the latest commit of the pull request, the server
+//! responds with a conflict, reported as [`Error::StaleReview`]. Other conflicts are kept as
+//! they are.
+
+use crate::api::pull_request_participant::{Participant, ParticipantStatus};
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{
+    encode_path_segment, ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest,
+};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// The payload for setting the review status of a participant.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantStatusPayload {
+    /// The new review status
+    pub status: ParticipantStatus,
+
+    /// The latest commit that was reviewed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_reviewed_commit: Option<String>,
+}
+
+/// Request builder for setting the review status of a pull request participant.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct PullRequestParticipantStatusPut {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+
+    /// The slug of the participant, usually the authenticated user
+    user_slug: String,
+
+    /// The new review status
+    status: ParticipantStatus,
+
+    /// The latest commit that was reviewed; if the pull request has moved on since, the
+    /// status is not updated
+    #[builder(setter(into, strip_option), default)]
+    last_reviewed_commit: Option<String>,
+}
+
+impl PullRequestParticipantStatusPut {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        let response = self.client.send_request::<O>(self.build_request()?).await;
+
+        match (response, &self.last_reviewed_commit) {
+            (Err(error), Some(last_reviewed_commit)) if is_stale_review(&error) => {
+                Err(Error::StaleReview {
+                    last_reviewed_commit: last_reviewed_commit.clone(),
+                })
+            }
+            (response, _) => response,
+        }
+    }
+}
+
+/// Check whether an error is the conflict of a review made against an outdated commit, rather
+/// than another conflict, e.g. of a pull request that is no longer open.
+///
+/// The server reports it either as a `PullRequestOutOfDateException`, or with a message about
+/// the pull request having been updated since it was last reviewed.
+fn is_stale_review(error: &Error) -> bool {
+    error.status() == Some(409)
+        && error.server_errors().iter().any(|server_error| {
+            server_error.exception_simple_name() == Some("PullRequestOutOfDateException")
+                || server_error
+                    .message
+                    .as_deref()
+                    .is_some_and(|message| message.to_lowercase().contains("last reviewed"))
+        })
+}
+
+impl DescribeRequest for PullRequestParticipantStatusPut {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/pull-requests/{}/participants/{}",
+            self.project_key,
+            self.repository_slug,
+            self.pull_request_id,
+            encode_path_segment(&self.user_slug)
+        );
+
+        let payload = ParticipantStatusPayload {
+            status: self.status.clone(),
+            last_reviewed_commit: self.last_reviewed_commit.clone(),
+        };
+
+        self.client.build_request(
+            Method::PUT,
+            &request_uri,
+            None,
+            Some(&self.client.to_body(&payload)),
+        )
+    }
+}
+
+impl ApiRequest for PullRequestParticipantStatusPut {
+    type Output = Participant;
+    const ENDPOINT_NAME: &'static str = "pull_request_participant_status_put";
+
+    /// Sends the request to set the review status.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the updated participant or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for PullRequestParticipantStatusPut {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(PullRequestParticipantStatusPut);
+
+impl Api {
+    /// Creates a request builder for setting the review status of a pull request participant.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    /// * `user_slug` - The slug of the participant
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request. The status must be set before building.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::pull_request_participant::ParticipantStatus;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let response = client
+    ///         .api()
+    ///         .pull_request_participant_status_put("PROJECT", "REPO", "123", "my-bot")
+    ///         .status(ParticipantStatus::Approved)
+    ///         .last_reviewed_commit("7549846524f8aed2bd1c0249993ae1bf9d3c9998")
+    ///         .build()?
+    ///         .send()
+    ///         .await;
+    ///
+    ///     match response {
+    ///         Err(e) if e.is_stale_review() => eprintln!("New commits were pushed; review again"),
+    ///         response => {
+    ///             response?;
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository, and can only
+    ///   set their own status.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-pull-requests/#api-api-latest-projects-projectkey-repos-repositoryslug-pull-requests-pullrequestid-participants-userslug-put)
+    pub fn pull_request_participant_status_put(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+        user_slug: &str,
+    ) -> PullRequestParticipantStatusPutBuilder {
+        let mut builder = PullRequestParticipantStatusPutBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .pull_request_id(pull_request_id.to_string())
+            .user_slug(user_slug.to_string());
+        builder
+    }
+}
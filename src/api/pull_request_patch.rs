@@ -0,0 +1,127 @@
+//! # Pull Request Patch API
+//!
+//! This module provides functionality to retrieve the raw patch of a pull request from
+//! Bitbucket Server. The patch is returned as plain text in `git format-patch` form, so it
+//! can be applied locally with `git am`.
+
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+
+/// Request for retrieving the raw patch of a pull request.
+///
+/// This struct is used to send requests to retrieve a pull request's patch.
+#[derive(Clone, Debug)]
+pub struct PullRequestPatch {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+}
+
+impl ApiRequest for PullRequestPatch {
+    // response is raw text, not JSON
+    type Output = String;
+    const ENDPOINT_NAME: &'static str = "pull_request_patch";
+
+    /// Sends the request to retrieve the pull request patch.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the raw patch text or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.client.send_text_request(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for PullRequestPatch {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/pull-requests/{}.patch",
+            self.project_key, self.repository_slug, self.pull_request_id
+        );
+
+        self.client.build_text_request(&request_uri, None)
+    }
+}
+
+crate::client::impl_with_client!(PullRequestPatch);
+
+impl Api {
+    /// Creates a request to retrieve the raw patch of a pull request.
+    ///
+    /// The patch is returned verbatim as a `String`, exactly as produced by the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let patch = client
+    ///         .api()
+    ///         .pull_request_patch("PROJECT", "REPO", "123")
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(patch) = patch {
+    ///         std::fs::write("pr-123.patch", patch)?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-pull-requests/#api-api-latest-projects-projectkey-repos-repositoryslug-pull-requests-pullrequestid-patch-get)
+    pub fn pull_request_patch(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+    ) -> PullRequestPatch {
+        PullRequestPatch {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            pull_request_id: pull_request_id.to_owned(),
+        }
+    }
+}
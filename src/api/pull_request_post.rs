@@ -5,7 +5,12 @@
 //! descriptions, and reviewers.
 
 use crate::api::Api;
-use crate::client::{ApiRequest, ApiResponse, Client};
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 /// A user or group that can be added as a reviewer to a pull request
@@ -15,12 +20,10 @@ pub struct Reviewer {
     pub user: User,
 }
 
-/// A user in Bitbucket Server
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct User {
-    /// The name of the user
-    pub name: String,
-}
+/// A user in Bitbucket Server. Only `name` needs to be set to refer to a reviewer.
+///
+/// This is the shared [`crate::api::common::User`], re-exported here for compatibility.
+pub use crate::api::common::User;
 
 /// The payload for creating a pull request.
 ///
@@ -47,6 +50,81 @@ pub struct PullRequestPostPayload {
     pub reviewers: Option<Vec<Reviewer>>,
 }
 
+impl PullRequestPostPayload {
+    /// Creates a payload for a pull request between branches of different repositories, e.g.
+    /// from a fork to its upstream repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the pull request
+    /// * `source` - The project key and slug of the repository the changes come from
+    /// * `source_branch` - The branch the changes come from, e.g. `refs/heads/feature`
+    /// * `target` - The project key and slug of the repository to merge into
+    /// * `target_branch` - The branch to merge into, e.g. `refs/heads/main`
+    ///
+    /// # Returns
+    ///
+    /// A payload with `fromRef` in the source repository and `toRef` in the target repository.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::pull_request_post::PullRequestPostPayload;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let pull_request = PullRequestPostPayload::cross_repo(
+    ///         "Fix typo",
+    ///         ("~JDOE", "my-repo"),
+    ///         "refs/heads/fix-typo",
+    ///         ("PRJ", "my-repo"),
+    ///         "refs/heads/main",
+    ///     );
+    ///
+    ///     // the pull request is created in the target repository
+    ///     client
+    ///         .api()
+    ///         .pull_request_post("PRJ", "my-repo", &pull_request)
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The pull request must be posted to the target repository, not the source repository.
+    /// * The source repository must be a fork of the target repository.
+    pub fn cross_repo(
+        title: &str,
+        source: (&str, &str),
+        source_branch: &str,
+        target: (&str, &str),
+        target_branch: &str,
+    ) -> Self {
+        let ref_info = |(project_key, repository_slug): (&str, &str), id: &str| RefInfo {
+            id: id.to_string(),
+            repository: RepositoryInfo {
+                slug: repository_slug.to_string(),
+                project: ProjectInfo {
+                    key: project_key.to_string(),
+                },
+            },
+        };
+
+        PullRequestPostPayload {
+            title: title.to_string(),
+            from_ref: ref_info(source, source_branch),
+            to_ref: ref_info(target, target_branch),
+            ..Default::default()
+        }
+    }
+}
+
 /// Information about a Git reference (branch)
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -80,7 +158,7 @@ pub struct ProjectInfo {
 /// Request builder for creating a pull request.
 ///
 /// This struct is used to build and send requests to create pull requests.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct PullRequestPost {
     /// The HTTP client to use for making requests
     client: Client,
@@ -95,8 +173,41 @@ pub struct PullRequestPost {
     pull_request: PullRequestPostPayload,
 }
 
+impl PullRequestPost {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for PullRequestPost {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/pull-requests",
+            self.project_key, self.repository_slug
+        );
+
+        self.client.build_request(
+            Method::POST,
+            &request_uri,
+            None,
+            Some(&self.client.to_body(&self.pull_request)),
+        )
+    }
+}
+
 impl ApiRequest for PullRequestPost {
     type Output = PullRequestPostPayload;
+    const ENDPOINT_NAME: &'static str = "pull_request_post";
 
     /// Sends the request to create a pull request.
     ///
@@ -104,20 +215,33 @@ impl ApiRequest for PullRequestPost {
     ///
     /// A Result containing the created pull request information or an error.
     async fn send(&self) -> ApiResponse<Self::Output> {
-        let request_uri = format!(
-            "api/latest/projects/{}/repos/{}/pull-requests",
-            self.project_key, self.repository_slug
-        );
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
 
-        self.client
-            .post::<Self>(
-                &request_uri,
-                &serde_json::to_string(&self.pull_request).unwrap(),
-            )
-            .await
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for PullRequestPost {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
     }
 }
 
+crate::client::impl_with_client!(PullRequestPost);
+
 impl Api {
     /// Creates a request to create a new pull request.
     ///
@@ -238,6 +362,7 @@ mod tests {
             reviewers: Some(vec![Reviewer {
                 user: User {
                     name: "testuser".to_string(),
+                    ..Default::default()
                 },
             }]),
         };
@@ -281,4 +406,20 @@ mod tests {
             r#"{"title":"Test PR","fromRef":{"id":"refs/heads/feature","repository":{"slug":"test-repo","project":{"key":"TEST"}}},"toRef":{"id":"refs/heads/main","repository":{"slug":"test-repo","project":{"key":"TEST"}}}}"#
         );
     }
+    #[test]
+    fn it_can_serialize_cross_repo() {
+        let pull_request = PullRequestPostPayload::cross_repo(
+            "Test PR",
+            ("~JDOE", "test-fork"),
+            "refs/heads/feature",
+            ("TEST", "test-repo"),
+            "refs/heads/main",
+        );
+
+        let json = serde_json::to_string(&pull_request).unwrap();
+        assert_eq!(
+            json,
+            r#"{"title":"Test PR","fromRef":{"id":"refs/heads/feature","repository":{"slug":"test-fork","project":{"key":"~JDOE"}}},"toRef":{"id":"refs/heads/main","repository":{"slug":"test-repo","project":{"key":"TEST"}}}}"#
+        );
+    }
 }
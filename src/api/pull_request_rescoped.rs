@@ -0,0 +1,154 @@
+//! # Pull Request Rescoped API
+//!
+//! This module provides functionality to check whether a pull request was rescoped, i.e.
+//! whether its source branch got new commits, since a head commit was seen. CI systems poll
+//! this to know when to re-run the builds of a pull request.
+
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+use serde::{Deserialize, Serialize};
+
+/// The result of checking a pull request for a rescope.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestRescope {
+    /// Whether the source branch points to a different commit than the one previously seen
+    pub rescoped: bool,
+
+    /// The latest commit of the source branch, to pass as the previous head on the next check
+    pub latest_commit: String,
+}
+
+/// Request for checking whether a pull request was rescoped since a head commit was seen.
+#[derive(Clone, Debug)]
+pub struct PullRequestRescoped {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+
+    /// The latest commit of the source branch when the pull request was last seen
+    previous_head: String,
+}
+
+impl ApiRequest for PullRequestRescoped {
+    type Output = PullRequestRescope;
+    const ENDPOINT_NAME: &'static str = "pull_request_rescoped";
+
+    /// Sends the request to retrieve the pull request and compare its head commit.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the rescope check result or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the headers of the pull request response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        let (pull_request, headers) = Api {
+            client: self.client.clone(),
+        }
+        .pull_request_get(
+            &self.project_key,
+            &self.repository_slug,
+            &self.pull_request_id,
+        )
+        .build()
+        .map_err(Error::from_builder)?
+        .send_with_headers()
+        .await?;
+
+        let pull_request = pull_request.ok_or_else(|| {
+            Error::Unexpected(format!(
+                "Pull request {} was not returned",
+                self.pull_request_id
+            ))
+        })?;
+
+        Ok((
+            Some(PullRequestRescope {
+                rescoped: pull_request.is_rescoped_since(&self.previous_head),
+                latest_commit: pull_request.from_ref.latest_commit,
+            }),
+            headers,
+        ))
+    }
+}
+
+crate::client::impl_with_client!(PullRequestRescoped);
+
+impl Api {
+    /// Creates a request to check whether a pull request was rescoped since a head commit
+    /// was seen.
+    ///
+    /// The pull request is retrieved with [`Api::pull_request_get`], and the latest commit of
+    /// its source branch is compared with the previously seen head commit.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    /// * `previous_head` - The latest commit of the source branch when the pull request was last seen
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let rescope = client
+    ///         .api()
+    ///         .pull_request_rescoped("PROJECT_KEY", "REPOSITORY_SLUG", "123", "LAST_BUILT_COMMIT")
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(rescope) = rescope.filter(|rescope| rescope.rescoped) {
+    ///         println!("Re-running the builds for {}", rescope.latest_commit);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * Only the head commit is compared, so a force push back to the previously seen commit
+    ///   is not reported as a rescope.
+    pub fn pull_request_rescoped(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+        previous_head: &str,
+    ) -> PullRequestRescoped {
+        PullRequestRescoped {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            pull_request_id: pull_request_id.to_owned(),
+            previous_head: previous_head.to_owned(),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! # Pull Request Review
+//!
+//! This module provides a helper that posts several comments on a pull request at once, like
+//! submitting a review with inline comments in the UI. Bitbucket Server takes one comment per
+//! request, so the comments are posted concurrently, with a bounded number of requests in
+//! flight, and the result of each comment is reported.
+
+use crate::api::pull_request_comment::Comment;
+use crate::api::pull_request_comment_post::CommentPostPayload;
+use crate::api::Api;
+use crate::client::{ApiRequest, Client};
+use crate::Error;
+use derive_builder::Builder;
+use futures_util::stream::{self, StreamExt};
+
+/// The default maximum number of comments posted at the same time.
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Request builder for posting the comments of a review.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct PullRequestReview {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the pull request
+    pull_request_id: String,
+
+    /// The comments of the review, e.g. created with [`CommentPostPayload::inline`]
+    #[builder(setter(each(name = "comment")), default)]
+    comments: Vec<CommentPostPayload>,
+
+    /// The maximum number of comments posted at the same time. If not passed,
+    /// [`DEFAULT_CONCURRENCY`] is used.
+    #[builder(setter(into, strip_option), default)]
+    concurrency: Option<usize>,
+}
+
+/// The outcome of posting a review.
+#[derive(Debug)]
+pub struct ReviewOutcome {
+    /// The result of each comment, in the order the comments were added
+    pub results: Vec<Result<Comment, Error>>,
+}
+
+impl ReviewOutcome {
+    /// Check whether every comment was posted.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no comment failed, including when the review had no comments.
+    pub fn is_success(&self) -> bool {
+        self.results.iter().all(Result::is_ok)
+    }
+
+    /// The comments that were posted, in the order they were added.
+    pub fn posted(&self) -> Vec<&Comment> {
+        self.results.iter().filter_map(|result| result.as_ref().ok()).collect()
+    }
+
+    /// The comments that failed, as their position in the review and their error.
+    pub fn failures(&self) -> Vec<(usize, &Error)> {
+        self.results
+            .iter()
+            .enumerate()
+            .filter_map(|(index, result)| result.as_ref().err().map(|error| (index, error)))
+            .collect()
+    }
+}
+
+impl PullRequestReview {
+    /// Sends the requests to post the comments.
+    ///
+    /// A failed comment doesn't stop the others; its error is reported in place of its
+    /// comment.
+    ///
+    /// # Returns
+    ///
+    /// The result of each comment, in the order the comments were added.
+    pub async fn send(&self) -> ReviewOutcome {
+        // buffered keeps the results in the order of the comments, whatever order they complete in
+        let results = stream::iter(&self.comments)
+            .map(|comment| self.post(comment))
+            .buffered(self.concurrency.unwrap_or(DEFAULT_CONCURRENCY).max(1))
+            .collect()
+            .await;
+
+        ReviewOutcome { results }
+    }
+
+    /// Posts one comment.
+    async fn post(&self, comment: &CommentPostPayload) -> Result<Comment, Error> {
+        Api {
+            client: self.client.clone(),
+        }
+        .pull_request_comment_post(
+            &self.project_key,
+            &self.repository_slug,
+            &self.pull_request_id,
+            comment,
+        )
+        .send()
+        .await?
+        .ok_or_else(|| Error::Unexpected("The posted comment was not returned".to_string()))
+    }
+}
+
+crate::client::impl_with_client!(PullRequestReview);
+
+impl Api {
+    /// Creates a request builder for posting several comments on a pull request at once.
+    ///
+    /// Each comment is posted with [`Api::pull_request_comment_post`].
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
+    ///
+    /// # Returns
+    ///
+    /// A builder for adding the comments and configuring the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::new;
+    /// use bitbucket_server_rs::api::pull_request_comment::LineType;
+    /// use bitbucket_server_rs::api::pull_request_comment_post::CommentPostPayload;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let outcome = client
+    ///         .api()
+    ///         .review("PROJECT", "REPO", "123")
+    ///         .comment(CommentPostPayload::inline("src/lib.rs", 10, LineType::Added, "Needs a test"))
+    ///         .comment(CommentPostPayload::inline("src/main.rs", 4, LineType::Context, "Typo"))
+    ///         .build()?
+    ///         .send()
+    ///         .await;
+    ///
+    ///     for (index, error) in outcome.failures() {
+    ///         eprintln!("Comment {} failed: {}", index, error);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * The comments are independent: posted comments are kept when others fail, so retrying
+    ///   a review should only resend the failed ones.
+    /// * The requests run concurrently on the caller's task; no background tasks are spawned.
+    pub fn review(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: &str,
+    ) -> PullRequestReviewBuilder {
+        let mut builder = PullRequestReviewBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .pull_request_id(pull_request_id.to_string());
+        builder
+    }
+}
@@ -0,0 +1,291 @@
+//! # Pull Request Settings Common Types
+//!
+//! This module contains common types used by the repository pull request settings API endpoints,
+//! such as the merge strategies a repository allows and the merge checks it requires.
+
+use serde::{Deserialize, Serialize};
+
+/// The ID of a merge strategy, as used in the `strategyId` of a merge request.
+///
+/// When serialized, it uses the lowercase IDs required by the API. Unknown IDs are read as
+/// [`MergeStrategyId::Unknown`], so the settings of newer servers can still be read.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum MergeStrategyId {
+    /// Always create a merge commit
+    #[default]
+    #[serde(rename = "no-ff")]
+    NoFastForward,
+
+    /// Fast-forward if possible, otherwise create a merge commit
+    #[serde(rename = "ff")]
+    FastForward,
+
+    /// Only fast-forward; fail if that's not possible
+    #[serde(rename = "ff-only")]
+    FastForwardOnly,
+
+    /// Rebase the source branch, then create a merge commit
+    #[serde(rename = "rebase-no-ff")]
+    RebaseNoFastForward,
+
+    /// Rebase the source branch, then fast-forward
+    #[serde(rename = "rebase-ff-only")]
+    RebaseFastForwardOnly,
+
+    /// Squash the changes into a single commit
+    #[serde(rename = "squash")]
+    Squash,
+
+    /// Squash the changes into a single commit, only if it can be fast-forwarded
+    #[serde(rename = "squash-ff-only")]
+    SquashFastForwardOnly,
+
+    /// A strategy this client doesn't know, e.g. one added by a newer Bitbucket Server; it
+    /// can't be sent back in a merge request
+    #[serde(other)]
+    Unknown,
+}
+
+/// A merge strategy as configured for a repository.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeStrategy {
+    /// The ID of the strategy
+    pub id: MergeStrategyId,
+
+    /// The human-readable name of the strategy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// A description of the strategy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Whether the strategy is enabled for the repository
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// The git flag corresponding to the strategy, e.g. `--no-ff`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flag: Option<String>,
+}
+
+/// The merge configuration of a repository.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeConfig {
+    /// The strategy used when none is specified in a merge request
+    pub default_strategy: MergeStrategy,
+
+    /// All known strategies, enabled or not
+    #[serde(default)]
+    pub strategies: Vec<MergeStrategy>,
+
+    /// Where the configuration comes from (DEFAULT, PROJECT or REPOSITORY)
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub config_type: Option<String>,
+
+    /// The template of the commit message of merge and squash commits; servers before
+    /// Bitbucket 8.13 don't return it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_message_template: Option<CommitMessageTemplate>,
+
+    /// The maximum number of commit summaries listed in the message of merge and squash
+    /// commits; 0 leaves them out
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_summaries: Option<u32>,
+}
+
+/// The template of the commit message of merge and squash commits.
+///
+/// The title and body can contain variables that the server replaces with the details of the
+/// pull request, e.g. its title or ID, when it is merged.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitMessageTemplate {
+    /// Whether the template is used; if not, the server's default message is used
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// The template of the first line of the commit message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// The template of the rest of the commit message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+impl MergeConfig {
+    /// The IDs of the strategies enabled for the repository.
+    ///
+    /// # Returns
+    ///
+    /// The IDs of the enabled strategies, in the order returned by the server.
+    pub fn allowed_strategies(&self) -> Vec<&MergeStrategyId> {
+        self.strategies
+            .iter()
+            .filter(|strategy| strategy.enabled)
+            .map(|strategy| &strategy.id)
+            .collect()
+    }
+
+    /// Check whether a strategy is enabled for the repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the strategy
+    ///
+    /// # Returns
+    ///
+    /// `true` if the strategy can be used to merge pull requests.
+    pub fn allows(&self, id: &MergeStrategyId) -> bool {
+        self.allowed_strategies().contains(&id)
+    }
+}
+
+/// The pull request settings of a repository.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestSettings {
+    /// The merge configuration of the repository
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_config: Option<MergeConfig>,
+
+    /// The number of approvals required to merge
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_approvers: Option<u32>,
+
+    /// Whether all reviewers must approve before merging
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_all_approvers: Option<bool>,
+
+    /// Whether all tasks must be resolved before merging
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_all_tasks_complete: Option<bool>,
+
+    /// The number of successful builds required to merge
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_successful_builds: Option<u32>,
+
+    /// Whether approvals are reset when new commits are pushed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unapprove_on_update: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_can_map_strategy_ids() {
+        let ids = vec![
+            (MergeStrategyId::NoFastForward, "\"no-ff\""),
+            (MergeStrategyId::FastForward, "\"ff\""),
+            (MergeStrategyId::FastForwardOnly, "\"ff-only\""),
+            (MergeStrategyId::RebaseNoFastForward, "\"rebase-no-ff\""),
+            (MergeStrategyId::RebaseFastForwardOnly, "\"rebase-ff-only\""),
+            (MergeStrategyId::Squash, "\"squash\""),
+            (MergeStrategyId::SquashFastForwardOnly, "\"squash-ff-only\""),
+        ];
+
+        for (id, json) in ids {
+            assert_eq!(serde_json::to_string(&id).unwrap(), json);
+            assert_eq!(serde_json::from_str::<MergeStrategyId>(json).unwrap(), id);
+        }
+    } // end of it_can_map_strategy_ids
+
+    #[test]
+    fn it_can_deserialize_settings() {
+        let json = r#"{
+            "mergeConfig": {
+                "defaultStrategy": {
+                    "id": "squash",
+                    "name": "Squash",
+                    "description": "Combine all commits into one new non-merge commit on the target branch.",
+                    "enabled": true,
+                    "flag": "--squash"
+                },
+                "strategies": [
+                    {"id": "no-ff", "name": "Merge commit", "enabled": false, "flag": "--no-ff"},
+                    {"id": "squash", "name": "Squash", "enabled": true, "flag": "--squash"},
+                    {"id": "ff-only", "name": "Fast-forward only", "enabled": true, "flag": "--ff-only"}
+                ],
+                "type": "REPOSITORY"
+            }
+        }"#;
+
+        let settings: PullRequestSettings = serde_json::from_str(json).unwrap();
+        let merge_config = settings.merge_config.unwrap();
+
+        assert_eq!(merge_config.default_strategy.id, MergeStrategyId::Squash);
+        assert_eq!(merge_config.config_type, Some("REPOSITORY".to_string()));
+        assert_eq!(
+            merge_config.allowed_strategies(),
+            vec![&MergeStrategyId::Squash, &MergeStrategyId::FastForwardOnly]
+        );
+        assert!(merge_config.allows(&MergeStrategyId::Squash));
+        assert!(!merge_config.allows(&MergeStrategyId::NoFastForward));
+    } // end of it_can_deserialize_settings
+
+    #[test]
+    fn it_can_round_trip_merge_checks() {
+        let json = serde_json::json!({
+            "mergeConfig": {
+                "defaultStrategy": {"id": "no-ff", "enabled": true},
+                "strategies": [{"id": "no-ff", "enabled": true}]
+            },
+            "requiredApprovers": 2,
+            "requiredAllApprovers": false,
+            "requiredAllTasksComplete": true,
+            "requiredSuccessfulBuilds": 1,
+            "unapproveOnUpdate": true
+        });
+
+        let settings: PullRequestSettings = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(settings.required_approvers, Some(2));
+        assert_eq!(settings.required_all_tasks_complete, Some(true));
+        assert_eq!(serde_json::to_value(&settings).unwrap(), json);
+    } // end of it_can_round_trip_merge_checks
+
+    #[test]
+    fn it_can_deserialize_commit_message_template() {
+        let json = serde_json::json!({
+            "mergeConfig": {
+                "defaultStrategy": {"id": "squash", "enabled": true},
+                "strategies": [{"id": "squash", "enabled": true}],
+                "commitMessageTemplate": {
+                    "enabled": true,
+                    "title": "{{title}} (#{{id}})",
+                    "body": "{{description}}\n\nApproved-by: {{approvers}}"
+                },
+                "commitSummaries": 20,
+                "type": "PROJECT"
+            }
+        });
+
+        let settings: PullRequestSettings = serde_json::from_value(json.clone()).unwrap();
+        let merge_config = settings.merge_config.as_ref().unwrap();
+        let template = merge_config.commit_message_template.as_ref().unwrap();
+
+        assert!(template.enabled);
+        assert_eq!(template.title.as_deref(), Some("{{title}} (#{{id}})"));
+        assert_eq!(
+            template.body.as_deref(),
+            Some("{{description}}\n\nApproved-by: {{approvers}}")
+        );
+        assert_eq!(merge_config.commit_summaries, Some(20));
+        assert_eq!(serde_json::to_value(&settings).unwrap(), json);
+    } // end of it_can_deserialize_commit_message_template
+
+    #[test]
+    fn it_can_deserialize_merge_config_without_commit_message_template() {
+        let json = r#"{"defaultStrategy": {"id": "no-ff", "enabled": true}}"#;
+
+        let merge_config: MergeConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(merge_config.commit_message_template, None);
+        assert_eq!(merge_config.commit_summaries, None);
+    } // end of it_can_deserialize_merge_config_without_commit_message_template
+}
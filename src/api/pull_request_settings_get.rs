@@ -0,0 +1,148 @@
+//! # Pull Request Settings GET API
+//!
+//! This module provides functionality to read the pull request settings of a repository from
+//! Bitbucket Server, such as the merge strategies it allows. Use it to pick a valid `strategyId`
+//! before merging a pull request.
+
+use crate::api::pull_request_settings::PullRequestSettings;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+/// Request for retrieving the pull request settings of a repository.
+#[derive(Clone, Debug)]
+pub struct PullRequestSettingsGet {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+}
+
+impl PullRequestSettingsGet {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for PullRequestSettingsGet {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/settings/pull-requests",
+            self.project_key, self.repository_slug
+        );
+
+        self.client
+            .build_request(Method::GET, &request_uri, None, None)
+    }
+}
+
+impl ApiRequest for PullRequestSettingsGet {
+    type Output = PullRequestSettings;
+    const ENDPOINT_NAME: &'static str = "pull_request_settings_get";
+
+    /// Sends the request to retrieve the pull request settings.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the pull request settings or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for PullRequestSettingsGet {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(PullRequestSettingsGet);
+
+impl Api {
+    /// Creates a request to retrieve the pull request settings of a repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::pull_request_settings::MergeStrategyId;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let settings = client
+    ///         .api()
+    ///         .pull_request_settings_get("PROJECT", "REPO")
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(merge_config) = settings.and_then(|settings| settings.merge_config) {
+    ///         if merge_config.allows(&MergeStrategyId::Squash) {
+    ///             println!("Squash merges are allowed");
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-repository/#api-api-latest-projects-projectkey-repos-repositoryslug-settings-pull-requests-get)
+    pub fn pull_request_settings_get(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+    ) -> PullRequestSettingsGet {
+        PullRequestSettingsGet {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! # Pull Request Settings Update API
+//!
+//! This module provides functionality to update the pull request settings of a repository in
+//! Bitbucket Server, such as its merge checks (required approvers, required successful builds,
+//! unresolved tasks) and merge strategies. Use it to provision repositories.
+
+use crate::api::pull_request_settings::PullRequestSettings;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+/// Request for updating the pull request settings of a repository.
+///
+/// Bitbucket updates `settings/pull-requests` with a POST rather than a PUT, so this request
+/// is sent as a POST even though it updates the settings in place.
+#[derive(Clone, Debug)]
+pub struct PullRequestSettingsUpdate {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The settings to update; settings that are not set are left unchanged
+    settings: PullRequestSettings,
+}
+
+impl PullRequestSettingsUpdate {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for PullRequestSettingsUpdate {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/settings/pull-requests",
+            self.project_key, self.repository_slug
+        );
+
+        self.client.build_request(
+            Method::POST,
+            &request_uri,
+            None,
+            Some(&self.client.to_body(&self.settings)),
+        )
+    }
+}
+
+impl ApiRequest for PullRequestSettingsUpdate {
+    type Output = PullRequestSettings;
+    const ENDPOINT_NAME: &'static str = "pull_request_settings_update";
+
+    /// Sends the request to update the pull request settings.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the updated settings or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for PullRequestSettingsUpdate {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(PullRequestSettingsUpdate);
+
+impl Api {
+    /// Creates a request to update the pull request settings of a repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `settings` - The settings to update; settings that are `None` are left unchanged
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::pull_request_settings::PullRequestSettings;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let settings = PullRequestSettings {
+    ///         required_approvers: Some(2),
+    ///         required_successful_builds: Some(1),
+    ///         required_all_tasks_complete: Some(true),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     client
+    ///         .api()
+    ///         .pull_request_settings_update("PROJECT", "REPO", &settings)
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_ADMIN permission for the repository.
+    /// * Bitbucket updates these settings with a POST, not a PUT.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-repository/#api-api-latest-projects-projectkey-repos-repositoryslug-settings-pull-requests-post)
+    pub fn pull_request_settings_update(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        settings: &PullRequestSettings,
+    ) -> PullRequestSettingsUpdate {
+        PullRequestSettingsUpdate {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            settings: settings.to_owned(),
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! # Pull Requests GET Many API
+//!
+//! This module provides functionality to retrieve several pull requests of a repository at
+//! once, e.g. for dashboards showing dozens of pull requests. The pull requests are retrieved
+//! concurrently, with a bounded number of requests in flight, instead of one after the other.
+
+use crate::api::pull_request::PullRequest;
+use crate::api::Api;
+use crate::client::{ApiRequest, Client};
+use crate::Error;
+use derive_builder::Builder;
+use futures_util::stream::{self, StreamExt};
+
+/// The default maximum number of pull requests retrieved at the same time.
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+/// Request builder for retrieving several pull requests concurrently.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct PullRequestsGetMany {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The IDs of the pull requests
+    pull_request_ids: Vec<String>,
+
+    /// The maximum number of pull requests retrieved at the same time. If not passed,
+    /// [`DEFAULT_CONCURRENCY`] is used.
+    #[builder(setter(into, strip_option), default)]
+    concurrency: Option<usize>,
+}
+
+impl PullRequestsGetMany {
+    /// Sends the requests to retrieve the pull requests.
+    ///
+    /// A failed request doesn't stop the others; its error is returned in place of its pull
+    /// request.
+    ///
+    /// # Returns
+    ///
+    /// The result of each pull request, in the order of the IDs passed.
+    pub async fn send(&self) -> Vec<Result<PullRequest, Error>> {
+        // buffered keeps the results in the order of the IDs, whatever order they complete in
+        stream::iter(&self.pull_request_ids)
+            .map(|pull_request_id| self.get(pull_request_id))
+            .buffered(self.concurrency.unwrap_or(DEFAULT_CONCURRENCY).max(1))
+            .collect()
+            .await
+    }
+
+    /// Retrieves one pull request.
+    async fn get(&self, pull_request_id: &str) -> Result<PullRequest, Error> {
+        Api {
+            client: self.client.clone(),
+        }
+        .pull_request_get(&self.project_key, &self.repository_slug, pull_request_id)
+        .build()
+        .map_err(Error::from_builder)?
+        .send()
+        .await?
+        .ok_or_else(|| {
+            Error::Unexpected(format!("Pull request {pull_request_id} was not returned"))
+        })
+    }
+}
+
+crate::client::impl_with_client!(PullRequestsGetMany);
+
+impl Api {
+    /// Creates a request builder for retrieving several pull requests concurrently.
+    ///
+    /// Each pull request is retrieved with [`Api::pull_request_get`].
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_ids` - The IDs of the pull requests
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::new;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let pull_requests = client
+    ///         .api()
+    ///         .pull_requests_get_many("PROJECT", "REPO", &["1", "2", "3"])
+    ///         .concurrency(4usize)
+    ///         .build()?
+    ///         .send()
+    ///         .await;
+    ///
+    ///     for pull_request in pull_requests {
+    ///         match pull_request {
+    ///             Ok(pull_request) => println!("{}: {}", pull_request.id, pull_request.title),
+    ///             Err(e) => eprintln!("Error: {}", e),
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for the repository.
+    /// * The requests run concurrently on the caller's task; no background tasks are spawned.
+    pub fn pull_requests_get_many(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_ids: &[&str],
+    ) -> PullRequestsGetManyBuilder {
+        let mut builder = PullRequestsGetManyBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .pull_request_ids(pull_request_ids.iter().map(|id| id.to_string()).collect());
+        builder
+    }
+}
@@ -0,0 +1,189 @@
+//! # Default Reviewers Pull Request Payload
+//!
+//! This module composes the repository lookup, the default reviewers API and the pull request
+//! payload: given the source and target refs of a pull request, it returns a
+//! [`PullRequestPostPayload`] with its reviewers pre-populated, ready to be customized and
+//! sent with [`Api::pull_request_post`] or [`Api::pull_request_create_or_get`].
+
+use crate::api::common::Repository;
+use crate::api::pull_request_post::{PullRequestPostPayload, RefInfo, RepositoryInfo, Reviewer};
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+
+/// Request for building a pull request payload with the repository's default reviewers.
+#[derive(Clone, Debug)]
+pub struct RepoDefaultReviewersForPr {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The source ref of the pull request
+    from_ref: RefInfo,
+
+    /// The target ref of the pull request
+    to_ref: RefInfo,
+}
+
+impl RepoDefaultReviewersForPr {
+    /// Looks up the numeric ID of a repository, which the default reviewers API requires.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the ID of the repository or an error.
+    async fn repository_id(&self, repository: &RepositoryInfo) -> Result<u64, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}",
+            repository.project.key, repository.slug
+        );
+
+        self.client
+            .get_with_headers::<Repository>(&request_uri, None)
+            .await?
+            .0
+            .and_then(|repository| repository.id)
+            .ok_or_else(|| {
+                Error::Unexpected(format!(
+                    "Could not resolve the ID of repository {}/{}",
+                    repository.project.key, repository.slug
+                ))
+            })
+    }
+}
+
+impl ApiRequest for RepoDefaultReviewersForPr {
+    type Output = PullRequestPostPayload;
+    const ENDPOINT_NAME: &'static str = "repo_default_reviewers_for_pr";
+
+    /// Sends the requests to resolve the default reviewers and builds the payload.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the payload with its reviewers set, or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the requests, keeping the headers of the default reviewers response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the payload and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        let target_repo_id = self.repository_id(&self.to_ref.repository).await?;
+        let source_repo_id = if self.from_ref.repository == self.to_ref.repository {
+            target_repo_id
+        } else {
+            self.repository_id(&self.from_ref.repository).await?
+        };
+
+        let (reviewers, headers) = Api {
+            client: self.client.clone(),
+        }
+        .default_reviewers_get(
+            &self.to_ref.repository.project.key,
+            &self.to_ref.repository.slug,
+        )
+        .source_repo_id(source_repo_id)
+        .target_repo_id(target_repo_id)
+        .source_ref_id(self.from_ref.id.as_str())
+        .target_ref_id(self.to_ref.id.as_str())
+        .build()
+        .map_err(Error::from_builder)?
+        .send_with_headers()
+        .await?;
+
+        let payload = PullRequestPostPayload {
+            from_ref: self.from_ref.clone(),
+            to_ref: self.to_ref.clone(),
+            reviewers: Some(
+                reviewers
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|user| Reviewer { user })
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+
+        Ok((Some(payload), headers))
+    }
+}
+
+crate::client::impl_with_client!(RepoDefaultReviewersForPr);
+
+impl Api {
+    /// Creates a request that builds a pull request payload with the default reviewers set.
+    ///
+    /// The IDs of the source and target repositories are looked up, then the reviewers
+    /// suggested by [`Api::default_reviewers_get`] are put in the `reviewers` of the payload.
+    /// The title and description are left empty for the caller to fill in.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_ref` - The source ref of the pull request
+    /// * `to_ref` - The target ref of the pull request
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::pull_request_post::{RefInfo, RepositoryInfo, ProjectInfo};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let repository = RepositoryInfo {
+    ///         slug: "my-repo".to_string(),
+    ///         project: ProjectInfo {
+    ///             key: "PROJECT".to_string(),
+    ///         },
+    ///     };
+    ///     let from_ref = RefInfo {
+    ///         id: "refs/heads/feature".to_string(),
+    ///         repository: repository.clone(),
+    ///     };
+    ///     let to_ref = RefInfo {
+    ///         id: "refs/heads/main".to_string(),
+    ///         repository,
+    ///     };
+    ///
+    ///     let mut payload = client
+    ///         .clone()
+    ///         .api()
+    ///         .repo_default_reviewers_for_pr(&from_ref, &to_ref)
+    ///         .send()
+    ///         .await?
+    ///         .unwrap_or_default();
+    ///     payload.title = "Add new feature".to_string();
+    ///
+    ///     client
+    ///         .api()
+    ///         .pull_request_post("PROJECT", "my-repo", &payload)
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_READ permission for both repositories.
+    /// * See [`Api::default_reviewers_get`] for the requirements of the default reviewers API.
+    pub fn repo_default_reviewers_for_pr(
+        self,
+        from_ref: &RefInfo,
+        to_ref: &RefInfo,
+    ) -> RepoDefaultReviewersForPr {
+        RepoDefaultReviewersForPr {
+            client: self.client,
+            from_ref: from_ref.to_owned(),
+            to_ref: to_ref.to_owned(),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! # Repository List API
+//!
+//! This module provides functionality to list the repositories the authenticated user can see in
+//! Bitbucket Server, optionally filtered by project, name and the permission the user holds.
+
+use crate::api::common::{Page, Repository};
+use crate::api::my_repo_permission::RepositoryPermission;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+/// Request builder for listing repositories.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct RepositoryList {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// Only return the repositories of the project with this key
+    #[builder(setter(into, strip_option), default)]
+    project_key: Option<String>,
+
+    /// Only return the repositories whose name matches this text, case-insensitively
+    #[builder(setter(into, strip_option), default)]
+    name: Option<String>,
+
+    /// Only return the repositories on which the authenticated user holds at least this
+    /// permission, whether granted directly, through a group, by public access or globally
+    #[builder(setter(into, strip_option), default)]
+    permission: Option<RepositoryPermission>,
+
+    /// Start number for the page (inclusive). If not passed, first page is assumed.
+    #[builder(setter(into, strip_option), default)]
+    start: Option<u64>,
+
+    /// Number of items to return. If not passed, a page size of 25 is used.
+    #[builder(setter(into, strip_option), default)]
+    limit: Option<u64>,
+}
+
+impl RepositoryList {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for RepositoryList {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let mut params = BTreeMap::new();
+
+        if let Some(project_key) = &self.project_key {
+            params.insert("projectkey".to_string(), project_key.clone());
+        }
+        if let Some(name) = &self.name {
+            params.insert("name".to_string(), name.clone());
+        }
+        if let Some(permission) = &self.permission {
+            params.insert("permission".to_string(), permission.as_str().to_string());
+        }
+        if let Some(start) = &self.start {
+            params.insert("start".to_string(), start.to_string());
+        }
+        if let Some(limit) = &self.limit {
+            params.insert("limit".to_string(), limit.to_string());
+        }
+
+        self.client
+            .build_request(Method::GET, "api/latest/repos", Some(params), None)
+    }
+}
+
+impl ApiRequest for RepositoryList {
+    type Output = Page<Repository>;
+    const ENDPOINT_NAME: &'static str = "repository_list";
+
+    /// Sends the request to list repositories.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either a page of repositories or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for RepositoryList {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(RepositoryList);
+
+impl Api {
+    /// Creates a request builder for listing the repositories the authenticated user can see.
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::api::my_repo_permission::RepositoryPermission;
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let response = client
+    ///         .api()
+    ///         .repository_list()
+    ///         .project_key("PROJECT")
+    ///         .permission(RepositoryPermission::Write)
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(page) = response {
+    ///         for repository in page.values {
+    ///             println!("Can push to {}", repository.slug);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * Only the repositories the authenticated user can read are returned; no permission is
+    ///   needed to call it.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-repository/#api-api-latest-repos-get)
+    pub fn repository_list(self) -> RepositoryListBuilder {
+        let mut builder = RepositoryListBuilder::default();
+        builder.client(self.client.clone());
+        builder
+    }
+}
@@ -0,0 +1,349 @@
+//! # Search API
+//!
+//! This module provides functionality to search code, repositories and commits across a
+//! Bitbucket Server instance, e.g. to find where a symbol is defined or which repository
+//! holds a service. It uses the `search` REST module, which requires the server's search
+//! index (Elasticsearch or OpenSearch) to be configured.
+
+use crate::api::common::{Commit, Repository};
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// The kinds of entities a search can return.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SearchEntity {
+    /// Files whose content or path matches the query
+    Code,
+
+    /// Repositories whose name matches the query
+    Repositories,
+
+    /// Commits whose message or hash matches the query
+    Commits,
+}
+
+/// The result of a search, with a page of results for each searched entity.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+    /// The matching files, if code was searched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<SearchPage<CodeSearchHit>>,
+
+    /// The matching repositories, if repositories were searched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repositories: Option<SearchPage<Repository>>,
+
+    /// The matching commits, if commits were searched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commits: Option<SearchPage<Commit>>,
+}
+
+/// A page of search results for one entity.
+///
+/// Unlike [`crate::api::common::Page`], it has the total `count` of matches and uses
+/// `nextStart` rather than `nextPageStart`.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchPage<T> {
+    /// Whether the results are the primary or secondary results of the search
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+
+    /// The total number of matches
+    #[serde(default)]
+    pub count: u64,
+
+    /// The index of the first result in this page
+    #[serde(default)]
+    pub start: u64,
+
+    /// The index to use as `start` to fetch the next page, if there is one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_start: Option<u64>,
+
+    /// Whether this is the last page of results
+    #[serde(default)]
+    pub is_last_page: bool,
+
+    /// The results in this page
+    #[serde(default)]
+    pub values: Vec<T>,
+}
+
+/// A file matching a code search.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeSearchHit {
+    /// The repository containing the file
+    pub repository: Repository,
+
+    /// The path of the file
+    pub file: String,
+
+    /// The number of matches in the file
+    #[serde(default)]
+    pub hit_count: u64,
+
+    /// The matching lines, grouped with their surrounding lines
+    #[serde(default)]
+    pub hit_contexts: Vec<Vec<HitLine>>,
+}
+
+/// A line of a file shown in a code search result.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct HitLine {
+    /// The 1-based line number
+    pub line: u64,
+
+    /// The text of the line, with matches highlighted by `<em>` tags
+    pub text: String,
+}
+
+/// The payload of a search request.
+#[derive(Clone, Debug, PartialEq, Default, Serialize)]
+struct SearchPayload {
+    query: String,
+    entities: SearchPayloadEntities,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limits: Option<SearchPayloadLimits>,
+}
+
+/// The entities to search; each one that is set is searched.
+#[derive(Clone, Debug, PartialEq, Default, Serialize)]
+struct SearchPayloadEntities {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<SearchPayloadPaging>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repositories: Option<SearchPayloadPaging>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commits: Option<SearchPayloadPaging>,
+}
+
+/// Where a searched entity's page of results starts.
+#[derive(Clone, Debug, PartialEq, Default, Serialize)]
+struct SearchPayloadPaging {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<u64>,
+}
+
+/// The number of results to return for the primary entity.
+#[derive(Clone, Debug, PartialEq, Default, Serialize)]
+struct SearchPayloadLimits {
+    primary: u64,
+}
+
+/// Request builder for searching code, repositories and commits.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct Search {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The search query, e.g. `parse_config lang:rust` or `project:PRJ ext:yaml`
+    query: String,
+
+    /// The entities to search. If not passed, only code is searched.
+    #[builder(setter(into, strip_option), default)]
+    entities: Option<Vec<SearchEntity>>,
+
+    /// Start number for the page (inclusive). If not passed, first page is assumed.
+    #[builder(setter(into, strip_option), default)]
+    start: Option<u64>,
+
+    /// Number of results to return. If not passed, the server's default is used.
+    #[builder(setter(into, strip_option), default)]
+    limit: Option<u64>,
+}
+
+impl Search {
+    /// Builds the payload of the request.
+    fn payload(&self) -> SearchPayload {
+        let entities = self
+            .entities
+            .clone()
+            .unwrap_or_else(|| vec![SearchEntity::Code]);
+        let paging = || {
+            Some(SearchPayloadPaging {
+                start: self.start,
+            })
+        };
+
+        let mut payload_entities = SearchPayloadEntities::default();
+        for entity in entities {
+            match entity {
+                SearchEntity::Code => payload_entities.code = paging(),
+                SearchEntity::Repositories => payload_entities.repositories = paging(),
+                SearchEntity::Commits => payload_entities.commits = paging(),
+            }
+        }
+
+        SearchPayload {
+            query: self.query.clone(),
+            entities: payload_entities,
+            limits: self.limit.map(|limit| SearchPayloadLimits { primary: limit }),
+        }
+    }
+
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for Search {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        self.client.build_request(
+            Method::POST,
+            "search/latest/search",
+            None,
+            Some(&self.client.to_body(&self.payload())),
+        )
+    }
+}
+
+impl ApiRequest for Search {
+    type Output = SearchResult;
+    const ENDPOINT_NAME: &'static str = "search";
+
+    /// Sends the search request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the search result or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for Search {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(Search);
+
+impl Api {
+    /// Creates a request builder for searching code, repositories and commits.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query, e.g. `parse_config lang:rust`
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::search::SearchEntity;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let response = client
+    ///         .api()
+    ///         .search("parse_config lang:rust")
+    ///         .entities(vec![SearchEntity::Code])
+    ///         .limit(10u64)
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(code) = response.and_then(|result| result.code) {
+    ///         for hit in code.values {
+    ///             println!("{}/{}: {}", hit.repository.slug, hit.file, hit.hit_count);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * Only repositories the authenticated user can read are searched.
+    /// * The server must have a search index configured; otherwise the request fails.
+    ///
+    /// See [Bitbucket Data Center search syntax](https://confluence.atlassian.com/bitbucketserver/search-for-code-in-bitbucket-server-814204781.html)
+    pub fn search(self, query: &str) -> SearchBuilder {
+        let mut builder = SearchBuilder::default();
+        builder
+            .client(self.client.clone())
+            .query(query.to_string());
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_can_serialize_payload() {
+        let search = Search {
+            query: "parse_config".to_string(),
+            entities: Some(vec![SearchEntity::Code, SearchEntity::Repositories]),
+            start: Some(25),
+            limit: Some(10),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_value(search.payload()).unwrap(),
+            serde_json::json!({
+                "query": "parse_config",
+                "entities": {"code": {"start": 25}, "repositories": {"start": 25}},
+                "limits": {"primary": 10}
+            })
+        );
+    } // end of it_can_serialize_payload
+
+    #[test]
+    fn it_searches_code_by_default() {
+        let search = Search {
+            query: "parse_config".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_value(search.payload()).unwrap(),
+            serde_json::json!({"query": "parse_config", "entities": {"code": {}}})
+        );
+    } // end of it_searches_code_by_default
+}
@@ -0,0 +1,204 @@
+//! # Webhook Common Types
+//!
+//! This module contains common types used by the webhook API endpoints, and helpers for
+//! receivers to verify that a delivery was signed with the webhook's secret.
+//!
+//! The secret is write-only: it is sent when a webhook is created, but Bitbucket never returns
+//! it, so it is never read back when webhooks are listed.
+
+use crate::timestamp::{ts_milliseconds_option, Timestamp};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+/// The header Bitbucket puts the signature of a delivery in, e.g. `sha256=5bdcc1...`.
+pub const SIGNATURE_HEADER: &str = "X-Hub-Signature";
+
+/// A repository webhook.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Webhook {
+    /// The ID of the webhook, set by the server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    /// The name of the webhook
+    pub name: String,
+
+    /// The URL events are delivered to
+    pub url: String,
+
+    /// The events that trigger a delivery, e.g. `repo:refs_changed` or `pr:opened`
+    #[serde(default)]
+    pub events: Vec<String>,
+
+    /// Whether the webhook is enabled
+    #[serde(default)]
+    pub active: bool,
+
+    /// The configuration of the webhook
+    #[serde(default)]
+    pub configuration: WebhookConfiguration,
+
+    /// The date when the webhook was created
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "ts_milliseconds_option")]
+    pub created_date: Option<Timestamp>,
+
+    /// The date when the webhook was last updated
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "ts_milliseconds_option")]
+    pub updated_date: Option<Timestamp>,
+}
+
+/// The configuration of a webhook.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct WebhookConfiguration {
+    /// The secret deliveries are signed with.
+    ///
+    /// Write-only: it is sent when creating a webhook, but is always `None` on webhooks read
+    /// from the server.
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+}
+
+/// Computes the signature Bitbucket sends with a delivery signed with `secret`.
+///
+/// # Arguments
+///
+/// * `secret` - The secret of the webhook
+/// * `body` - The raw body of the delivery
+///
+/// # Returns
+///
+/// The value of the [`SIGNATURE_HEADER`] header: `sha256=` followed by the hex encoded
+/// HMAC-SHA256 of the body.
+pub fn signature(secret: &str, body: &[u8]) -> String {
+    let digest = hmac_sha256(secret, body).finalize().into_bytes();
+    let hex: String = digest.iter().map(|byte| format!("{byte:02x}")).collect();
+
+    format!("sha256={hex}")
+}
+
+/// Verifies that a delivery was signed with `secret`.
+///
+/// The comparison runs in constant time.
+///
+/// # Arguments
+///
+/// * `secret` - The secret of the webhook
+/// * `body` - The raw body of the delivery, exactly as received
+/// * `header` - The value of the [`SIGNATURE_HEADER`] header of the delivery
+///
+/// # Returns
+///
+/// `true` if the signature matches the body, `false` otherwise.
+///
+/// # Example
+///
+/// ```
+/// use bitbucket_server_rs::api::webhook::{signature, verify_signature};
+///
+/// let body = br#"{"eventKey":"repo:refs_changed"}"#;
+/// let header = signature("SECRET", body);
+///
+/// assert!(verify_signature("SECRET", body, &header));
+/// assert!(!verify_signature("OTHER_SECRET", body, &header));
+/// ```
+pub fn verify_signature(secret: &str, body: &[u8], header: &str) -> bool {
+    let Some(expected) = header.strip_prefix("sha256=").and_then(decode_hex) else {
+        return false;
+    };
+
+    hmac_sha256(secret, body).verify_slice(&expected).is_ok()
+}
+
+fn hmac_sha256(secret: &str, body: &[u8]) -> Hmac<Sha256> {
+    // HMAC accepts keys of any length
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(body);
+    mac
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timestamp::epoch_seconds;
+    use serde_json::{from_str, json, to_value};
+
+    #[test]
+    fn it_can_deserialize_without_secret() {
+        let json = r#"{
+            "id": 1,
+            "name": "CI",
+            "url": "https://ci.example.com/hook",
+            "events": ["repo:refs_changed", "pr:opened"],
+            "active": true,
+            "configuration": {},
+            "createdDate": 1738198923000,
+            "updatedDate": 1738198924000
+        }"#;
+
+        let webhook: Webhook = from_str(json).unwrap();
+
+        assert_eq!(webhook.id, Some(1));
+        assert_eq!(webhook.events, vec!["repo:refs_changed", "pr:opened"]);
+        assert!(webhook.active);
+        assert_eq!(webhook.configuration.secret, None);
+        assert_eq!(epoch_seconds(&webhook.created_date.unwrap()), 1738198923);
+    } // end of it_can_deserialize_without_secret
+
+    #[test]
+    fn it_never_reads_the_secret() {
+        let json = r#"{
+            "name": "CI",
+            "url": "https://ci.example.com/hook",
+            "configuration": {"secret": "SECRET"}
+        }"#;
+
+        let webhook: Webhook = from_str(json).unwrap();
+
+        assert_eq!(webhook.configuration.secret, None);
+    } // end of it_never_reads_the_secret
+
+    #[test]
+    fn it_serializes_the_secret() {
+        let webhook = Webhook {
+            name: "CI".to_string(),
+            url: "https://ci.example.com/hook".to_string(),
+            configuration: WebhookConfiguration {
+                secret: Some("SECRET".to_string()),
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            to_value(&webhook).unwrap()["configuration"],
+            json!({"secret": "SECRET"})
+        );
+    } // end of it_serializes_the_secret
+
+    #[test]
+    fn it_can_sign_and_verify() {
+        // RFC 4231, test case 2
+        let header = signature("Jefe", b"what do ya want for nothing?");
+
+        assert_eq!(
+            header,
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        assert!(verify_signature("Jefe", b"what do ya want for nothing?", &header));
+        assert!(!verify_signature("Jefe", b"what do ya want for something?", &header));
+        assert!(!verify_signature("Jefe", b"what do ya want for nothing?", "sha256=zz"));
+        assert!(!verify_signature("Jefe", b"what do ya want for nothing?", "5bdcc146"));
+    } // end of it_can_sign_and_verify
+}
@@ -0,0 +1,164 @@
+//! # Webhook POST API
+//!
+//! This module provides functionality to create a repository webhook in Bitbucket Server.
+//! The webhook's secret is sent on creation so deliveries are signed with it; it is not
+//! returned in the response.
+
+use crate::api::webhook::Webhook;
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+/// Request for creating a repository webhook.
+#[derive(Clone, Debug)]
+pub struct WebhookPost {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The webhook to create
+    webhook: Webhook,
+}
+
+impl WebhookPost {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for WebhookPost {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/webhooks",
+            self.project_key, self.repository_slug
+        );
+
+        self.client.build_request(
+            Method::POST,
+            &request_uri,
+            None,
+            Some(&self.client.to_body(&self.webhook)),
+        )
+    }
+}
+
+impl ApiRequest for WebhookPost {
+    type Output = Webhook;
+    const ENDPOINT_NAME: &'static str = "webhook_post";
+
+    /// Sends the request to create the webhook.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the created webhook, without its secret, or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for WebhookPost {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(WebhookPost);
+
+impl Api {
+    /// Creates a request to create a repository webhook.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `webhook` - The webhook to create
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    /// use bitbucket_server_rs::api::webhook::{Webhook, WebhookConfiguration};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let webhook = Webhook {
+    ///         name: "CI".to_string(),
+    ///         url: "https://ci.example.com/hook".to_string(),
+    ///         events: vec!["repo:refs_changed".to_string()],
+    ///         active: true,
+    ///         configuration: WebhookConfiguration {
+    ///             secret: Some("SECRET".to_string()),
+    ///         },
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let created = client
+    ///         .api()
+    ///         .webhook_post("PROJECT", "REPO", &webhook)
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_ADMIN permission for the repository.
+    /// * Use [`crate::api::webhook::verify_signature`] to verify deliveries with the secret.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-repository/#api-api-latest-projects-projectkey-repos-repositoryslug-webhooks-post)
+    pub fn webhook_post(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        webhook: &Webhook,
+    ) -> WebhookPost {
+        WebhookPost {
+            client: self.client,
+            project_key: project_key.to_owned(),
+            repository_slug: repository_slug.to_owned(),
+            webhook: webhook.to_owned(),
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! # Webhook Test API
+//!
+//! This module provides functionality to test a repository webhook in Bitbucket Server.
+//! Bitbucket delivers a test event to the webhook's URL, signed with the webhook's secret if it
+//! has one, and reports the outcome. Provisioning tooling can use it to verify the endpoint is
+//! reachable right after creating the webhook.
+
+use crate::api::Api;
+#[cfg(feature = "raw-value")]
+use crate::client::{RawValue, RawValueRequest};
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client, DescribeRequest};
+use crate::Error;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// The outcome of a webhook delivery.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub enum WebhookOutcome {
+    /// The endpoint responded with a 2xx status
+    #[serde(rename = "SUCCESS")]
+    Success,
+
+    /// The endpoint responded with a non-2xx status
+    #[serde(rename = "FAILURE")]
+    Failure,
+
+    /// The delivery did not get a response, e.g. the connection was refused
+    #[default]
+    #[serde(rename = "ERROR")]
+    Error,
+}
+
+/// The result of a webhook delivery.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookResult {
+    /// Whether the delivery succeeded
+    pub outcome: WebhookOutcome,
+
+    /// A description of the result, e.g. the HTTP status or the connection error
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// The delivery of a webhook test event.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookTestResult {
+    /// The event that was delivered, e.g. `diagnostics:ping`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<String>,
+
+    /// How long the delivery took, in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u64>,
+
+    /// The result of the delivery
+    pub result: WebhookResult,
+}
+
+impl WebhookTestResult {
+    /// Check whether the test event was delivered successfully.
+    pub fn is_success(&self) -> bool {
+        self.result.outcome == WebhookOutcome::Success
+    }
+}
+
+/// The payload of a webhook test request.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookTestPayload {
+    /// The event to deliver; the server sends a ping event if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<String>,
+}
+
+/// Request builder for testing a repository webhook.
+#[derive(Clone, Debug, Default, Builder)]
+pub struct WebhookTest {
+    /// The HTTP client to use for making requests
+    client: Client,
+
+    /// The key of the project containing the repository
+    project_key: String,
+
+    /// The slug of the repository
+    repository_slug: String,
+
+    /// The ID of the webhook
+    webhook_id: String,
+
+    /// The event to deliver, e.g. `repo:refs_changed`
+    #[builder(setter(into, strip_option), default)]
+    event: Option<String>,
+}
+
+impl WebhookTest {
+    /// Sends the request, deserializing the response into `O`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn execute<O: DeserializeOwned>(&self) -> ApiResponseWithHeaders<O> {
+        self.client.send_request::<O>(self.build_request()?).await
+    }
+}
+
+impl DescribeRequest for WebhookTest {
+    /// Builds the request, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built.
+    fn build_request(&self) -> Result<reqwest::Request, Error> {
+        let request_uri = format!(
+            "api/latest/projects/{}/repos/{}/webhooks/{}/test",
+            self.project_key, self.repository_slug, self.webhook_id
+        );
+
+        let payload = WebhookTestPayload {
+            event: self.event.clone(),
+        };
+
+        self.client.build_request(
+            Method::POST,
+            &request_uri,
+            None,
+            Some(&self.client.to_body(&payload)),
+        )
+    }
+}
+
+impl ApiRequest for WebhookTest {
+    type Output = WebhookTestResult;
+    const ENDPOINT_NAME: &'static str = "webhook_test";
+
+    /// Sends the request to test the webhook.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the delivery result or an error. A failed delivery is not an
+    /// error; check [`WebhookTestResult::is_success`].
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the request, keeping the response headers.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        self.execute().await
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl RawValueRequest for WebhookTest {
+    /// Sends the request, keeping the full parsed JSON value of the response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data with its raw value and headers, or an error.
+    async fn send_with_raw_value(&self) -> ApiResponseWithHeaders<RawValue<Self::Output>> {
+        self.execute().await
+    }
+}
+
+crate::client::impl_with_client!(WebhookTest);
+
+impl Api {
+    /// Creates a request builder for testing a repository webhook.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `webhook_id` - The ID of the webhook
+    ///
+    /// # Returns
+    ///
+    /// A builder for configuring and sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let delivery = client
+    ///         .api()
+    ///         .webhook_test("PROJECT", "REPO", "1")
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some(delivery) = delivery {
+    ///         if !delivery.is_success() {
+    ///             eprintln!("Webhook is unreachable: {:?}", delivery.result.description);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The authenticated user must have REPO_ADMIN permission for the repository.
+    pub fn webhook_test(
+        self,
+        project_key: &str,
+        repository_slug: &str,
+        webhook_id: &str,
+    ) -> WebhookTestBuilder {
+        let mut builder = WebhookTestBuilder::default();
+        builder
+            .client(self.client.clone())
+            .project_key(project_key.to_string())
+            .repository_slug(repository_slug.to_string())
+            .webhook_id(webhook_id.to_string());
+        builder
+    }
+}
@@ -0,0 +1,119 @@
+//! # Who Am I API
+//!
+//! This module provides functionality to retrieve the user the client is authenticated as,
+//! e.g. for a CLI to print "Logged in as ..." at startup.
+//!
+//! Bitbucket Server has no dedicated endpoint for this. Instead, it names the authenticated
+//! user in the `X-AUSERNAME` header of every response, so a cheap request is made to read the
+//! header, then the user's details are looked up.
+
+use crate::api::common::{Page, User};
+use crate::api::Api;
+use crate::client::{ApiRequest, ApiResponse, ApiResponseWithHeaders, Client};
+use crate::Error;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// The header Bitbucket puts the username of the authenticated user in.
+const USERNAME_HEADER: &str = "X-AUSERNAME";
+
+/// Request for retrieving the authenticated user.
+#[derive(Clone, Debug)]
+pub struct Whoami {
+    /// The HTTP client to use for making requests
+    client: Client,
+}
+
+impl ApiRequest for Whoami {
+    type Output = User;
+    const ENDPOINT_NAME: &'static str = "whoami";
+
+    /// Sends the requests to retrieve the authenticated user.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the authenticated user or an error.
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    /// Sends the requests, keeping the headers of the user lookup response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        let (_, headers) = self
+            .client
+            .get_with_headers::<Value>("api/latest/application-properties", None)
+            .await?;
+
+        // anonymous requests succeed without the header, e.g. with an empty token on a
+        // server that allows public access
+        let username = headers
+            .get(USERNAME_HEADER)
+            .and_then(|username| username.to_str().ok())
+            .filter(|username| !username.is_empty())
+            .ok_or(Error::Anonymous)?
+            .to_string();
+
+        let mut params = BTreeMap::new();
+        params.insert("filter".to_string(), username.clone());
+
+        let (page, headers) = self
+            .client
+            .get_with_headers::<Page<User>>("api/latest/users", Some(params))
+            .await?;
+
+        // the filter also matches other users whose name or email contains the username
+        let user = page
+            .and_then(|page| page.values.into_iter().find(|user| user.name == username))
+            .unwrap_or(User {
+                name: username,
+                ..Default::default()
+            });
+
+        Ok((Some(user), headers))
+    }
+}
+
+crate::client::impl_with_client!(Whoami);
+
+impl Api {
+    /// Creates a request to retrieve the user the client is authenticated as.
+    ///
+    /// # Returns
+    ///
+    /// A request for sending
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     if let Some(user) = client.api().whoami().send().await? {
+    ///         println!("Logged in as {}", user.display_name.unwrap_or(user.name));
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * An invalid or expired token results in `Error::Unauthenticated`, and a request the
+    ///   server answers anonymously, e.g. with an empty token, in `Error::Anonymous`.
+    /// * If the user lookup doesn't return a user with exactly that name, only the `name` of
+    ///   the returned user is set.
+    ///
+    /// See [Bitbucket Data Center REST API Docs](https://developer.atlassian.com/server/bitbucket/rest/v811/api-group-system-maintenance/#api-api-latest-users-get)
+    pub fn whoami(self) -> Whoami {
+        Whoami {
+            client: self.client,
+        }
+    }
+}
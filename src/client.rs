@@ -5,18 +5,102 @@
 
 use crate::api;
 use crate::Error;
+use crate::timestamp::{from_epoch_seconds, Timestamp};
 use api::Api;
-use reqwest::{RequestBuilder, Response, StatusCode};
+use log::{info, warn};
+use reqwest::header::{HeaderMap, ACCEPT, AUTHORIZATION, ETAG, IF_NONE_MATCH, LOCATION};
+use reqwest::{Method, RequestBuilder, Response, StatusCode};
 use serde::de::DeserializeOwned;
-use std::collections::HashMap;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
 use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The header Bitbucket adds to authentication failures that require a CAPTCHA.
+const AUTHENTICATION_DENIED_REASON: &str = "X-Authentication-Denied-Reason";
+
+/// The header that opts a state-changing request out of Atlassian's XSRF check.
+const ATLASSIAN_TOKEN: &str = "X-Atlassian-Token";
+
+/// The body Atlassian's XSRF protection rejects requests with.
+const XSRF_CHECK_FAILED: &str = "XSRF check failed";
+
+/// A request as it would be sent to the server, as returned by [`DescribeRequest::describe`].
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct RequestDescription {
+    /// The HTTP method, e.g. `GET`
+    pub method: String,
+
+    /// The full URL, including the query parameters
+    pub url: String,
+
+    /// The request headers, with the API token redacted
+    pub headers: Vec<(String, String)>,
+
+    /// The serialized body; `None` if the request has no body
+    pub body: Option<String>,
+}
+
+impl RequestDescription {
+    /// Describe a built request, redacting its `Authorization` header.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to describe.
+    ///
+    /// # Returns
+    ///
+    /// The description of the request, or an error if it can't be built.
+    fn from_request(request: &reqwest::Request) -> Self {
+        let headers = request
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                let value = if name == AUTHORIZATION {
+                    "Bearer ***".to_string()
+                } else {
+                    String::from_utf8_lossy(value.as_bytes()).into_owned()
+                };
+                (name.to_string(), value)
+            })
+            .collect();
+
+        RequestDescription {
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            headers,
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(|body| String::from_utf8_lossy(body).into_owned()),
+        }
+    }
+
+    /// The value of a header of the request.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the header, in any case.
+    ///
+    /// # Returns
+    ///
+    /// The value of the header, or `None` if the request doesn't have it.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
 
 /// Configuration for the Bitbucket Server API HTTP client.
 ///
 /// This struct holds all the necessary configuration for making API requests to a Bitbucket Server instance.
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default)]
 pub struct Client {
-    /// Base URL for the bitbucket server. It must end with `/rest`.
+    /// Base URL for the bitbucket server, ending with `/rest` and without a trailing slash.
     pub base_path: String,
 
     /// The HTTP client to use for making requests.
@@ -24,6 +108,200 @@ pub struct Client {
 
     /// The API token to use for authentication.
     pub api_token: String,
+
+    /// How idempotent requests (GET and PUT) are retried; by default they aren't.
+    pub retry_policy: RetryPolicy,
+
+    /// Whether request bodies are serialized as indented, multi-line JSON; by default they are
+    /// compact.
+    pub pretty_bodies: bool,
+
+    /// Whether POST, PUT and DELETE requests carry `X-Atlassian-Token: no-check`; clients
+    /// created with [`new`] send it.
+    pub atlassian_token_no_check: bool,
+
+    /// When requests stop being sent to a failing server; by default they never do.
+    pub circuit_breaker: Option<CircuitBreakerPolicy>,
+
+    /// The rate-limit state of the last response carrying rate-limit headers, shared by clones.
+    rate_limit_state: Arc<Mutex<Option<RateLimitState>>>,
+
+    /// The failures counted by the circuit breaker, shared by clones.
+    circuit_breaker_state: Arc<Mutex<CircuitBreakerState>>,
+}
+
+impl fmt::Debug for Client {
+    /// Formats the client with its API token redacted, so that logging the client, or a
+    /// request embedding it, doesn't leak the token.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("base_path", &self.base_path)
+            .field("http_client", &self.http_client)
+            .field("api_token", &"***")
+            .field("retry_policy", &self.retry_policy)
+            .field("pretty_bodies", &self.pretty_bodies)
+            .field("atlassian_token_no_check", &self.atlassian_token_no_check)
+            .field("circuit_breaker", &self.circuit_breaker)
+            .field("rate_limit_state", &self.rate_limit_state)
+            .field("circuit_breaker_state", &self.circuit_breaker_state)
+            .finish()
+    }
+}
+
+/// The rate-limit state reported by the server.
+///
+/// Pollers can widen their interval as `remaining` drops instead of waiting to be throttled.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct RateLimitState {
+    /// The number of requests allowed in the current window (`X-RateLimit-Limit`)
+    pub limit: Option<u32>,
+
+    /// The number of requests left in the current window (`X-RateLimit-Remaining`)
+    pub remaining: Option<u32>,
+
+    /// When the current window resets (`X-RateLimit-Reset`)
+    pub reset: Option<Timestamp>,
+}
+
+impl RateLimitState {
+    /// Read the rate-limit state from the headers of a response.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` - The headers of the response.
+    ///
+    /// # Returns
+    ///
+    /// The rate-limit state, or `None` if the response has no rate-limit headers.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<i64>().ok())
+        };
+
+        let state = RateLimitState {
+            limit: header("X-RateLimit-Limit").and_then(|limit| u32::try_from(limit).ok()),
+            remaining: header("X-RateLimit-Remaining")
+                .and_then(|remaining| u32::try_from(remaining).ok()),
+            reset: header("X-RateLimit-Reset")
+                .and_then(from_epoch_seconds),
+        };
+
+        (state != RateLimitState::default()).then_some(state)
+    }
+}
+
+/// How idempotent requests are retried when they fail.
+///
+/// GET and PUT requests are retried on connection errors and server errors (HTTP 5xx), waiting
+/// an exponentially growing delay between attempts. Each retry is logged at `warn` level, and a
+/// request that eventually succeeds is logged at `info` level. POST requests are never retried.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of retries after the first attempt; 0 disables retries.
+    pub max_retries: u32,
+
+    /// The delay before the first retry; it doubles with each following retry.
+    pub initial_backoff: Duration,
+}
+
+/// When the client stops sending requests to a failing server.
+///
+/// After `failure_threshold` consecutive failures (connection errors and server errors, HTTP
+/// 5xx) within `window`, the circuit opens: requests fail with `Error::CircuitOpen` without
+/// being sent, for `cooldown`. Then a single trial request is let through: if it succeeds the
+/// circuit closes, otherwise it stays open for another cooldown. The state is shared by all
+/// clones of the client.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CircuitBreakerPolicy {
+    /// The number of consecutive failures that opens the circuit.
+    pub failure_threshold: u32,
+
+    /// The time within which the failures must happen; older failures are forgotten.
+    pub window: Duration,
+
+    /// How long requests are short-circuited once the circuit is open.
+    pub cooldown: Duration,
+}
+
+/// The failures counted by a circuit breaker.
+#[derive(Debug, Default)]
+struct CircuitBreakerState {
+    /// The number of consecutive failures in the current window
+    failures: u32,
+
+    /// When the first failure of the current window happened
+    window_start: Option<Instant>,
+
+    /// When the circuit opened, or when its last trial request was let through; `None` while
+    /// it is closed
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreakerState {
+    /// Let a request through, unless the circuit is open.
+    ///
+    /// Once the cooldown is over, the request is the trial of the half-open circuit and the
+    /// cooldown starts over, so other requests wait for its outcome. A trial that never
+    /// completes only holds the circuit open for one more cooldown.
+    ///
+    /// # Returns
+    ///
+    /// `Err` with the time left until the next trial if the circuit is open.
+    fn admit(&mut self, policy: &CircuitBreakerPolicy, now: Instant) -> Result<(), Duration> {
+        let Some(opened_at) = self.opened_at else {
+            return Ok(());
+        };
+
+        let reopens_at = opened_at + policy.cooldown;
+        if now < reopens_at {
+            return Err(reopens_at - now);
+        }
+
+        self.opened_at = Some(now);
+        Ok(())
+    }
+
+    /// Count the outcome of a request that was sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The policy of the circuit breaker
+    /// * `failed` - Whether the request failed with a connection error or a server error
+    /// * `now` - When the request completed
+    fn record(&mut self, policy: &CircuitBreakerPolicy, failed: bool, now: Instant) {
+        if !failed {
+            *self = CircuitBreakerState::default();
+            return;
+        }
+
+        if self.opened_at.is_some() {
+            // the trial request failed
+            self.opened_at = Some(now);
+            return;
+        }
+
+        match self.window_start {
+            Some(start) if now.duration_since(start) <= policy.window => self.failures += 1,
+            _ => {
+                self.window_start = Some(now);
+                self.failures = 1;
+            }
+        }
+
+        if self.failures >= policy.failure_threshold {
+            warn!(
+                "Circuit breaker opened for {:?} after {} consecutive failures",
+                policy.cooldown, self.failures
+            );
+            *self = CircuitBreakerState {
+                opened_at: Some(now),
+                ..CircuitBreakerState::default()
+            };
+        }
+    }
 }
 
 /// The Bitbucket API client implementation.
@@ -49,7 +327,8 @@ impl Client {
 ///
 /// # Arguments
 ///
-/// * `base_path` - The base URL for the Bitbucket server. It must end with `/rest`.
+/// * `base_path` - The base URL for the Bitbucket server. `https://host/rest`, `https://host/rest/`
+///   and `https://host` are all accepted; see [`normalize_base_path`].
 /// * `api_token` - The API token to use for authentication.
 ///
 /// # Returns
@@ -87,192 +366,1395 @@ impl Client {
 /// ```
 pub fn new(base_path: &str, api_token: &str) -> Client {
     Client {
-        base_path: base_path.to_string(),
+        base_path: normalize_base_path(base_path),
         http_client: reqwest::Client::new(),
         api_token: api_token.to_string(),
+        retry_policy: RetryPolicy::default(),
+        pretty_bodies: false,
+        atlassian_token_no_check: true,
+        circuit_breaker: None,
+        rate_limit_state: Arc::default(),
+        circuit_breaker_state: Arc::default(),
+    }
+}
+
+/// Percent-encode a query parameter name or value, keeping only unreserved characters as they are.
+///
+/// Query parameters passed to the client as a map are encoded by the HTTP client; use this,
+/// or [`with_query`], when building a URI with a query string by hand.
+///
+/// # Arguments
+///
+/// * `value` - The name or value to encode.
+///
+/// # Returns
+///
+/// The encoded value.
+///
+/// # Example
+///
+/// ```
+/// use bitbucket_server_rs::client::encode_query_value;
+///
+/// assert_eq!(encode_query_value("ci build+1&2"), "ci%20build%2B1%262");
+/// ```
+pub fn encode_query_value(value: &str) -> String {
+    percent_encode(value)
+}
+
+/// Append query parameters to a URI, encoding their names and values.
+///
+/// # Arguments
+///
+/// * `uri` - The URI, with or without a query string already.
+/// * `params` - The names and values of the query parameters, in order.
+///
+/// # Returns
+///
+/// The URI with the query parameters appended.
+///
+/// # Example
+///
+/// ```
+/// use bitbucket_server_rs::client::with_query;
+///
+/// assert_eq!(
+///     with_query("api/latest/builds", &[("key", "ci build+1&2"), ("limit", "10")]),
+///     "api/latest/builds?key=ci%20build%2B1%262&limit=10"
+/// );
+/// ```
+pub fn with_query(uri: &str, params: &[(&str, &str)]) -> String {
+    let mut uri = uri.to_string();
+
+    for (name, value) in params {
+        uri.push(if uri.contains('?') { '&' } else { '?' });
+        uri.push_str(&encode_query_value(name));
+        uri.push('=');
+        uri.push_str(&encode_query_value(value));
+    }
+
+    uri
+}
+
+/// Percent-encode a single segment of a URI path, such as a ref, a label or a user slug.
+///
+/// Only unreserved characters are kept as they are, so a `/` in the segment is encoded too
+/// and can't be mistaken for a separator.
+///
+/// # Arguments
+///
+/// * `segment` - The path segment to encode.
+///
+/// # Returns
+///
+/// The encoded segment.
+///
+/// # Example
+///
+/// ```
+/// use bitbucket_server_rs::client::encode_path_segment;
+///
+/// assert_eq!(encode_path_segment("refs/heads/feature"), "refs%2Fheads%2Ffeature");
+/// assert_eq!(encode_path_segment("needs review"), "needs%20review");
+/// ```
+pub fn encode_path_segment(segment: &str) -> String {
+    percent_encode(segment)
+}
+
+/// Percent-encode a multi-segment URI path, such as the path of a file in a repository.
+///
+/// Each segment is encoded with [`encode_path_segment`], keeping the `/` separators between
+/// them.
+///
+/// # Arguments
+///
+/// * `path` - The path to encode.
+///
+/// # Returns
+///
+/// The encoded path.
+///
+/// # Example
+///
+/// ```
+/// use bitbucket_server_rs::client::encode_path;
+///
+/// assert_eq!(encode_path("docs/release notes/v1#2.md"), "docs/release%20notes/v1%232.md");
+/// ```
+pub fn encode_path(path: &str) -> String {
+    path.split('/')
+        .map(encode_path_segment)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Percent-encode a value, keeping only unreserved characters as they are.
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// Normalize a Bitbucket server base URL.
+///
+/// The result has exactly one trailing `/rest` segment and no trailing slash, so that
+/// `https://host/rest`, `https://host/rest/` and `https://host` all produce the same base path.
+///
+/// # Arguments
+///
+/// * `base_path` - The base URL as given by the user.
+///
+/// # Returns
+///
+/// The normalized base URL.
+///
+/// # Example
+///
+/// ```
+/// use bitbucket_server_rs::client::normalize_base_path;
+///
+/// assert_eq!(normalize_base_path("https://host"), "https://host/rest");
+/// assert_eq!(normalize_base_path("https://host/rest/"), "https://host/rest");
+/// ```
+pub fn normalize_base_path(base_path: &str) -> String {
+    let base_path = base_path.trim().trim_end_matches('/');
+
+    if base_path.ends_with("/rest") {
+        base_path.to_string()
+    } else {
+        format!("{}/rest", base_path)
     }
 }
 
 /// HTTP request and response handling implementations for the Bitbucket API client.
 impl Client {
-    /// Create a request builder with authentication headers.
-    ///
-    /// This method adds the necessary authentication and content type headers to a request.
+    /// Add the authentication header to a request.
     ///
     /// # Arguments
     ///
-    /// * `req` - The request builder to add headers to.
+    /// * `req` - The request builder to add the header to.
     ///
     /// # Returns
     ///
-    /// A request builder with the headers added.
-    pub async fn builder(&self, req: RequestBuilder) -> RequestBuilder {
+    /// A request builder with the header added.
+    fn authorize(&self, req: RequestBuilder) -> RequestBuilder {
         req.header("Authorization", format!("Bearer {}", self.api_token))
-            .header("Content-Type", "application/json")
     }
 
-    /// Set a custom HTTP client with specific configuration.
+    /// Add the XSRF opt-out header to a state-changing request, unless it is turned off.
     ///
-    /// This method allows you to use a custom HTTP client with specific configuration
-    /// options, such as timeouts, proxies, etc.
+    /// # Arguments
+    ///
+    /// * `req` - The request builder to add the header to.
+    ///
+    /// # Returns
+    ///
+    /// A request builder with the header added.
+    fn no_xsrf_check(&self, req: RequestBuilder) -> RequestBuilder {
+        if self.atlassian_token_no_check {
+            req.header(ATLASSIAN_TOKEN, "no-check")
+        } else {
+            req
+        }
+    }
+
+    /// Build the full URL of an API endpoint.
+    ///
+    /// Relative URIs are joined to the base path, i.e. they live under `/rest`. Absolute URLs
+    /// (starting with `http://` or `https://`) are used as they are, which allows reaching
+    /// endpoints outside `/rest`, such as git LFS or plugin endpoints; see [`Client::server_url`].
     ///
     /// # Arguments
     ///
-    /// * `http_client` - The custom HTTP client to use.
+    /// * `uri` - The URI of the endpoint, relative to the base path, or an absolute URL.
+    ///
+    /// # Returns
+    ///
+    /// The full URL of the endpoint.
+    pub fn url(&self, uri: &str) -> String {
+        if uri.starts_with("http://") || uri.starts_with("https://") {
+            return uri.to_string();
+        }
+
+        format!("{}/{}", self.base_path, uri.trim_start_matches('/'))
+    }
+
+    /// The root URL of the Bitbucket server, i.e. the base path without the `/rest` segment.
+    ///
+    /// Any context path the server is deployed under is kept, e.g. `https://host/bitbucket/rest`
+    /// gives `https://host/bitbucket`. Use this to build absolute URLs for endpoints that don't
+    /// live under `/rest`; the web UI links, such as [`Client::pull_request_web_url`], start
+    /// with it too.
     ///
     /// # Example
     ///
-    /// ```no_run
+    /// ```
     /// use bitbucket_server_rs::client::new;
-    /// use reqwest::ClientBuilder;
-    /// use std::time::Duration;
-    ///
-    /// let mut client = new("https://bitbucket-server/rest", "API_TOKEN");
     ///
-    /// // Create a custom HTTP client with a 30-second timeout
-    /// let http_client = ClientBuilder::new()
-    ///     .timeout(Duration::from_secs(30))
-    ///     .build()
-    ///     .expect("Failed to build HTTP client");
+    /// let client = new("https://bitbucket-server/rest", "API_TOKEN");
     ///
-    /// // Set the custom HTTP client
-    /// client.with_http_client(http_client);
+    /// assert_eq!(client.server_url(), "https://bitbucket-server");
+    /// assert_eq!(
+    ///     client.url(&format!("{}/lfs/info", client.server_url())),
+    ///     "https://bitbucket-server/lfs/info"
+    /// );
     /// ```
-    pub fn with_http_client(&mut self, http_client: reqwest::Client) {
-        self.http_client = http_client;
+    pub fn server_url(&self) -> &str {
+        self.base_path
+            .strip_suffix("/rest")
+            .unwrap_or(&self.base_path)
     }
 
-    /// Send a GET request to the Bitbucket Server API.
-    ///
-    /// This method sends a GET request to the specified URI with the given query parameters.
+    /// The web UI URL of a pull request, e.g. to post a clickable link to chat.
     ///
     /// # Arguments
     ///
-    /// * `uri` - The URI to send the request to, relative to the base path.
-    /// * `params` - Optional query parameters to include in the request.
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `pull_request_id` - The ID of the pull request
     ///
     /// # Returns
     ///
-    /// A Result containing either the response data or an error.
-    pub async fn get<T: ApiRequest>(
+    /// The URL of the pull request's page.
+    pub fn pull_request_web_url(
         &self,
-        uri: &str,
-        params: Option<HashMap<String, String>>,
-    ) -> ApiResponse<T::Output> {
-        let uri = format!("{}/{}", self.base_path, uri);
-        let get = self.http_client.get(uri).query(&params);
-
-        let req = self
-            .builder(get)
-            .await
-            .build()
-            .expect("Failed to build request");
-
-        let response = self.http_client.execute(req).await.map_err(|e| {
-            Error::RequestError(format!("Error sending request: {:?}", e))
-        })?;
-
-        Self::process_response::<T>(response).await
+        project_key: &str,
+        repository_slug: &str,
+        pull_request_id: u64,
+    ) -> String {
+        format!(
+            "{}/pull-requests/{}",
+            self.repository_web_url(project_key, repository_slug),
+            pull_request_id
+        )
     }
 
-    /// Send a POST request to the Bitbucket Server API.
-    ///
-    /// This method sends a POST request to the specified URI with the given body.
+    /// The web UI URL of a commit.
     ///
     /// # Arguments
     ///
-    /// * `uri` - The URI to send the request to, relative to the base path.
-    /// * `body` - The body to include in the request.
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `commit_id` - The ID of the commit
     ///
     /// # Returns
     ///
-    /// A Result containing either the response data or an error.
-    pub async fn post<T: ApiRequest>(
-        &self,
-        uri: &str,
-        body: &str,
-    ) -> ApiResponse<<T as ApiRequest>::Output> {
-        let uri = format!("{}/{}", self.base_path, uri);
-        let post = self.http_client.post(uri).body(body.to_string());
-
-        let req = self
-            .builder(post)
-            .await
-            .build()
-            .expect("Failed to build request");
-
-        let response = self.http_client.execute(req).await.map_err(|e| {
-            Error::RequestError(format!("Error sending request: {:?}", e))
-        })?;
-
-        Self::process_response::<T>(response).await
+    /// The URL of the commit's page.
+    pub fn commit_web_url(&self, project_key: &str, repository_slug: &str, commit_id: &str) -> String {
+        format!(
+            "{}/commits/{}",
+            self.repository_web_url(project_key, repository_slug),
+            encode_path_segment(commit_id)
+        )
     }
 
-    /// Process the response from the Bitbucket Server API.
-    ///
-    /// This method processes the response from the API, handling different status codes
-    /// and converting the response body to the expected output type.
+    /// The web UI URL for browsing the files of a branch.
     ///
     /// # Arguments
     ///
-    /// * `response` - The response from the API.
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `branch` - The branch, e.g. `refs/heads/feature`
     ///
     /// # Returns
     ///
-    /// A Result containing either the response data or an error.
-    async fn process_response<T: ApiRequest>(
-        response: Response,
-    ) -> ApiResponse<<T as ApiRequest>::Output> {
-        match response.status() {
-            status if status.is_success() => {
-                let json = response.text().await.map_err(|e| {
-                    Error::ResponseError(format!("Error reading response: {e:#?}"))
-                })?;
-
-                Self::make_api_response::<T>(json.as_str())
-            }
-            status if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN => {
-                Err(Error::Unauthorized)
-            }
-            status if status.is_client_error() => Err(Error::ResponseError(format!(
-                "HTTP Client error [{}]: {}",
-                status.as_u16(),
-                response.text().await.unwrap_or_default(),
-            ))),
-            status if status.is_server_error() => Err(Error::ResponseError(format!(
-                "HTTP Server error [{}]: {}",
-                status.as_u16(),
-                response.text().await.unwrap_or_default(),
-            ))),
-            _ => Err(Error::Unexpected(format!(
-                "Unexpected HTTP Response [{}]: {}",
-                response.status(),
-                response.text().await.unwrap_or_default()
-            ))),
-        }
+    /// The URL of the branch's file browser.
+    pub fn branch_web_url(&self, project_key: &str, repository_slug: &str, branch: &str) -> String {
+        with_query(
+            &format!("{}/browse", self.repository_web_url(project_key, repository_slug)),
+            &[("at", branch)],
+        )
     }
 
-    /// Convert a JSON string to an API response.
-    ///
-    /// This method converts a JSON string to an API response, handling empty responses
-    /// and deserialization errors.
+    /// The web UI URL comparing the commits of two branches.
     ///
     /// # Arguments
     ///
-    /// * `json` - The JSON string to convert.
+    /// * `project_key` - The key of the project containing the repository
+    /// * `repository_slug` - The slug of the repository
+    /// * `source_branch` - The branch with the changes, e.g. `refs/heads/feature`
+    /// * `target_branch` - The branch to compare against, e.g. `refs/heads/main`
     ///
     /// # Returns
     ///
-    /// A Result containing either the deserialized data or an error.
-    fn make_api_response<T: ApiRequest>(json: &str) -> ApiResponse<<T as ApiRequest>::Output> {
-        // if the response is empty, Ok(None) means the response was successful but empty
-        if json.len() == 0 {
-            return Ok(None);
+    /// The URL of the comparison page, listing the commits on the source branch that are not on
+    /// the target branch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::client::new;
+    ///
+    /// let client = new("https://host/rest", "API_TOKEN");
+    ///
+    /// assert_eq!(
+    ///     client.compare_web_url("PRJ", "repo", "refs/heads/feature", "refs/heads/main"),
+    ///     "https://host/projects/PRJ/repos/repo/compare/commits\
+    ///      ?sourceBranch=refs%2Fheads%2Ffeature&targetBranch=refs%2Fheads%2Fmain"
+    /// );
+    /// ```
+    pub fn compare_web_url(
+        &self,
+        project_key: &str,
+        repository_slug: &str,
+        source_branch: &str,
+        target_branch: &str,
+    ) -> String {
+        with_query(
+            &format!(
+                "{}/compare/commits",
+                self.repository_web_url(project_key, repository_slug)
+            ),
+            &[("sourceBranch", source_branch), ("targetBranch", target_branch)],
+        )
+    }
+
+    /// The web UI URL of a repository, the root of the other web URLs.
+    ///
+    /// The project key and repository slug are percent-encoded, like the path segments of API
+    /// requests.
+    fn repository_web_url(&self, project_key: &str, repository_slug: &str) -> String {
+        format!(
+            "{}/projects/{}/repos/{}",
+            self.server_url(),
+            encode_path_segment(project_key),
+            encode_path_segment(repository_slug)
+        )
+    }
+
+    /// Set a custom HTTP client with specific configuration.
+    ///
+    /// This method allows you to use a custom HTTP client with specific configuration
+    /// options, such as timeouts, proxies, etc.
+    ///
+    /// # Arguments
+    ///
+    /// * `http_client` - The custom HTTP client to use.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::new;
+    /// use reqwest::ClientBuilder;
+    /// use std::time::Duration;
+    ///
+    /// let mut client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    /// // Create a custom HTTP client with a 30-second timeout
+    /// let http_client = ClientBuilder::new()
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .expect("Failed to build HTTP client");
+    ///
+    /// // Set the custom HTTP client
+    /// client.with_http_client(http_client);
+    /// ```
+    pub fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.http_client = http_client;
+    }
+
+    /// The rate-limit state reported by the last response that carried rate-limit headers.
+    ///
+    /// The state is updated on every response with `X-RateLimit-*` headers and shared by all
+    /// clones of the client; responses without those headers leave it unchanged.
+    ///
+    /// # Returns
+    ///
+    /// The last-seen rate-limit state, or `None` if no response carried rate-limit headers yet.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::new;
+    /// use std::time::Duration;
+    ///
+    /// let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    /// // ... send some requests ...
+    ///
+    /// let interval = match client.rate_limit_state().and_then(|state| state.remaining) {
+    ///     Some(remaining) if remaining < 10 => Duration::from_secs(60),
+    ///     _ => Duration::from_secs(5),
+    /// };
+    /// ```
+    pub fn rate_limit_state(&self) -> Option<RateLimitState> {
+        self.rate_limit_state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Set how idempotent requests are retried when they fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry_policy` - The retry policy to use.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, RetryPolicy};
+    /// use std::time::Duration;
+    ///
+    /// let mut client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    /// // Retry up to 3 times, waiting 200ms, 400ms and 800ms
+    /// client.with_retry_policy(RetryPolicy {
+    ///     max_retries: 3,
+    ///     initial_backoff: Duration::from_millis(200),
+    /// });
+    /// ```
+    pub fn with_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Stop sending requests for a while after repeated failures; see [`CircuitBreakerPolicy`].
+    ///
+    /// This protects both a long-running service and a struggling server from requests that
+    /// are bound to fail. Requests short-circuited by the open circuit fail with
+    /// `Error::CircuitOpen`.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit_breaker` - The circuit breaker policy to use.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, CircuitBreakerPolicy};
+    /// use std::time::Duration;
+    ///
+    /// let mut client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    /// // Back off for 30 seconds after 5 failures within a minute
+    /// client.with_circuit_breaker(CircuitBreakerPolicy {
+    ///     failure_threshold: 5,
+    ///     window: Duration::from_secs(60),
+    ///     cooldown: Duration::from_secs(30),
+    /// });
+    /// ```
+    pub fn with_circuit_breaker(&mut self, circuit_breaker: CircuitBreakerPolicy) {
+        self.circuit_breaker = Some(circuit_breaker);
+    }
+
+    /// Serialize request bodies as indented, multi-line JSON.
+    ///
+    /// This is a debugging aid, e.g. to read bodies captured by a proxy. Fields are always in
+    /// the order they are declared in, so bodies are reproducible either way. Keep the default
+    /// compact bodies for production use.
+    ///
+    /// # Arguments
+    ///
+    /// * `pretty_bodies` - Whether to indent request bodies
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::new;
+    ///
+    /// let mut client = new("https://bitbucket-server/rest", "API_TOKEN");
+    /// client.with_pretty_bodies(true);
+    /// ```
+    pub fn with_pretty_bodies(&mut self, pretty_bodies: bool) {
+        self.pretty_bodies = pretty_bodies;
+    }
+
+    /// Send `X-Atlassian-Token: no-check` on state-changing requests (POST, PUT and DELETE).
+    ///
+    /// Instances with XSRF protection enforced, often behind a proxy, reject state-changing
+    /// requests without the header with `Error::XsrfCheckFailed`. It's on by default; turn it
+    /// off for proxies that strip or reject unknown headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `no_check` - Whether to send the header
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::new;
+    ///
+    /// let mut client = new("https://bitbucket-server/rest", "API_TOKEN");
+    /// client.with_atlassian_token_no_check(false);
+    /// ```
+    pub fn with_atlassian_token_no_check(&mut self, no_check: bool) {
+        self.atlassian_token_no_check = no_check;
+    }
+
+    /// Serialize a request body as JSON, indented if [`Client::with_pretty_bodies`] is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - The payload to serialize
+    ///
+    /// # Returns
+    ///
+    /// The JSON body of the request.
+    pub fn to_body<T: Serialize>(&self, body: &T) -> String {
+        // the payload types only hold strings, numbers, booleans and string-keyed maps,
+        // which always serialize
+        if self.pretty_bodies {
+            serde_json::to_string_pretty(body).unwrap()
+        } else {
+            serde_json::to_string(body).unwrap()
+        }
+    }
+
+    /// Send a GET request to the Bitbucket Server API.
+    ///
+    /// This method sends a GET request to the specified URI with the given query parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    /// * `params` - Optional query parameters to include in the request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data or an error.
+    pub async fn get<T: ApiRequest>(
+        &self,
+        uri: &str,
+        params: Option<BTreeMap<String, String>>,
+    ) -> ApiResponse<T::Output> {
+        self.get_with_headers::<T::Output>(uri, params)
+            .await
+            .map(|(data, _)| data)
+    }
+
+    /// Send a GET request to the Bitbucket Server API, keeping the response headers.
+    ///
+    /// This method behaves like [`Client::get`], but also returns the headers of the response.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    /// * `params` - Optional query parameters to include in the request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    pub async fn get_with_headers<O: DeserializeOwned>(
+        &self,
+        uri: &str,
+        params: Option<BTreeMap<String, String>>,
+    ) -> ApiResponseWithHeaders<O> {
+        self.send_get::<O>(uri, params, None).await
+    }
+
+    /// Send a conditional GET request to the Bitbucket Server API, keeping the response headers.
+    ///
+    /// The request carries an `If-None-Match` header with the `ETag` of a previous response.
+    /// If the resource hasn't changed, the server responds with `304 Not Modified` and no data
+    /// is returned; see [`Conditional`] to tell that apart from an empty response.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    /// * `params` - Optional query parameters to include in the request.
+    /// * `etag` - The `ETag` of the previous response.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    pub async fn get_if_none_match_with_headers<O: DeserializeOwned>(
+        &self,
+        uri: &str,
+        params: Option<BTreeMap<String, String>>,
+        etag: &str,
+    ) -> ApiResponseWithHeaders<O> {
+        self.send_get::<O>(uri, params, Some(etag)).await
+    }
+
+    /// Send a GET request, with an `If-None-Match` header if an `ETag` is given.
+    async fn send_get<O: DeserializeOwned>(
+        &self,
+        uri: &str,
+        params: Option<BTreeMap<String, String>>,
+        etag: Option<&str>,
+    ) -> ApiResponseWithHeaders<O> {
+        let request = match etag {
+            Some(etag) => self.build_conditional_request(uri, params, etag)?,
+            None => self.build_request(Method::GET, uri, params, None)?,
+        };
+
+        self.send_request::<O>(request).await
+    }
+
+    /// Build a conditional GET request, without sending it.
+    ///
+    /// The request is built as by [`Client::build_request`], and carries an `If-None-Match`
+    /// header with the `ETag` of a previous response.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI of the request, relative to the base path, or an absolute URL.
+    /// * `params` - Optional query parameters to include in the request.
+    /// * `etag` - The `ETag` of the previous response.
+    ///
+    /// # Returns
+    ///
+    /// The request, ready to be sent with [`Client::send_request`], or an error if it can't be
+    /// built, e.g. because the client has no base URL.
+    pub fn build_conditional_request(
+        &self,
+        uri: &str,
+        params: Option<BTreeMap<String, String>>,
+        etag: &str,
+    ) -> Result<reqwest::Request, Error> {
+        self.request_builder(Method::GET, uri, params, None)?
+            .header(IF_NONE_MATCH, etag)
+            .build()
+            .map_err(Error::from_builder)
+    }
+
+    /// Send a POST request to the Bitbucket Server API.
+    ///
+    /// This method sends a POST request to the specified URI with the given body.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    /// * `body` - The body to include in the request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data or an error.
+    pub async fn post<T: ApiRequest>(
+        &self,
+        uri: &str,
+        body: &str,
+    ) -> ApiResponse<<T as ApiRequest>::Output> {
+        self.post_with_headers::<T::Output>(uri, body)
+            .await
+            .map(|(data, _)| data)
+    }
+
+    /// Send a POST request to the Bitbucket Server API, keeping the response headers.
+    ///
+    /// This method behaves like [`Client::post`], but also returns the headers of the response,
+    /// e.g. the `Location` of a newly created resource.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    /// * `body` - The body to include in the request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    pub async fn post_with_headers<O: DeserializeOwned>(
+        &self,
+        uri: &str,
+        body: &str,
+    ) -> ApiResponseWithHeaders<O> {
+        self.send_request::<O>(self.build_request(Method::POST, uri, None, Some(body))?)
+            .await
+    }
+
+    /// Send a POST request without a body to the Bitbucket Server API.
+    ///
+    /// Some endpoints (e.g. watching or reopening a pull request) take no payload. The request
+    /// is sent without a body and without the `Content-Type: application/json` header, which
+    /// some Bitbucket versions reject when the body is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data or an error.
+    pub async fn post_empty<T: ApiRequest>(
+        &self,
+        uri: &str,
+    ) -> ApiResponse<<T as ApiRequest>::Output> {
+        self.post_empty_with_headers::<T::Output>(uri)
+            .await
+            .map(|(data, _)| data)
+    }
+
+    /// Send a POST request without a body to the Bitbucket Server API, keeping the response
+    /// headers.
+    ///
+    /// This method behaves like [`Client::post_empty`], but also returns the headers of the
+    /// response.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    pub async fn post_empty_with_headers<O: DeserializeOwned>(
+        &self,
+        uri: &str,
+    ) -> ApiResponseWithHeaders<O> {
+        self.send_request::<O>(self.build_request(Method::POST, uri, None, None)?)
+            .await
+    }
+
+    /// Send a PUT request to the Bitbucket Server API.
+    ///
+    /// This method sends a PUT request to the specified URI with the given body, e.g. to update
+    /// a pull request or the settings of a repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    /// * `body` - The body to include in the request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data or an error.
+    pub async fn put<T: ApiRequest>(
+        &self,
+        uri: &str,
+        body: &str,
+    ) -> ApiResponse<<T as ApiRequest>::Output> {
+        self.put_with_headers::<T::Output>(uri, body)
+            .await
+            .map(|(data, _)| data)
+    }
+
+    /// Send a PUT request to the Bitbucket Server API, keeping the response headers.
+    ///
+    /// This method behaves like [`Client::put`], but also returns the headers of the response.
+    /// PUT requests are idempotent, so they are safe to retry on server errors (HTTP 5xx).
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    /// * `body` - The body to include in the request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    pub async fn put_with_headers<O: DeserializeOwned>(
+        &self,
+        uri: &str,
+        body: &str,
+    ) -> ApiResponseWithHeaders<O> {
+        self.send_request::<O>(self.build_request(Method::PUT, uri, None, Some(body))?)
+            .await
+    }
+
+    /// Send a DELETE request to the Bitbucket Server API.
+    ///
+    /// This method sends a DELETE request to the specified URI with the given query parameters,
+    /// e.g. to delete a build status or a webhook. The request has no body, so it carries no
+    /// `Content-Type`. Deletions usually respond with no content, i.e. `Ok(None)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    /// * `params` - Optional query parameters to include in the request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data or an error.
+    pub async fn delete<T: ApiRequest>(
+        &self,
+        uri: &str,
+        params: Option<BTreeMap<String, String>>,
+    ) -> ApiResponse<T::Output> {
+        self.delete_empty_with_headers::<T::Output>(uri, params)
+            .await
+            .map(|(data, _)| data)
+    }
+
+    /// Send a DELETE request without a body to the Bitbucket Server API, keeping the response
+    /// headers.
+    ///
+    /// This method behaves like [`Client::delete`], but also returns the headers of the
+    /// response.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    /// * `params` - Optional query parameters to include in the request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    pub async fn delete_empty_with_headers<O: DeserializeOwned>(
+        &self,
+        uri: &str,
+        params: Option<BTreeMap<String, String>>,
+    ) -> ApiResponseWithHeaders<O> {
+        self.send_request::<O>(self.build_request(Method::DELETE, uri, params, None)?)
+            .await
+    }
+
+    /// Send a DELETE request with a JSON body to the Bitbucket Server API, keeping the response
+    /// headers.
+    ///
+    /// Some endpoints (e.g. deleting a branch) take the resource to delete in the body rather
+    /// than in the URI.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    /// * `body` - The body to include in the request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    pub async fn delete_with_headers<O: DeserializeOwned>(
+        &self,
+        uri: &str,
+        body: &str,
+    ) -> ApiResponseWithHeaders<O> {
+        self.send_request::<O>(self.build_request(Method::DELETE, uri, None, Some(body))?)
+            .await
+    }
+
+    /// Send a GET request for a plain-text resource to the Bitbucket Server API.
+    ///
+    /// This method is used for endpoints that return raw text (e.g. patches and diffs)
+    /// instead of JSON. The response body is returned verbatim.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    /// * `params` - Optional query parameters to include in the request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response text or an error.
+    pub async fn get_text(
+        &self,
+        uri: &str,
+        params: Option<BTreeMap<String, String>>,
+    ) -> ApiResponse<String> {
+        self.get_text_with_headers(uri, params)
+            .await
+            .map(|(text, _)| text)
+    }
+
+    /// Send a GET request for a plain-text resource, keeping the response headers.
+    ///
+    /// This method behaves like [`Client::get_text`], but also returns the headers of the response.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    /// * `params` - Optional query parameters to include in the request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response text and headers or an error.
+    pub async fn get_text_with_headers(
+        &self,
+        uri: &str,
+        params: Option<BTreeMap<String, String>>,
+    ) -> ApiResponseWithHeaders<String> {
+        self.send_text_request(self.build_text_request(uri, params)?)
+            .await
+    }
+
+    /// Build a GET request for a plain-text resource, without sending it.
+    ///
+    /// The request is built as by [`Client::build_request`], and accepts `text/plain`.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI of the request, relative to the base path, or an absolute URL.
+    /// * `params` - Optional query parameters to include in the request.
+    ///
+    /// # Returns
+    ///
+    /// The request, ready to be sent with [`Client::send_text_request`], or an error if it
+    /// can't be built.
+    pub fn build_text_request(
+        &self,
+        uri: &str,
+        params: Option<BTreeMap<String, String>>,
+    ) -> Result<reqwest::Request, Error> {
+        self.request_builder(Method::GET, uri, params, None)?
+            .header(ACCEPT, "text/plain")
+            .build()
+            .map_err(Error::from_builder)
+    }
+
+    /// Send a built request for a plain-text resource, keeping the response headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send, e.g. from [`Client::build_text_request`].
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response text and headers or an error.
+    pub async fn send_text_request(
+        &self,
+        request: reqwest::Request,
+    ) -> ApiResponseWithHeaders<String> {
+        let response = self.execute(request).await?;
+
+        let headers = response.headers().clone();
+        let text = Self::response_text(response).await?;
+
+        // an empty body means the response was successful but empty
+        if text.is_empty() {
+            return Ok((None, headers));
+        }
+
+        Ok((Some(text), headers))
+    }
+
+    /// Send a GET request for a binary resource to the Bitbucket Server API.
+    ///
+    /// This method is used for endpoints that return raw bytes (e.g. attachments and avatars)
+    /// instead of JSON. The response body is returned unmodified.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    /// * `params` - Optional query parameters to include in the request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response bytes or an error.
+    pub async fn get_bytes(
+        &self,
+        uri: &str,
+        params: Option<BTreeMap<String, String>>,
+    ) -> ApiResponse<Vec<u8>> {
+        self.get_bytes_with_headers(uri, params)
+            .await
+            .map(|(bytes, _)| bytes)
+    }
+
+    /// Send a GET request for a binary resource, keeping the response headers.
+    ///
+    /// This method behaves like [`Client::get_bytes`], but also returns the headers of the
+    /// response, e.g. its `Content-Type`.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI to send the request to, relative to the base path, or an absolute URL.
+    /// * `params` - Optional query parameters to include in the request.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response bytes and headers or an error.
+    pub async fn get_bytes_with_headers(
+        &self,
+        uri: &str,
+        params: Option<BTreeMap<String, String>>,
+    ) -> ApiResponseWithHeaders<Vec<u8>> {
+        self.send_bytes_request(self.build_bytes_request(uri, params)?)
+            .await
+    }
+
+    /// Build a GET request for a binary resource, without sending it.
+    ///
+    /// The request is built as by [`Client::build_request`], and accepts any content type.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI of the request, relative to the base path, or an absolute URL.
+    /// * `params` - Optional query parameters to include in the request.
+    ///
+    /// # Returns
+    ///
+    /// The request, ready to be sent with [`Client::send_bytes_request`], or an error if it
+    /// can't be built.
+    pub fn build_bytes_request(
+        &self,
+        uri: &str,
+        params: Option<BTreeMap<String, String>>,
+    ) -> Result<reqwest::Request, Error> {
+        self.request_builder(Method::GET, uri, params, None)?
+            .header(ACCEPT, "*/*")
+            .build()
+            .map_err(Error::from_builder)
+    }
+
+    /// Send a built request for a binary resource, keeping the response headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send, e.g. from [`Client::build_bytes_request`].
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response bytes and headers or an error.
+    pub async fn send_bytes_request(
+        &self,
+        request: reqwest::Request,
+    ) -> ApiResponseWithHeaders<Vec<u8>> {
+        let response = self.execute(request).await?;
+
+        let headers = response.headers().clone();
+        let bytes = Self::response_bytes(response).await?;
+
+        // an empty body means the response was successful but empty
+        if bytes.is_empty() {
+            return Ok((None, headers));
+        }
+
+        Ok((Some(bytes), headers))
+    }
+
+    /// Build a request to the Bitbucket Server API, without sending it.
+    ///
+    /// Every request is built here, so the request sent is the one [`DescribeRequest::describe`]
+    /// describes. It carries the authentication header; a request with a body also carries
+    /// `Content-Type: application/json`, and a request other than GET carries the XSRF opt-out
+    /// header unless it is turned off (see [`Client::with_atlassian_token_no_check`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The HTTP method of the request.
+    /// * `uri` - The URI of the request, relative to the base path, or an absolute URL.
+    /// * `params` - Optional query parameters to include in the request.
+    /// * `body` - The JSON body of the request, if it has one.
+    ///
+    /// # Returns
+    ///
+    /// The request, ready to be sent with [`Client::send_request`], or an error if it can't be
+    /// built, e.g. because the client has no base URL.
+    pub fn build_request(
+        &self,
+        method: Method,
+        uri: &str,
+        params: Option<BTreeMap<String, String>>,
+        body: Option<&str>,
+    ) -> Result<reqwest::Request, Error> {
+        self.request_builder(method, uri, params, body)?
+            .build()
+            .map_err(Error::from_builder)
+    }
+
+    /// Start a request with the headers every request of its kind carries.
+    ///
+    /// See [`Client::build_request`].
+    fn request_builder(
+        &self,
+        method: Method,
+        uri: &str,
+        params: Option<BTreeMap<String, String>>,
+        body: Option<&str>,
+    ) -> Result<RequestBuilder, Error> {
+        let url = self.url(uri);
+        // e.g. a request built from `Api::template` and sent with `send` instead of `send_with`
+        if self.base_path.is_empty() && !url.contains("://") {
+            return Err(Error::RequestError(format!(
+                "the client has no base URL to send {uri} to"
+            )));
+        }
+
+        let is_get = method == Method::GET;
+        let mut req = self
+            .authorize(self.http_client.request(method, url))
+            .query(&params);
+        if !is_get {
+            req = self.no_xsrf_check(req);
+        }
+        if let Some(body) = body {
+            req = req
+                .header("Content-Type", "application/json")
+                .body(body.to_string());
+        }
+
+        Ok(req)
+    }
+
+    /// Send a built request to the Bitbucket Server API, keeping the response headers.
+    ///
+    /// GET and PUT requests are idempotent, so they are retried according to the retry policy;
+    /// other requests are sent once.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send, e.g. from [`Client::build_request`].
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    pub async fn send_request<O: DeserializeOwned>(
+        &self,
+        request: reqwest::Request,
+    ) -> ApiResponseWithHeaders<O> {
+        let response = self.execute(request).await?;
+
+        Self::process_response::<O>(response).await
+    }
+
+    /// Send a request, retrying it if it is idempotent.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response or an error.
+    async fn execute(&self, request: reqwest::Request) -> Result<Response, Error> {
+        if matches!(*request.method(), Method::GET | Method::PUT) {
+            return self.execute_with_retries(request).await;
+        }
+
+        self.admit()?;
+        self.execute_once(request)
+            .await
+            .map_err(|e| Error::RequestError(format!("Error sending request: {:?}", e)))
+    }
+
+    /// Send a request once, recording the rate-limit state of its response.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response or the error of the HTTP client.
+    async fn execute_once(&self, request: reqwest::Request) -> Result<Response, reqwest::Error> {
+        let response = self.http_client.execute(request).await;
+
+        if let Some(policy) = &self.circuit_breaker {
+            let failed = response
+                .as_ref()
+                .map_or(true, |response| response.status().is_server_error());
+            self.circuit_breaker_state
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .record(policy, failed, Instant::now());
+        }
+
+        let response = response?;
+
+        if let Some(state) = RateLimitState::from_headers(response.headers()) {
+            *self
+                .rate_limit_state
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(state);
+        }
+
+        Ok(response)
+    }
+
+    /// Check that the circuit breaker lets a request through.
+    ///
+    /// # Returns
+    ///
+    /// `Error::CircuitOpen` if the circuit is open.
+    fn admit(&self) -> Result<(), Error> {
+        let Some(policy) = &self.circuit_breaker else {
+            return Ok(());
+        };
+
+        self.circuit_breaker_state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .admit(policy, Instant::now())
+            .map_err(|retry_after| Error::CircuitOpen { retry_after })
+    }
+
+    /// Send an idempotent request, retrying it according to the retry policy.
+    ///
+    /// Only the method and path of the request are logged; its query, headers and body may
+    /// carry sensitive data and are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response of the last attempt or an error.
+    async fn execute_with_retries(&self, request: reqwest::Request) -> Result<Response, Error> {
+        let method = request.method().clone();
+        let path = request.url().path().to_string();
+        let mut delay = self.retry_policy.initial_backoff;
+        let mut retries = 0;
+
+        loop {
+            let attempt = match request.try_clone() {
+                Some(attempt) if retries < self.retry_policy.max_retries => attempt,
+                _ => break,
+            };
+
+            self.admit()?;
+
+            let reason = match self.execute_once(attempt).await {
+                Ok(response) if response.status().is_server_error() => {
+                    format!("HTTP Server error [{}]", response.status().as_u16())
+                }
+                Err(e) if !e.is_builder() => format!("{}", e.without_url()),
+                result => {
+                    if retries > 0 && result.as_ref().is_ok_and(|r| r.status().is_success()) {
+                        info!("{method} {path} succeeded after {retries} retries");
+                    }
+                    return result.map_err(|e| {
+                        Error::RequestError(format!("Error sending request: {:?}", e))
+                    });
+                }
+            };
+
+            retries += 1;
+            warn!(
+                "Retrying {method} {path} ({retries} of {}) in {delay:?} after: {reason}",
+                self.retry_policy.max_retries
+            );
+            tokio::time::sleep(delay).await;
+            delay *= 2;
+        }
+
+        self.admit()?;
+        let response = self.execute_once(request).await.map_err(|e| {
+            Error::RequestError(format!("Error sending request: {:?}", e))
+        })?;
+        if retries > 0 && response.status().is_success() {
+            info!("{method} {path} succeeded after {retries} retries");
+        }
+
+        Ok(response)
+    }
+
+    /// Process the response from the Bitbucket Server API.
+    ///
+    /// This method processes the response from the API, handling different status codes
+    /// and converting the response body to the expected output type.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - The response from the API.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response data and headers or an error.
+    async fn process_response<O: DeserializeOwned>(
+        response: Response,
+    ) -> ApiResponseWithHeaders<O> {
+        let headers = response.headers().clone();
+        let json = Self::response_text(response).await?;
+
+        Ok((Self::make_api_response::<O>(json.as_str())?, headers))
+    }
+
+    /// Read the body of a response from the Bitbucket Server API.
+    ///
+    /// This method checks the status code of the response, mapping error statuses to
+    /// the appropriate `Error` variant, and returns the body text of successful responses.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - The response from the API.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response body or an error.
+    async fn response_text(response: Response) -> Result<String, Error> {
+        match response.status() {
+            // the body is read whatever the media type, e.g. `application/json; charset=utf-8`,
+            // and decoded with the charset of the `Content-Type`, if any
+            status if status.is_success() => response.text().await.map_err(|e| {
+                Error::ResponseError(format!("Error reading response: {e:#?}"))
+            }),
+            // the caller sent an ETag and the resource hasn't changed
+            StatusCode::NOT_MODIFIED => Ok(String::new()),
+            status if status.is_redirection() => Err(Error::Redirect {
+                location: response
+                    .headers()
+                    .get(LOCATION)
+                    .and_then(|location| location.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string(),
+            }),
+            status if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN => {
+                match response
+                    .headers()
+                    .get(AUTHENTICATION_DENIED_REASON)
+                    .and_then(|reason| reason.to_str().ok())
+                {
+                    // a CAPTCHA must be solved before any further login attempt succeeds
+                    Some(reason) => Err(Error::AuthenticationThrottled {
+                        reason: reason.to_string(),
+                    }),
+                    None => match response.text().await.unwrap_or_default() {
+                        body if status == StatusCode::UNAUTHORIZED => Err(Error::Unauthenticated {
+                            status: status.as_u16(),
+                            body,
+                        }),
+                        // the request lacked `X-Atlassian-Token: no-check`
+                        body if body.contains(XSRF_CHECK_FAILED) => Err(Error::XsrfCheckFailed),
+                        body => Err(Error::Forbidden {
+                            status: status.as_u16(),
+                            body,
+                        }),
+                    },
+                }
+            }
+            status if status.is_client_error() => Err(Error::HttpClientError {
+                status: status.as_u16(),
+                body: response.text().await.unwrap_or_default(),
+            }),
+            status if status.is_server_error() => Err(Error::HttpServerError {
+                status: status.as_u16(),
+                body: response.text().await.unwrap_or_default(),
+            }),
+            status => Err(Error::UnexpectedResponse {
+                status: status.as_u16(),
+                body: response.text().await.unwrap_or_default(),
+            }),
+        }
+    }
+
+    /// Read the body of a response from the Bitbucket Server API as raw bytes.
+    ///
+    /// Error statuses are mapped like [`Client::response_text`] does.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - The response from the API.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the response body or an error.
+    async fn response_bytes(response: Response) -> Result<Vec<u8>, Error> {
+        if !response.status().is_success() {
+            return Self::response_text(response).await.map(String::into_bytes);
+        }
+
+        response
+            .bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| Error::ResponseError(format!("Error reading response: {e:#?}")))
+    }
+
+    /// Convert a JSON string to an API response.
+    ///
+    /// This method converts a JSON string to an API response, handling empty responses
+    /// and deserialization errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The JSON string to convert.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the deserialized data or an error.
+    fn make_api_response<O: DeserializeOwned>(json: &str) -> ApiResponse<O> {
+        // if the response is empty, Ok(None) means the response was successful but empty
+        if json.is_empty() {
+            return Ok(None);
         }
 
         // deserialize into the request's output type
-        let data = serde_json::from_str::<T::Output>(json)
-            .map_err(|e| Error::ResponseError(format!("Error deserializing: {e:#?}")))?;
+        let data = serde_json::from_str::<O>(json)
+            .map_err(|source| Error::DeserializationError { source })?;
 
         Ok(Some(data))
     }
@@ -280,22 +1762,590 @@ impl Client {
 
 /// The response from the API.
 ///
-/// This is a `Result` type that contains an `Option` of the response data or an `ApiError`.
+/// This is a `Result` type that contains an `Option` of the response data or an [`Error`].
 /// The `Option` is used because some API responses may be empty (e.g., successful DELETE requests).
 pub type ApiResponse<T> = Result<Option<T>, Error>;
 
+/// The response from the API, together with the response headers.
+///
+/// This is returned by [`ApiRequest::send_with_headers`] for callers that need headers such as
+/// `ETag` or the `Location` of a newly created resource.
+pub type ApiResponseWithHeaders<T> = Result<(Option<T>, HeaderMap), Error>;
+
+/// The result of a conditional request, i.e. a request carrying the `ETag` of a previous response.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Conditional<T> {
+    /// The resource changed, or no `ETag` was sent; the new `ETag` is kept for the next request
+    Modified {
+        /// The response data
+        data: Option<T>,
+
+        /// The `ETag` of the response, if the server sent one
+        etag: Option<String>,
+    },
+
+    /// The resource hasn't changed since the response with the given `ETag` (HTTP 304)
+    NotModified {
+        /// The `ETag` to send with the next request
+        etag: String,
+    },
+}
+
+impl<T> Conditional<T> {
+    /// Build the result of a conditional request from its response.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The response data
+    /// * `headers` - The response headers
+    /// * `sent_etag` - The `ETag` that was sent with the request, if any
+    ///
+    /// # Returns
+    ///
+    /// `NotModified` if an `ETag` was sent and the response is empty, `Modified` otherwise.
+    pub fn from_response(data: Option<T>, headers: &HeaderMap, sent_etag: Option<&str>) -> Self {
+        let etag = headers
+            .get(ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(str::to_string);
+
+        match (data, sent_etag) {
+            (None, Some(sent_etag)) => Conditional::NotModified {
+                etag: etag.unwrap_or_else(|| sent_etag.to_string()),
+            },
+            (data, _) => Conditional::Modified { data, etag },
+        }
+    }
+
+    /// The `ETag` to send with the next request, if there is one.
+    pub fn etag(&self) -> Option<&str> {
+        match self {
+            Conditional::Modified { etag, .. } => etag.as_deref(),
+            Conditional::NotModified { etag } => Some(etag),
+        }
+    }
+
+    /// Check whether the resource changed.
+    pub fn is_modified(&self) -> bool {
+        matches!(self, Conditional::Modified { .. })
+    }
+}
+
 /// Trait for implementing API requests.
 ///
 /// This trait defines the interface for all API requests. It requires implementing
-/// the `Output` associated type and the `send` method.
+/// the `Output` associated type and the `send` and `send_with_headers` methods.
 pub trait ApiRequest {
     /// The type of the response to deserialize to.
     type Output: DeserializeOwned;
 
+    /// A stable name for the endpoint, e.g. `pull_request_list`, for labelling metrics.
+    ///
+    /// Unlike the request URL, it doesn't contain project keys, IDs or query parameters, so
+    /// it keeps the cardinality of metric labels low. Requests implemented outside this crate
+    /// may leave it empty, in which case [`ApiRequest::endpoint_name`] falls back to the type
+    /// name of the request.
+    const ENDPOINT_NAME: &'static str = "";
+
     /// Build the request and send it to the API.
     ///
     /// # Returns
     ///
     /// A Future that resolves to an ApiResponse containing either the response data or an error.
     fn send(&self) -> impl Future<Output = ApiResponse<Self::Output>> + Send;
+
+    /// Build the request and send it to the API, keeping the response headers.
+    ///
+    /// `send` remains the common path; use this when headers such as `ETag` or `Location`
+    /// are needed. Requests that make several calls return empty headers.
+    ///
+    /// # Returns
+    ///
+    /// A Future that resolves to an ApiResponseWithHeaders containing either the response
+    /// data and headers or an error.
+    fn send_with_headers(
+        &self,
+    ) -> impl Future<Output = ApiResponseWithHeaders<Self::Output>> + Send;
+
+    /// The [`ApiRequest::ENDPOINT_NAME`] of this request, or its type name if it has none.
+    ///
+    /// # Returns
+    ///
+    /// The name of the endpoint, e.g. `pull_request_list`.
+    fn endpoint_name(&self) -> &'static str {
+        if Self::ENDPOINT_NAME.is_empty() {
+            std::any::type_name::<Self>()
+        } else {
+            Self::ENDPOINT_NAME
+        }
+    }
+
+    /// Build the request and send it to the API, measuring how long the call took.
+    ///
+    /// The duration is the wall-clock time of the whole `send`, including reading and
+    /// deserializing the response, and is measured for failed requests too. Use it to feed
+    /// latency metrics labelled with [`ApiRequest::endpoint_name`]; `send` itself stays free
+    /// of timing overhead.
+    ///
+    /// # Returns
+    ///
+    /// A Future that resolves to the response of `send` together with the elapsed time.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let request = client
+    ///         .api()
+    ///         .pull_request_get("PROJECT", "REPO", "1")
+    ///         .build()?;
+    ///     let (response, elapsed) = request.send_timed().await;
+    ///
+    ///     println!("{} took {}ms", request.endpoint_name(), elapsed.as_millis());
+    ///     let _pull_request = response?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn send_timed(&self) -> impl Future<Output = (ApiResponse<Self::Output>, Duration)> + Send
+    where
+        Self: Sync,
+    {
+        async {
+            let start = Instant::now();
+            let response = self.send().await;
+
+            (response, start.elapsed())
+        }
+    }
+}
+
+/// An API request sent as a single HTTP request, which can be built without sending it.
+///
+/// Requests composed of other requests, e.g. paginating or resolving a pull request first, don't
+/// implement it, as their later calls depend on the responses to the first ones.
+pub trait DescribeRequest: ApiRequest {
+    /// Build the HTTP request that `send` sends, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// The request, with the client's headers, or an error if it can't be built, e.g. because
+    /// the client has no base URL.
+    fn build_request(&self) -> Result<reqwest::Request, Error>;
+
+    /// Describe the request that `send` sends, without sending it.
+    ///
+    /// The method, URL with its query parameters, headers and body of the request are returned,
+    /// e.g. to unit-test that a builder produces the expected request or to reproduce it with
+    /// another tool. The query parameters are sorted by name, so the URL is the same on every
+    /// run. The API token is redacted.
+    ///
+    /// # Returns
+    ///
+    /// The description of the request, or an error if it can't be built.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::client::{new, DescribeRequest};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let request = client
+    ///         .api()
+    ///         .pull_request_get("PROJECT", "REPO", "1")
+    ///         .build()?
+    ///         .describe()?;
+    ///
+    ///     assert_eq!(request.method, "GET");
+    ///     assert_eq!(
+    ///         request.url,
+    ///         "https://bitbucket-server/rest/api/latest/projects/PROJECT/repos/REPO/pull-requests/1"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn describe(&self) -> Result<RequestDescription, Error> {
+        self.build_request()
+            .map(|request| RequestDescription::from_request(&request))
+    }
+}
+
+/// An API request with a JSON response, whose full parsed value can be kept.
+///
+/// Only requests that read JSON implement it, so wrapping a plain-text request, e.g.
+/// [`Api::pull_request_patch`], is a compile error rather than a failed request:
+///
+/// ```compile_fail
+/// use bitbucket_server_rs::client::{new, RawValueRequest};
+///
+/// let client = new("https://bitbucket-server/rest", "API_TOKEN");
+/// let request = client
+///     .api()
+///     .pull_request_patch("PROJECT", "REPO", "1")
+///     .with_raw_value();
+/// ```
+#[cfg(feature = "raw-value")]
+pub trait RawValueRequest: ApiRequest {
+    /// Build the request and send it to the API, keeping the full parsed JSON value of the
+    /// response alongside the typed output.
+    ///
+    /// # Returns
+    ///
+    /// A Future that resolves to the response data with its raw value and headers, or an error.
+    fn send_with_raw_value(
+        &self,
+    ) -> impl Future<Output = ApiResponseWithHeaders<RawValue<Self::Output>>> + Send;
+
+    /// Wrap the request so that `send` also returns the full parsed JSON value of the response.
+    ///
+    /// This is an escape hatch for reading fields that aren't modeled by the typed output.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest, RawValueRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let response = client
+    ///         .api()
+    ///         .build_status_get("PROJECT", "COMMIT", "REPO")
+    ///         .build()?
+    ///         .with_raw_value()
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     if let Some((build_status, value)) = response {
+    ///         println!("{:?} {}", build_status.state, value["someUnmodeledField"]);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn with_raw_value(self) -> WithRawValue<Self>
+    where
+        Self: Sized,
+    {
+        WithRawValue { request: self }
+    }
+}
+
+/// A built request whose client can be swapped while keeping all its other fields.
+///
+/// Every request type of the [`api`] module implements it, so the same request can be sent
+/// through a differently configured client, e.g. one with a longer timeout for a big diff,
+/// without rebuilding it.
+pub trait WithClient: Clone {
+    /// Clone the request, replacing its client.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to send the cloned request with
+    ///
+    /// # Returns
+    ///
+    /// A copy of the request that is sent through `client`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::client::{new, ApiRequest, WithClient};
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///
+    ///     let mut patient = client.clone();
+    ///     patient.with_http_client(
+    ///         reqwest::Client::builder()
+    ///             .timeout(Duration::from_secs(300))
+    ///             .build()?,
+    ///     );
+    ///
+    ///     let request = client
+    ///         .api()
+    ///         .pull_request_diff_get("PROJECT", "REPO", "1")
+    ///         .build()?;
+    ///
+    ///     // the same request, with a longer timeout
+    ///     let diff = request.with_client(patient).send().await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn with_client(&self, client: Client) -> Self;
+
+    /// Send the request through `client`, whatever client it was built with.
+    ///
+    /// This is how requests built from [`Api::template`] are sent: the request is defined once
+    /// and dispatched through different clients, e.g. to production and staging servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to send the request with
+    ///
+    /// # Returns
+    ///
+    /// A Future that resolves to the response data or an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bitbucket_server_rs::api::Api;
+    /// use bitbucket_server_rs::client::{new, WithClient};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let production = new("https://bitbucket-server/rest", "API_TOKEN");
+    ///     let staging = new("https://bitbucket-staging/rest", "STAGING_API_TOKEN");
+    ///
+    ///     let request = Api::template()
+    ///         .pull_request_get("PROJECT", "REPO", "1")
+    ///         .build()?;
+    ///
+    ///     let pull_request = request.send_with(&production).await?;
+    ///     let staged_pull_request = request.send_with(&staging).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn send_with(&self, client: &Client) -> impl Future<Output = ApiResponse<Self::Output>> + Send
+    where
+        Self: ApiRequest + Send + Sync,
+    {
+        let request = self.with_client(client.clone());
+
+        async move { request.send().await }
+    }
+}
+
+/// Implements [`WithClient`] for a request type with a `client` field.
+macro_rules! impl_with_client {
+    ($request:ty) => {
+        impl $crate::client::WithClient for $request {
+            fn with_client(&self, client: $crate::client::Client) -> Self {
+                let mut request = self.clone();
+                request.client = client;
+                request
+            }
+        }
+    };
+}
+
+pub(crate) use impl_with_client;
+
+/// A deserialized response together with the full parsed JSON value it was read from.
+#[cfg(feature = "raw-value")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RawValue<T> {
+    /// The typed response data
+    pub data: T,
+
+    /// The full parsed JSON value of the response
+    pub value: serde_json::Value,
+}
+
+#[cfg(feature = "raw-value")]
+impl<'de, T: DeserializeOwned> serde::Deserialize<'de> for RawValue<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let data = T::deserialize(&value).map_err(serde::de::Error::custom)?;
+
+        Ok(RawValue { data, value })
+    }
+}
+
+/// A request whose `send` returns the typed output together with the raw JSON value.
+///
+/// Created with [`RawValueRequest::with_raw_value`].
+#[cfg(feature = "raw-value")]
+#[derive(Clone, Debug)]
+pub struct WithRawValue<R> {
+    /// The wrapped request
+    request: R,
+}
+
+#[cfg(feature = "raw-value")]
+impl<R: RawValueRequest + Sync> ApiRequest for WithRawValue<R> {
+    type Output = (R::Output, serde_json::Value);
+    const ENDPOINT_NAME: &'static str = R::ENDPOINT_NAME;
+
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        self.send_with_headers().await.map(|(data, _)| data)
+    }
+
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        let (data, headers) = self.request.send_with_raw_value().await?;
+
+        Ok((data.map(|raw| (raw.data, raw.value)), headers))
+    }
+}
+
+#[cfg(feature = "raw-value")]
+impl<R: WithClient> WithClient for WithRawValue<R> {
+    fn with_client(&self, client: Client) -> Self {
+        WithRawValue {
+            request: self.request.with_client(client),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPECTED_URL: &str =
+        "https://host/rest/api/latest/projects/PROJECT/repos/REPO/commits/COMMIT/builds";
+    const URI: &str = "api/latest/projects/PROJECT/repos/REPO/commits/COMMIT/builds";
+
+    #[test]
+    fn it_accepts_base_path_ending_with_rest() {
+        let client = new("https://host/rest", "API_TOKEN");
+
+        assert_eq!(client.base_path, "https://host/rest");
+        assert_eq!(client.url(URI), EXPECTED_URL);
+    }
+
+    #[test]
+    fn it_accepts_base_path_ending_with_rest_and_slash() {
+        let client = new("https://host/rest/", "API_TOKEN");
+
+        assert_eq!(client.base_path, "https://host/rest");
+        assert_eq!(client.url(URI), EXPECTED_URL);
+    }
+
+    #[test]
+    fn it_accepts_base_path_without_rest() {
+        let client = new("https://host", "API_TOKEN");
+
+        assert_eq!(client.base_path, "https://host/rest");
+        assert_eq!(client.url(URI), EXPECTED_URL);
+    }
+
+    #[test]
+    fn it_uses_absolute_urls_as_is() {
+        let client = new("https://host/rest", "API_TOKEN");
+
+        assert_eq!(
+            client.url("https://host/plugins/servlet/lfs"),
+            "https://host/plugins/servlet/lfs"
+        );
+        assert_eq!(client.url("http://other-host/raw"), "http://other-host/raw");
+    }
+
+    #[test]
+    fn it_can_get_server_url() {
+        let client = new("https://host/context/rest/", "API_TOKEN");
+
+        assert_eq!(client.server_url(), "https://host/context");
+    }
+
+    #[test]
+    fn it_keeps_context_path() {
+        for base_path in [
+            "https://host/bitbucket/rest",
+            "https://host/bitbucket/rest/",
+            "https://host/bitbucket",
+            "https://host/bitbucket/",
+        ] {
+            let client = new(base_path, "API_TOKEN");
+
+            assert_eq!(client.base_path, "https://host/bitbucket/rest");
+            assert_eq!(
+                client.url(URI),
+                "https://host/bitbucket/rest/api/latest/projects/PROJECT/repos/REPO/commits/COMMIT/builds"
+            );
+            assert_eq!(client.server_url(), "https://host/bitbucket");
+            assert_eq!(
+                client.pull_request_web_url("PROJECT", "REPO", 1),
+                "https://host/bitbucket/projects/PROJECT/repos/REPO/pull-requests/1"
+            );
+        }
+    }
+
+    #[test]
+    fn it_encodes_path_segments() {
+        assert_eq!(
+            encode_path_segment("refs/heads/feature/foo bar"),
+            "refs%2Fheads%2Ffeature%2Ffoo%20bar"
+        );
+        assert_eq!(encode_path_segment("a?b#c%d"), "a%3Fb%23c%25d");
+        assert_eq!(encode_path_segment("j.doe_1-x~"), "j.doe_1-x~");
+    }
+
+    #[test]
+    fn it_encodes_paths_keeping_separators() {
+        assert_eq!(
+            encode_path("src/my module/file name.rs"),
+            "src/my%20module/file%20name.rs"
+        );
+        assert_eq!(encode_path("caf\u{e9}/?.md"), "caf%C3%A9/%3F.md");
+    }
+
+    #[test]
+    fn it_encodes_query_values() {
+        assert_eq!(
+            with_query("api/latest/builds", &[("key", "ci build+1&2=3")]),
+            "api/latest/builds?key=ci%20build%2B1%262%3D3"
+        );
+        assert_eq!(
+            with_query("api/latest/merge?version=1", &[("message", "a#b")]),
+            "api/latest/merge?version=1&message=a%23b"
+        );
+        assert_eq!(with_query("api/latest/builds", &[]), "api/latest/builds");
+    }
+
+    #[test]
+    fn it_redacts_api_token_in_debug_output() {
+        let client = new("https://host/rest", "SECRET_API_TOKEN");
+
+        let debug = format!("{client:?}");
+
+        assert!(!debug.contains("SECRET_API_TOKEN"));
+        assert!(debug.contains(r#"api_token: "***""#));
+        assert!(debug.contains("https://host/rest"));
+    }
+
+    #[test]
+    fn it_can_build_web_urls() {
+        let client = new("https://host/context/rest", "API_TOKEN");
+
+        assert_eq!(client.server_url(), "https://host/context");
+        assert_eq!(
+            client.pull_request_web_url("PRJ", "repo", 7),
+            "https://host/context/projects/PRJ/repos/repo/pull-requests/7"
+        );
+        assert_eq!(
+            client.commit_web_url("PRJ", "repo", "8d51122def5"),
+            "https://host/context/projects/PRJ/repos/repo/commits/8d51122def5"
+        );
+        assert_eq!(
+            client.branch_web_url("PRJ", "repo", "refs/heads/feature/a b"),
+            "https://host/context/projects/PRJ/repos/repo/browse?at=refs%2Fheads%2Ffeature%2Fa%20b"
+        );
+        assert_eq!(
+            client.compare_web_url("PRJ", "repo", "refs/heads/feature", "refs/heads/main"),
+            "https://host/context/projects/PRJ/repos/repo/compare/commits?sourceBranch=refs%2Fheads%2Ffeature&targetBranch=refs%2Fheads%2Fmain"
+        );
+        // the key of a personal project, and segments that must be encoded
+        assert_eq!(
+            client.pull_request_web_url("~USER", "my repo", 7),
+            "https://host/context/projects/~USER/repos/my%20repo/pull-requests/7"
+        );
+        assert_eq!(
+            client.commit_web_url("~USER", "repo", "8d51122/def5"),
+            "https://host/context/projects/~USER/repos/repo/commits/8d51122%2Fdef5"
+        );
+    }
 }
@@ -1,5 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::time::Duration;
 use thiserror::Error;
 
+/// An error reported by Bitbucket Server in the body of an error response.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerError {
+    /// The field or parameter the error is about, if any
+    pub context: Option<String>,
+
+    /// The message of the error
+    pub message: Option<String>,
+
+    /// The fully qualified class name of the server's exception, e.g.
+    /// `com.atlassian.bitbucket.pull.NoSuchPullRequestException`
+    pub exception_name: Option<String>,
+}
+
+impl ServerError {
+    /// The class name of the server's exception without its package, e.g.
+    /// `NoSuchPullRequestException`.
+    pub fn exception_simple_name(&self) -> Option<&str> {
+        self.exception_name
+            .as_deref()
+            .map(|name| name.rsplit('.').next().unwrap_or(name))
+    }
+}
+
+/// The body of an error response, as sent by Bitbucket Server.
+#[derive(Deserialize)]
+struct ErrorBody {
+    /// The errors of the response
+    #[serde(default)]
+    errors: Vec<ServerError>,
+}
+
 /// Error types that can occur when making API requests.
 ///
 /// This enum represents the different types of errors that can occur when making
@@ -10,13 +46,135 @@ pub enum Error {
     #[error("Error building the request: {0}")]
     RequestError(String),
 
-    /// Error getting the response.
+    /// Error getting the response, e.g. the connection dropped while reading its body.
     #[error("Error getting the response: {0}")]
     ResponseError(String),
 
-    /// Authentication error (HTTP 401 or 403).
-    #[error("Authentication error")]
-    Unauthorized,
+    /// The server rejected the request (HTTP 4xx other than 401 and 403), e.g. 404 for a
+    /// missing resource or 409 for a conflicting update.
+    #[error("HTTP Client error [{status}]: {body}")]
+    HttpClientError {
+        /// The HTTP status code of the response.
+        status: u16,
+
+        /// The body of the response, usually JSON with the server's `errors`; may be empty.
+        body: String,
+    },
+
+    /// The server failed to handle the request (HTTP 5xx).
+    #[error("HTTP Server error [{status}]: {body}")]
+    HttpServerError {
+        /// The HTTP status code of the response.
+        status: u16,
+
+        /// The body of the response; may be empty.
+        body: String,
+    },
+
+    /// The server answered with a status the client doesn't handle, e.g. 1xx.
+    #[error("Unexpected HTTP Response [{status}]: {body}")]
+    UnexpectedResponse {
+        /// The HTTP status code of the response.
+        status: u16,
+
+        /// The body of the response; may be empty.
+        body: String,
+    },
+
+    /// The body of a successful response doesn't match the expected type.
+    #[error("Error deserializing the response: {source}")]
+    DeserializationError {
+        /// The error returned by `serde_json`.
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// The token is missing, invalid or expired (HTTP 401).
+    #[error("Authentication error: the API token is missing, invalid or expired (HTTP {status})")]
+    Unauthenticated {
+        /// The HTTP status code of the response.
+        status: u16,
+
+        /// The body of the response, e.g. the server's message; may be empty.
+        body: String,
+    },
+
+    /// The server answered the request without authenticating a user, e.g. with an empty
+    /// token on a server that allows public access, so there is no user to report.
+    #[error("No authenticated user: the server answered the request anonymously")]
+    Anonymous,
+
+    /// The token is valid but lacks the permission for the request (HTTP 403), e.g.
+    /// REPO_WRITE to merge a pull request.
+    #[error("Permission denied: the API token lacks the permission for this request (HTTP {status})")]
+    Forbidden {
+        /// The HTTP status code of the response.
+        status: u16,
+
+        /// The body of the response, e.g. the server's message; may be empty.
+        body: String,
+    },
+
+    /// Authentication was denied until a CAPTCHA is solved (HTTP 401 or 403 with an
+    /// `X-Authentication-Denied-Reason` header).
+    ///
+    /// Bitbucket requires a CAPTCHA after repeated failed logins. Further requests fail until
+    /// a human logs in through the web UI, so automation should stop and alert someone.
+    #[error("Authentication denied until a CAPTCHA is solved in the web UI: {reason}")]
+    AuthenticationThrottled {
+        /// The value of the `X-Authentication-Denied-Reason` header.
+        reason: String,
+    },
+
+    /// The server redirected the request (HTTP 3xx) and the redirect was not followed.
+    ///
+    /// This usually means the base URL is misconfigured, e.g. `http` instead of `https`.
+    #[error("Redirected to '{location}'; check the base URL of the client")]
+    Redirect {
+        /// The `Location` the server redirected to, empty if the header was missing.
+        location: String,
+    },
+
+    /// The pull request changed since the given commit was reviewed (HTTP 409).
+    ///
+    /// The review status was not updated; review the latest commit and try again.
+    #[error("The pull request has changed since commit '{last_reviewed_commit}' was reviewed")]
+    StaleReview {
+        /// The commit the review was made against.
+        last_reviewed_commit: String,
+    },
+
+    /// The source branch of a pull request has no commits that its target branch doesn't, so
+    /// the pull request was not created.
+    #[error("No commits on '{from}' that are not on '{to}'")]
+    NoCommitsBetween {
+        /// The source ref of the pull request.
+        from: String,
+
+        /// The target ref of the pull request.
+        to: String,
+    },
+
+    /// A state-changing request was rejected by the server's XSRF protection (HTTP 403 with
+    /// `XSRF check failed`).
+    ///
+    /// The request was sent without `X-Atlassian-Token: no-check`; see
+    /// `Client::with_atlassian_token_no_check`. Proxies may also strip the header.
+    #[error("XSRF check failed: the request needs the 'X-Atlassian-Token: no-check' header")]
+    XsrfCheckFailed,
+
+    /// The server doesn't support the feature (HTTP 404 on the feature's endpoint), usually
+    /// because it runs an older Bitbucket Server version.
+    #[error("Not supported by this Bitbucket Server: {0}")]
+    Unsupported(String),
+
+    /// The request was not sent because the client's circuit breaker is open after repeated
+    /// failures; see `Client::with_circuit_breaker`.
+    #[error("Circuit breaker open after repeated failures; retry in {retry_after:?}")]
+    CircuitOpen {
+        /// The time left until the circuit lets a trial request through.
+        retry_after: Duration,
+    },
 
     /// Unexpected error with a custom message.
     #[error("Unexpected error: {0}" )]
@@ -24,22 +182,73 @@ pub enum Error {
 }
 
 impl Error {
-    /// Check if the error is an authentication error (HTTP 401 or 403).
+    /// Wraps the error of a request builder, e.g. a required field that was not set.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error returned by the `build` method of the builder
+    pub(crate) fn from_builder(error: impl Display) -> Self {
+        Error::RequestError(error.to_string())
+    }
+
+    /// Check if the error is an authentication or permission error (HTTP 401 or 403).
     ///
     /// # Returns
     ///
-    /// `true` if the error is an authentication error, `false` otherwise.
+    /// `true` if the error is `Unauthenticated`, `Anonymous` or `Forbidden`, `false` otherwise.
     ///
     /// # Example
     ///
     /// ```
     /// use bitbucket_server_rs::Error;
     ///
-    /// let error = Error::Unauthorized;
-    /// assert!(error.is_unauthorized());
+    /// let body = String::new();
+    /// assert!(Error::Unauthenticated { status: 401, body: body.clone() }.is_unauthorized());
+    /// assert!(Error::Forbidden { status: 403, body }.is_unauthorized());
     /// ```
     pub fn is_unauthorized(&self) -> bool {
-        matches!(self, Error::Unauthorized)
+        matches!(
+            self,
+            Error::Unauthenticated { .. } | Error::Anonymous | Error::Forbidden { .. }
+        )
+    }
+
+    /// Check if the error is an authentication error (HTTP 401).
+    ///
+    /// # Returns
+    ///
+    /// `true` if the token is missing, invalid or expired, or the server answered
+    /// anonymously, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::Error;
+    ///
+    /// let error = Error::Unauthenticated { status: 401, body: String::new() };
+    /// assert!(error.is_unauthenticated());
+    /// assert!(Error::Anonymous.is_unauthenticated());
+    /// ```
+    pub fn is_unauthenticated(&self) -> bool {
+        matches!(self, Error::Unauthenticated { .. } | Error::Anonymous)
+    }
+
+    /// Check if the error is a permission error (HTTP 403).
+    ///
+    /// # Returns
+    ///
+    /// `true` if the token lacks the permission for the request, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::Error;
+    ///
+    /// let error = Error::Forbidden { status: 403, body: String::new() };
+    /// assert!(error.is_forbidden());
+    /// ```
+    pub fn is_forbidden(&self) -> bool {
+        matches!(self, Error::Forbidden { .. })
     }
 
     /// Check if the error is a request error.
@@ -64,18 +273,308 @@ impl Error {
     ///
     /// # Returns
     ///
-    /// `true` if the error is a response error, `false` otherwise.
+    /// `true` if the response could not be read, had an error status other than 401 and 403,
+    /// or could not be deserialized, `false` otherwise.
     ///
     /// # Example
     ///
     /// ```
     /// use bitbucket_server_rs::Error;
     ///
-    /// let error = Error::ResponseError("Failed to parse response".to_string());
+    /// let error = Error::ResponseError("Failed to read response".to_string());
+    /// assert!(error.is_response_error());
+    ///
+    /// let error = Error::HttpServerError { status: 503, body: String::new() };
     /// assert!(error.is_response_error());
     /// ```
     pub fn is_response_error(&self) -> bool {
-        matches!(self, Error::ResponseError(_))
+        matches!(
+            self,
+            Error::ResponseError(_)
+                | Error::HttpClientError { .. }
+                | Error::HttpServerError { .. }
+                | Error::UnexpectedResponse { .. }
+                | Error::DeserializationError { .. }
+        )
+    }
+
+    /// Check if the error is an HTTP client error (HTTP 4xx other than 401 and 403).
+    ///
+    /// # Returns
+    ///
+    /// `true` if the error is an HTTP client error, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::Error;
+    ///
+    /// let error = Error::HttpClientError { status: 404, body: String::new() };
+    /// assert!(error.is_http_client_error());
+    /// ```
+    pub fn is_http_client_error(&self) -> bool {
+        matches!(self, Error::HttpClientError { .. })
+    }
+
+    /// Check if the error is an HTTP server error (HTTP 5xx).
+    ///
+    /// # Returns
+    ///
+    /// `true` if the error is an HTTP server error, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::Error;
+    ///
+    /// let error = Error::HttpServerError { status: 500, body: String::new() };
+    /// assert!(error.is_http_server_error());
+    /// ```
+    pub fn is_http_server_error(&self) -> bool {
+        matches!(self, Error::HttpServerError { .. })
+    }
+
+    /// Check if the error is an unexpected response status.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the server answered with a status the client doesn't handle, `false`
+    /// otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::Error;
+    ///
+    /// let error = Error::UnexpectedResponse { status: 101, body: String::new() };
+    /// assert!(error.is_unexpected_response());
+    /// ```
+    pub fn is_unexpected_response(&self) -> bool {
+        matches!(self, Error::UnexpectedResponse { .. })
+    }
+
+    /// Check if the error is a deserialization error.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the body of a successful response didn't match the expected type, `false`
+    /// otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::Error;
+    ///
+    /// let source = serde_json::from_str::<u64>("\"not a number\"").unwrap_err();
+    /// let error = Error::DeserializationError { source };
+    /// assert!(error.is_deserialization_error());
+    /// ```
+    pub fn is_deserialization_error(&self) -> bool {
+        matches!(self, Error::DeserializationError { .. })
+    }
+
+    /// The HTTP status of the response the error was made from, if any.
+    ///
+    /// # Returns
+    ///
+    /// The status of `Unauthenticated`, `Forbidden`, `HttpClientError`, `HttpServerError` and
+    /// `UnexpectedResponse` errors, `None` for the other errors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::Error;
+    ///
+    /// let error = Error::HttpClientError { status: 409, body: String::new() };
+    /// assert_eq!(error.status(), Some(409));
+    /// assert_eq!(Error::XsrfCheckFailed.status(), None);
+    /// ```
+    pub fn status(&self) -> Option<u16> {
+        self.status_and_body().map(|(status, _)| status)
+    }
+
+    /// The body of the response the error was made from, if any.
+    ///
+    /// # Returns
+    ///
+    /// The body of `Unauthenticated`, `Forbidden`, `HttpClientError`, `HttpServerError` and
+    /// `UnexpectedResponse` errors, `None` for the other errors.
+    pub fn body(&self) -> Option<&str> {
+        self.status_and_body().map(|(_, body)| body)
+    }
+
+    /// The errors reported by the server in the body of the response the error was made from.
+    ///
+    /// # Returns
+    ///
+    /// The `errors` of the body, or an empty list if the error has no body or its body isn't
+    /// Bitbucket's error JSON, e.g. the HTML page of a proxy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::Error;
+    ///
+    /// let error = Error::HttpClientError {
+    ///     status: 404,
+    ///     body: r#"{"errors":[{"context":null,"message":"Repository my-repo does not exist.","exceptionName":"com.atlassian.bitbucket.repository.NoSuchRepositoryException"}]}"#.to_string(),
+    /// };
+    ///
+    /// let errors = error.server_errors();
+    /// assert_eq!(errors[0].exception_simple_name(), Some("NoSuchRepositoryException"));
+    /// ```
+    pub fn server_errors(&self) -> Vec<ServerError> {
+        self.body()
+            .and_then(|body| serde_json::from_str::<ErrorBody>(body).ok())
+            .map(|body| body.errors)
+            .unwrap_or_default()
+    }
+
+    /// The status and body of the response the error was made from, if any.
+    fn status_and_body(&self) -> Option<(u16, &str)> {
+        match self {
+            Error::Unauthenticated { status, body }
+            | Error::Forbidden { status, body }
+            | Error::HttpClientError { status, body }
+            | Error::HttpServerError { status, body }
+            | Error::UnexpectedResponse { status, body } => Some((*status, body)),
+            _ => None,
+        }
+    }
+
+    /// Check if the error is an authentication throttled error.
+    ///
+    /// # Returns
+    ///
+    /// `true` if authentication is denied until a CAPTCHA is solved, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::Error;
+    ///
+    /// let error = Error::AuthenticationThrottled {
+    ///     reason: "CAPTCHA_CHALLENGE; login-url=https://bitbucket-server/login".to_string(),
+    /// };
+    /// assert!(error.is_authentication_throttled());
+    /// assert!(!error.is_unauthorized());
+    /// ```
+    pub fn is_authentication_throttled(&self) -> bool {
+        matches!(self, Error::AuthenticationThrottled { .. })
+    }
+
+    /// Check if the error is a redirect error.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the error is a redirect error, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::Error;
+    ///
+    /// let error = Error::Redirect { location: "https://bitbucket-server/rest".to_string() };
+    /// assert!(error.is_redirect());
+    /// ```
+    pub fn is_redirect(&self) -> bool {
+        matches!(self, Error::Redirect { .. })
+    }
+
+    /// Check if the error is a stale review error.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the error is a stale review error, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::Error;
+    ///
+    /// let error = Error::StaleReview { last_reviewed_commit: "abc123".to_string() };
+    /// assert!(error.is_stale_review());
+    /// ```
+    pub fn is_stale_review(&self) -> bool {
+        matches!(self, Error::StaleReview { .. })
+    }
+
+    /// Check if the error is a no commits between error.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a pull request was not created because its source branch has no commits
+    /// ahead of its target branch, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::Error;
+    ///
+    /// let error = Error::NoCommitsBetween {
+    ///     from: "refs/heads/feature".to_string(),
+    ///     to: "refs/heads/main".to_string(),
+    /// };
+    /// assert!(error.is_no_commits_between());
+    /// ```
+    pub fn is_no_commits_between(&self) -> bool {
+        matches!(self, Error::NoCommitsBetween { .. })
+    }
+
+    /// Check if the error is an XSRF check failure.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the server's XSRF protection rejected the request, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::Error;
+    ///
+    /// let error = Error::XsrfCheckFailed;
+    /// assert!(error.is_xsrf_check_failed());
+    /// ```
+    pub fn is_xsrf_check_failed(&self) -> bool {
+        matches!(self, Error::XsrfCheckFailed)
+    }
+
+    /// Check if the error is an unsupported feature error.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the error is an unsupported feature error, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::Error;
+    ///
+    /// let error = Error::Unsupported("pull request labels".to_string());
+    /// assert!(error.is_unsupported());
+    /// ```
+    pub fn is_unsupported(&self) -> bool {
+        matches!(self, Error::Unsupported(_))
+    }
+
+    /// Check if the error is a circuit open error.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the request was short-circuited by the client's circuit breaker, `false`
+    /// otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitbucket_server_rs::Error;
+    /// use std::time::Duration;
+    ///
+    /// let error = Error::CircuitOpen { retry_after: Duration::from_secs(30) };
+    /// assert!(error.is_circuit_open());
+    /// ```
+    pub fn is_circuit_open(&self) -> bool {
+        matches!(self, Error::CircuitOpen { .. })
     }
 
     /// Check if the error is an unexpected error.
@@ -13,12 +13,34 @@
 //! - **Builder Pattern**: Fluent API design for constructing requests
 //! - **JSON Serialization/Deserialization**: Automatic handling of JSON payloads
 //! - **Authentication**: Bearer token authentication support
+//! - **Timestamps** (default feature `chrono`): Dates as `chrono::DateTime<Utc>`, or `i64` epoch seconds without it
+//! - **Raw Values** (feature `raw-value`): Read unmodeled response fields through `RawValueRequest::with_raw_value`
+//! - **Compression** (feature `gzip`): Accept gzip-compressed responses, error responses included
+//! - **CI Environments** (feature `ci`): Fill build statuses from GitHub Actions, GitLab CI or Jenkins variables
 //!
 //! ## Currently Supported APIs
 //!
-//! - **Build Status**: Get and post build statuses for commits
-//! - **Pull Request Changes**: Retrieve changes in pull requests
-//! - **Pull Request Creation**: Create new pull requests
+//! - **Attachments**: Download repository attachments, such as images embedded in comments
+//! - **Avatars**: Download the avatar images of users and projects, e.g. to cache them locally
+//! - **Branches**: List and delete branches, compare their commits, check whether one is merged into another, prune merged bot branches with a dry run, or get the branching model
+//! - **Build Status**: Get, list and post build statuses for commits, or for the latest commit of a pull request or all of its commits, optionally checking state transitions
+//! - **Commit Changes**: Retrieve changes made in a commit
+//! - **Commits**: Get a commit, list the commits between two refs, walk its ancestors, or find the commits that last modified a directory's files
+//! - **Default Reviewers**: Get the reviewers suggested for a pull request, or a pull request payload with them pre-populated
+//! - **Jira Integration**: Get the Jira issues linked to a pull request
+//! - **Pull Request Changes**: Retrieve changes in pull requests, one page at a time or as a stream of all pages
+//! - **Pull Request Comments**: Comment on pull requests, reply to existing comments, post a review of several inline comments at once, and list comments or their full reply threads
+//! - **Pull Request Creation**: Create new pull requests, optionally reusing an already open one, or only if the source branch has commits ahead of the target
+//! - **Pull Request Labels**: Add, list and remove the labels of a pull request, on servers that support them
+//! - **Pull Request Listing**: Get a pull request, with avatars and rendered markup, or many of them concurrently, list the pull requests of a repository, list the pull requests on the authenticated user's dashboard by role, or check whether one was rescoped since a commit
+//! - **Pull Request Diff**: Retrieve the structured diff of a pull request, with truncation detection, its diff stat, its merge base, or the commits it introduces
+//! - **Pull Request Merge**: Check whether pull requests can be merged, and merge them right away, once their checks pass, or only if their builds are green, and read their changes and build statuses together for merge gates
+//! - **Pull Request Review**: Approve pull requests or mark them as needing work
+//! - **Pull Request Patch**: Retrieve the raw patch of a pull request
+//! - **Pull Request Settings**: Read and update a repository's merge checks and allowed merge strategies
+//! - **Webhooks**: Create, list and test repository webhooks, and verify the signature of deliveries
+//! - **Search**: Search code, repositories and commits across the instance
+//! - **Users**: Get the user the client is authenticated as, and the highest permission they hold on a repository
 //!
 //! ## Usage
 //!
@@ -136,8 +158,8 @@
 //!             "REPOSITORY_SLUG",
 //!             "123" // Pull request ID
 //!         )
-//!         .start(0u32)
-//!         .limit(100u32)
+//!         .start(0u64)
+//!         .limit(100u64)
 //!         .build()?
 //!         .send()
 //!         .await?;
@@ -167,8 +189,11 @@
 //!         Ok(response) => {
 //!             // Handle successful response
 //!         },
-//!         Err(Error::Unauthorized) => {
-//!             eprintln!("Authentication failed. Check your API token.");
+//!         Err(Error::Unauthenticated { body, .. }) => {
+//!             eprintln!("Authentication failed. Check your API token: {body}");
+//!         },
+//!         Err(Error::Forbidden { body, .. }) => {
+//!             eprintln!("Permission denied. Ask an admin for access: {body}");
 //!         },
 //!         Err(e) => {
 //!             eprintln!("Request failed: {:?}", e);
@@ -207,9 +232,10 @@ pub mod api;
 /// REST API Client module providing the core client functionality
 pub mod client;
 pub mod error;
+pub mod timestamp;
 
 // Re-export key items from client module
-pub use client::{new, Client, ApiRequest, ApiResponse};
+pub use client::{new, Client, ApiRequest, ApiResponse, ApiResponseWithHeaders};
 pub use error::Error;
 
 /// Prelude module that re-exports core functionality
@@ -220,6 +246,6 @@ pub use error::Error;
 /// Note: Specific API types are not included in the prelude to avoid
 /// potential name collisions. Import those directly from their respective modules.
 pub mod prelude {
-    pub use crate::client::{new, Client, ApiRequest, ApiResponse};
+    pub use crate::client::{new, Client, ApiRequest, ApiResponse, ApiResponseWithHeaders};
     pub use crate::error::Error;
 }
@@ -0,0 +1,114 @@
+//! # Timestamps
+//!
+//! This module contains the [`Timestamp`] type used by the dates of the API types, such as
+//! the creation date of a pull request, and the serde helpers that convert them from and to
+//! the epoch timestamps of the API.
+//!
+//! With the default `chrono` feature, a timestamp is a `chrono::DateTime<Utc>`. Without it,
+//! a timestamp is an `i64` of seconds since the epoch, so the client builds without chrono.
+//! The API sends dates in milliseconds; they are truncated to seconds without chrono.
+
+/// A point in time, as a `chrono::DateTime<Utc>`.
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+
+/// A point in time, in seconds since the epoch.
+#[cfg(not(feature = "chrono"))]
+pub type Timestamp = i64;
+
+/// Create a timestamp from seconds since the epoch.
+///
+/// # Arguments
+///
+/// * `seconds` - The number of seconds since the epoch
+///
+/// # Returns
+///
+/// The timestamp, or `None` if it is out of the range of `chrono::DateTime`.
+///
+/// # Example
+///
+/// ```
+/// use bitbucket_server_rs::timestamp::{epoch_seconds, from_epoch_seconds};
+///
+/// let timestamp = from_epoch_seconds(1738198923).unwrap();
+/// assert_eq!(epoch_seconds(&timestamp), 1738198923);
+/// ```
+pub fn from_epoch_seconds(seconds: i64) -> Option<Timestamp> {
+    #[cfg(feature = "chrono")]
+    return chrono::DateTime::from_timestamp(seconds, 0);
+
+    #[cfg(not(feature = "chrono"))]
+    return Some(seconds);
+}
+
+/// The number of seconds since the epoch of a timestamp.
+///
+/// # Arguments
+///
+/// * `timestamp` - The timestamp
+///
+/// # Returns
+///
+/// The number of whole seconds since the epoch.
+pub fn epoch_seconds(timestamp: &Timestamp) -> i64 {
+    #[cfg(feature = "chrono")]
+    return timestamp.timestamp();
+
+    #[cfg(not(feature = "chrono"))]
+    return *timestamp;
+}
+
+#[cfg(feature = "chrono")]
+pub(crate) use chrono::serde::ts_milliseconds_option;
+
+/// (De)serializes an optional timestamp as milliseconds since the epoch.
+#[cfg(not(feature = "chrono"))]
+pub(crate) mod ts_milliseconds_option {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        timestamp: &Option<i64>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        timestamp
+            .map(|seconds| seconds.saturating_mul(1000))
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<i64>, D::Error> {
+        Ok(Option::<i64>::deserialize(deserializer)?.map(|millis| millis.div_euclid(1000)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct Dates {
+        #[serde(default, with = "ts_milliseconds_option")]
+        millis: Option<Timestamp>,
+    }
+
+    #[test]
+    fn it_can_round_trip_epoch_timestamps() {
+        let dates: Dates = serde_json::from_str(r#"{"millis": 1738198923000}"#).unwrap();
+
+        assert_eq!(epoch_seconds(&dates.millis.unwrap()), 1738198923);
+        assert_eq!(
+            serde_json::to_value(&dates).unwrap(),
+            serde_json::json!({"millis": 1738198923000i64})
+        );
+    } // end of it_can_round_trip_epoch_timestamps
+
+    #[test]
+    fn it_can_deserialize_null_timestamps() {
+        let dates: Dates = serde_json::from_str(r#"{"millis": null}"#).unwrap();
+
+        assert_eq!(dates.millis, None);
+    } // end of it_can_deserialize_null_timestamps
+}
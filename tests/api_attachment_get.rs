@@ -0,0 +1,83 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+
+// a PNG signature followed by bytes that aren't valid UTF-8
+const TEST_ATTACHMENT: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0xff, 0xfe, 0x80, 0x0d, 0x0a,
+];
+
+#[tokio::test]
+async fn it_can_get_attachment_bytes() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/attachments/42");
+        then.status(200)
+            .header("Content-Type", "image/png")
+            .body(TEST_ATTACHMENT);
+    });
+
+    let (bytes, headers) = client
+        .api()
+        .attachment_get("PROJECT_KEY", "REPOSITORY_SLUG", "42")
+        .send_with_headers()
+        .await
+        .unwrap();
+
+    // the bytes must be returned unmodified
+    assert_eq!(bytes.unwrap(), TEST_ATTACHMENT);
+    assert_eq!(headers["Content-Type"], "image/png");
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_returns_none_for_missing_attachment() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/attachments/404");
+        then.status(404).body(
+            r#"{"errors":[{"context":null,"message":"Attachment 404 does not exist.","exceptionName":"com.atlassian.bitbucket.attachment.NoSuchAttachmentException"}]}"#,
+        );
+    });
+
+    let response = client
+        .api()
+        .attachment_get("PROJECT_KEY", "REPOSITORY_SLUG", "404")
+        .send()
+        .await;
+
+    assert_eq!(response.unwrap(), None);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_reports_missing_repository() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/MISSING_SLUG/attachments/1");
+        then.status(404).body(
+            r#"{"errors":[{"context":null,"message":"Repository PROJECT_KEY/MISSING_SLUG does not exist.","exceptionName":"com.atlassian.bitbucket.repository.NoSuchRepositoryException"}]}"#,
+        );
+    });
+
+    let error = client
+        .api()
+        .attachment_get("PROJECT_KEY", "MISSING_SLUG", "1")
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(error.is_http_client_error());
+    assert_eq!(error.status(), Some(404));
+    mock.assert();
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+
+// a PNG signature followed by bytes that aren't valid UTF-8
+const TEST_AVATAR: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0xff, 0xfe, 0x80,
+];
+
+#[tokio::test]
+async fn it_can_get_user_avatar() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/users/jdoe/avatar.png")
+            .query_param("s", "64");
+        then.status(200)
+            .header("Content-Type", "image/png")
+            .body(TEST_AVATAR);
+    });
+
+    let response = client.api().user_avatar("jdoe", 64).send().await;
+
+    assert_eq!(response.unwrap().unwrap(), TEST_AVATAR);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_can_get_project_avatar() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/avatar.png")
+            .query_param("s", "48");
+        then.status(200)
+            .header("Content-Type", "image/png")
+            .body(TEST_AVATAR);
+    });
+
+    let response = client.api().project_avatar("PROJECT_KEY", 48).send().await;
+
+    assert_eq!(response.unwrap().unwrap(), TEST_AVATAR);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_follows_redirect_to_default_avatar() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let avatar_mock = server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/users/jdoe/avatar.png");
+        then.status(302)
+            .header("Location", server.url("/default-avatar.png"));
+    });
+    let default_mock = server.mock(|when, then| {
+        when.method(GET).path("/default-avatar.png");
+        then.status(200)
+            .header("Content-Type", "image/png")
+            .body(TEST_AVATAR);
+    });
+
+    let response = client.api().user_avatar("jdoe", 64).send().await;
+
+    assert_eq!(response.unwrap().unwrap(), TEST_AVATAR);
+    avatar_mock.assert();
+    default_mock.assert();
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use serde_json::json;
+
+const COMPARE_PATH: &str =
+    "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/compare/commits";
+
+#[tokio::test]
+async fn it_reports_merged_branch() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(COMPARE_PATH)
+            .query_param("from", "feature/merged")
+            .query_param("to", "main")
+            .query_param("limit", "1");
+        then.status(200).json_body(json!({
+            "size": 0, "limit": 1, "isLastPage": true, "start": 0, "values": []
+        }));
+    });
+
+    let merged = client
+        .api()
+        .is_branch_merged("PROJECT_KEY", "REPOSITORY_SLUG", "feature/merged", "main")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(merged, Some(true));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_reports_unmerged_branch() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(COMPARE_PATH)
+            .query_param("from", "feature/open")
+            .query_param("to", "main");
+        then.status(200).json_body(json!({
+            "size": 1,
+            "limit": 1,
+            "isLastPage": false,
+            "start": 0,
+            "nextPageStart": 1,
+            "values": [{"id": "def0123abcdef4567abcdef8987abcdef6543abc", "displayId": "def0123abcd"}]
+        }));
+    });
+
+    let merged = client
+        .api()
+        .is_branch_merged("PROJECT_KEY", "REPOSITORY_SLUG", "feature/open", "main")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(merged, Some(false));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_fails_when_branch_does_not_exist() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(COMPARE_PATH)
+            .query_param("from", "feature/missing");
+        then.status(404).json_body(json!({
+            "errors": [{
+                "message": "Commit 'feature/missing' does not exist in repository 'REPOSITORY_SLUG'.",
+                "exceptionName": "com.atlassian.bitbucket.commit.NoSuchCommitException"
+            }]
+        }));
+    });
+
+    let error = client
+        .api()
+        .is_branch_merged("PROJECT_KEY", "REPOSITORY_SLUG", "feature/missing", "main")
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        bitbucket_server_rs::Error::HttpClientError { status: 404, .. }
+    ));
+    mock.assert();
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use bitbucket_server_rs::api::branch_model_get::BranchTypeId;
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use serde_json::json;
+
+#[tokio::test]
+async fn it_can_get_branch_model() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(
+            "/rest/branch-utils/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/branchmodel",
+        );
+        then.status(200).json_body(json!({
+            "development": {"id": "refs/heads/develop", "displayId": "develop"},
+            "production": {"id": "refs/heads/master", "displayId": "master"},
+            "types": [
+                {"id": "FEATURE", "displayName": "Feature", "prefix": "feature/"},
+                {"id": "RELEASE", "displayName": "Release", "prefix": "release/"}
+            ]
+        }));
+    });
+
+    let branch_model = client
+        .api()
+        .branch_model_get("PROJECT_KEY", "REPOSITORY_SLUG")
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        branch_model.branches.development.as_ref().unwrap().id,
+        "refs/heads/develop"
+    );
+    assert_eq!(
+        branch_model.prefix(&BranchTypeId::Release),
+        Some("release/")
+    );
+    mock.assert();
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::{DELETE, GET};
+use httpmock::MockServer;
+use serde_json::json;
+
+const REPO_PATH: &str = "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG";
+const DELETE_PATH: &str =
+    "/rest/branch-utils/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/branches";
+
+/// Mocks a repository with a default branch, a merged and an unmerged bot branch, and a
+/// merged branch that doesn't match the pattern.
+fn mock_repository(server: &MockServer) {
+    server.mock(|when, then| {
+        when.method(GET).path(format!("{REPO_PATH}/branches"));
+        then.status(200).json_body(json!({
+            "size": 4,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": [
+                {"id": "refs/heads/main", "displayId": "main", "latestCommit": "aaa", "isDefault": true},
+                {"id": "refs/heads/renovate/merged", "displayId": "renovate/merged", "latestCommit": "bbb", "isDefault": false},
+                {"id": "refs/heads/renovate/open", "displayId": "renovate/open", "latestCommit": "ccc", "isDefault": false},
+                {"id": "refs/heads/feature", "displayId": "feature", "latestCommit": "ddd", "isDefault": false}
+            ]
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("{REPO_PATH}/compare/commits"))
+            .query_param("from", "refs/heads/renovate/merged")
+            .query_param("to", "refs/heads/main");
+        then.status(200).json_body(json!({
+            "size": 0, "limit": 1, "isLastPage": true, "start": 0, "values": []
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("{REPO_PATH}/compare/commits"))
+            .query_param("from", "refs/heads/renovate/open")
+            .query_param("to", "refs/heads/main");
+        then.status(200).json_body(json!({
+            "size": 1, "limit": 1, "isLastPage": false, "start": 0, "nextPageStart": 1,
+            "values": [{"id": "ccc", "displayId": "ccc"}]
+        }));
+    });
+}
+
+#[tokio::test]
+async fn it_reports_merged_branches_in_dry_run() {
+    common::setup();
+    let (server, client) = common::mock_client();
+    mock_repository(&server);
+
+    let delete_mock = server.mock(|when, then| {
+        when.method(DELETE).path(DELETE_PATH);
+        then.status(204);
+    });
+
+    let branches = client
+        .api()
+        .prune_merged_branches("PROJECT_KEY", "REPOSITORY_SLUG", "renovate/*")
+        .dry_run(true)
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let names: Vec<&str> = branches.iter().map(|b| b.display_id.as_str()).collect();
+    assert_eq!(names, vec!["renovate/merged"]);
+    delete_mock.assert_hits(0);
+}
+
+#[tokio::test]
+async fn it_deletes_merged_branches() {
+    common::setup();
+    let (server, client) = common::mock_client();
+    mock_repository(&server);
+
+    let delete_mock = server.mock(|when, then| {
+        when.method(DELETE)
+            .path(DELETE_PATH)
+            .json_body(json!({"name": "refs/heads/renovate/merged", "endPoint": "bbb"}));
+        then.status(204);
+    });
+
+    let branches = client
+        .api()
+        .prune_merged_branches("PROJECT_KEY", "REPOSITORY_SLUG", "renovate/*")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(branches.len(), 1);
+    assert_eq!(branches[0].id, "refs/heads/renovate/merged");
+    delete_mock.assert();
+}
+
+#[tokio::test]
+async fn it_never_deletes_the_default_branch() {
+    common::setup();
+    let (server, client) = common::mock_client();
+    mock_repository(&server);
+
+    let branches = client
+        .api()
+        .prune_merged_branches("PROJECT_KEY", "REPOSITORY_SLUG", "main")
+        .dry_run(true)
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(branches.is_empty());
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use bitbucket_server_rs::api::build_status::BuildStatusState;
+use bitbucket_server_rs::api::build_status_post::BuildStatusPostPayload;
+use httpmock::Method::POST;
+
+const BUILDS_PATH: &str =
+    "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits/COMMIT_ID/builds";
+
+fn build_status(state: BuildStatusState) -> BuildStatusPostPayload {
+    BuildStatusPostPayload {
+        key: "build-123".to_string(),
+        state,
+        url: "https://ci.example.com/build/123".to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn it_rejects_invalid_transitions() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST).path(BUILDS_PATH);
+        then.status(204);
+    });
+
+    let reporter = client
+        .api()
+        .build_reporter("PROJECT_KEY", "REPOSITORY_SLUG", "COMMIT_ID")
+        .valid_states(true)
+        .build()
+        .unwrap();
+
+    reporter
+        .post(&build_status(BuildStatusState::InProgress))
+        .await
+        .unwrap();
+    reporter
+        .post(&build_status(BuildStatusState::Successful))
+        .await
+        .unwrap();
+    let result = reporter
+        .post(&build_status(BuildStatusState::InProgress))
+        .await;
+
+    assert!(result.unwrap_err().is_request_error());
+    assert_eq!(
+        reporter.last_state("build-123"),
+        Some(BuildStatusState::Successful)
+    );
+    mock.assert_hits(2);
+}
+
+#[tokio::test]
+async fn it_allows_any_transition_by_default() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST).path(BUILDS_PATH);
+        then.status(204);
+    });
+
+    let reporter = client
+        .api()
+        .build_reporter("PROJECT_KEY", "REPOSITORY_SLUG", "COMMIT_ID")
+        .build()
+        .unwrap();
+
+    reporter
+        .post(&build_status(BuildStatusState::Successful))
+        .await
+        .unwrap();
+    reporter
+        .post(&build_status(BuildStatusState::InProgress))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        reporter.last_state("build-123"),
+        Some(BuildStatusState::InProgress)
+    );
+    mock.assert_hits(2);
+}
+
+#[tokio::test]
+async fn it_aggregates_child_builds_into_parent_state() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let child_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(BUILDS_PATH)
+            .json_body_partial(r#"{"parent": "pipeline-9"}"#);
+        then.status(204);
+    });
+
+    let reporter = client
+        .api()
+        .build_reporter("PROJECT_KEY", "REPOSITORY_SLUG", "COMMIT_ID")
+        .build()
+        .unwrap();
+
+    let child = |key: &str, state: BuildStatusState| BuildStatusPostPayload {
+        key: key.to_string(),
+        state,
+        url: format!("https://ci.example.com/build/{key}"),
+        ..Default::default()
+    };
+
+    reporter
+        .post_child("pipeline-9", &child("linux", BuildStatusState::Successful))
+        .await
+        .unwrap();
+    reporter
+        .post_child("pipeline-9", &child("macos", BuildStatusState::InProgress))
+        .await
+        .unwrap();
+    assert_eq!(
+        reporter.parent_state("pipeline-9"),
+        BuildStatusState::InProgress
+    );
+
+    reporter
+        .post_child("pipeline-9", &child("macos", BuildStatusState::Failed))
+        .await
+        .unwrap();
+    assert_eq!(reporter.parent_state("pipeline-9"), BuildStatusState::Failed);
+    assert_eq!(reporter.parent_state("other"), BuildStatusState::Unknown);
+    child_mock.assert_hits(3);
+}
@@ -53,6 +53,38 @@ async fn it_can_get_build_status_with_key() {
     mock.assert();
 }
 
+#[tokio::test]
+async fn it_encodes_key_with_special_characters() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    // the server must receive the key exactly, not split at `&` or with `+` read as a space
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits/COMMIT_ID/builds")
+            .query_param("key", "ci build+1&2")
+            .matches(|request| {
+                request
+                    .query_params
+                    .as_ref()
+                    .is_some_and(|params| params.len() == 1)
+            });
+        then.body(TEST_RESPONSE).status(200);
+    });
+
+    let response = client
+        .api()
+        .build_status_get("PROJECT_KEY", "COMMIT_ID", "REPOSITORY_SLUG")
+        .key("ci build+1&2")
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    assert!(response.is_ok());
+    mock.assert();
+}
+
 #[tokio::test]
 async fn it_can_get_build_status_object() {
     common::setup();
@@ -97,8 +129,8 @@ static TEST_RESPONSE: &str = r#"{
     "successful": 134,
     "skipped": 5
   },
-  "createdDate": 1738198923,
-  "updatedDate": 1738198924,
+  "createdDate": 1738198923000,
+  "updatedDate": 1738198924000,
   "url": "https://my-bitbucket-server.com/browse/TEST-REP3",
   "duration": 2154,
   "buildNumber": "3",
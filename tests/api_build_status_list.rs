@@ -0,0 +1,88 @@
+mod common;
+
+use bitbucket_server_rs::api::build_status::BuildStatusState;
+use bitbucket_server_rs::api::build_status_get::BuildStatus;
+use bitbucket_server_rs::api::build_status_list::BuildStatusOrder;
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use serde_json::json;
+
+const COMMIT_PATH: &str = "/rest/build-status/latest/commits/8d51122def5632836d1cb1026e879069e10a1e13";
+
+#[tokio::test]
+async fn it_sorts_newest_build_status_first() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    // the server returns the oldest first, e.g. because of a proxy or an older version
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(COMMIT_PATH).query_param("orderBy", "oldest");
+        then.status(200).json_body(json!({
+            "size": 4,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": [
+                {"key": "lint", "state": "SUCCESSFUL", "url": "https://lint/1"},
+                {"key": "ci", "state": "FAILED", "url": "https://ci/1", "buildNumber": "1", "updatedDate": 1738198900000i64},
+                {"key": "ci", "state": "INPROGRESS", "url": "https://ci/2", "buildNumber": "2", "updatedDate": 1738199000000i64},
+                {"key": "ci", "state": "SUCCESSFUL", "url": "https://ci/3", "buildNumber": "3", "updatedDate": 1738199000000i64}
+            ]
+        }));
+    });
+
+    let mut statuses = client
+        .api()
+        .build_status_list("8d51122def5632836d1cb1026e879069e10a1e13")
+        .order_by(BuildStatusOrder::Oldest)
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap()
+        .values;
+
+    BuildStatus::sort_newest_first(&mut statuses);
+
+    let urls: Vec<&str> = statuses.iter().map(|status| status.url.as_str()).collect();
+    assert_eq!(
+        urls,
+        ["https://ci/3", "https://ci/2", "https://ci/1", "https://lint/1"]
+    );
+    assert_eq!(statuses[0].state, BuildStatusState::Successful);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_encodes_the_commit_id() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/build-status/latest/commits/release%2F1.0")
+            .query_param("orderBy", "status");
+        then.status(200).json_body(json!({
+            "size": 0,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": []
+        }));
+    });
+
+    let page = client
+        .api()
+        .build_status_list("release/1.0")
+        .order_by(BuildStatusOrder::Status)
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(page.values.is_empty());
+    mock.assert();
+}
@@ -3,8 +3,8 @@ mod common;
 use bitbucket_server_rs::api::build_status::{BuildStatusState, TestResults};
 use bitbucket_server_rs::api::build_status_post::BuildStatusPostPayload;
 use bitbucket_server_rs::client::ApiRequest;
-use chrono::{DateTime, Utc};
-use httpmock::Method::POST;
+use bitbucket_server_rs::timestamp::{epoch_seconds, from_epoch_seconds};
+use httpmock::Method::{GET, POST};
 use serde_json::json;
 
 #[tokio::test]
@@ -21,7 +21,7 @@ async fn it_can_post_build_status() {
                 "state": "SUCCESSFUL",
                 "url": "https://my-build-status.com/path",
                 "buildNumber": "9",
-                "dateAdded": 1738198923,
+                "dateAdded": 1738198923000i64,
                 "duration": 12,
                 "description": "DESCRIPTION",
                 "name": "NAME",
@@ -47,11 +47,7 @@ async fn it_can_post_build_status() {
                 key: "KEY".to_string(),
                 url: "https://my-build-status.com/path".to_string(),
                 build_number: Some("9".to_string()),
-                date_added: Some(
-                    DateTime::parse_from_rfc3339("2025-01-30T01:02:03Z")
-                        .unwrap()
-                        .with_timezone(&Utc),
-                ),
+                date_added: from_epoch_seconds(1738198923),
                 duration: Some(12),
                 description: Some("DESCRIPTION".to_string()),
                 name: Some("NAME".to_string()),
@@ -70,3 +66,57 @@ async fn it_can_post_build_status() {
     assert!(result.is_ok());
     mock.assert();
 }
+
+#[tokio::test]
+async fn it_can_post_build_status_and_get_it_back() {
+    common::setup();
+
+    let (server, client) = common::mock_client();
+
+    let post_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits/COMMIT_ID/builds")
+            .json_body(json!({
+                "key": "KEY",
+                "state": "SUCCESSFUL",
+                "url": "https://my-build-status.com/path"
+            }));
+        then.status(204);
+    });
+    let get_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits/COMMIT_ID/builds")
+            .query_param("key", "KEY");
+        then.status(200).json_body(json!({
+            "key": "KEY",
+            "state": "SUCCESSFUL",
+            "url": "https://my-build-status.com/path",
+            "createdDate": 1738198923000i64,
+            "updatedDate": 1738198924000i64
+        }));
+    });
+
+    let stored = client
+        .api()
+        .build_status_post(
+            "PROJECT_KEY",
+            "REPOSITORY_SLUG",
+            "COMMIT_ID",
+            &BuildStatusPostPayload {
+                state: BuildStatusState::Successful,
+                key: "KEY".to_string(),
+                url: "https://my-build-status.com/path".to_string(),
+                ..Default::default()
+            },
+        )
+        .send_and_get()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(stored.key, "KEY");
+    assert_eq!(epoch_seconds(&stored.created_date.unwrap()), 1738198923);
+    assert_eq!(epoch_seconds(&stored.updated_date.unwrap()), 1738198924);
+    post_mock.assert();
+    get_mock.assert();
+}
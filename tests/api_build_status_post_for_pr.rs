@@ -0,0 +1,66 @@
+mod common;
+
+use bitbucket_server_rs::api::build_status::BuildStatusState;
+use bitbucket_server_rs::api::build_status_post::BuildStatusPostPayload;
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::{GET, POST};
+use serde_json::json;
+
+#[tokio::test]
+async fn it_posts_build_status_to_pull_request_head() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let pull_request_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7");
+        then.status(200).json_body(json!({
+            "id": 7,
+            "version": 0,
+            "title": "Add new feature",
+            "state": "OPEN",
+            "open": true,
+            "closed": false,
+            "fromRef": {
+                "id": "refs/heads/feature",
+                "displayId": "feature",
+                "latestCommit": "8d51122def5632836d1cb1026e879069e10a1e13",
+                "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+            },
+            "toRef": {
+                "id": "refs/heads/main",
+                "displayId": "main",
+                "latestCommit": "0a943a29376f2336b78312d99e65da17048951db",
+                "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+            }
+        }));
+    });
+    let build_status_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits/8d51122def5632836d1cb1026e879069e10a1e13/builds")
+            .json_body(json!({
+                "key": "build-123",
+                "state": "INPROGRESS",
+                "url": "https://ci.example.com/build/123",
+                "ref": "refs/heads/feature"
+            }));
+        then.status(204);
+    });
+
+    let build_status = BuildStatusPostPayload {
+        key: "build-123".to_string(),
+        state: BuildStatusState::InProgress,
+        url: "https://ci.example.com/build/123".to_string(),
+        ..Default::default()
+    };
+
+    let response = client
+        .api()
+        .build_status_post_for_pr("PROJECT_KEY", "REPOSITORY_SLUG", "7", &build_status)
+        .send()
+        .await;
+
+    assert!(response.is_ok());
+    pull_request_mock.assert();
+    build_status_mock.assert();
+}
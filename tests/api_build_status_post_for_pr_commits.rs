@@ -0,0 +1,194 @@
+mod common;
+
+use bitbucket_server_rs::api::build_status::BuildStatusState;
+use bitbucket_server_rs::api::build_status_post::BuildStatusPostPayload;
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::{GET, POST};
+use httpmock::{Mock, MockServer};
+use serde_json::json;
+
+const REPOSITORY_PATH: &str = "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG";
+const HEAD_COMMIT: &str = "8d51122def5632836d1cb1026e879069e10a1e13";
+const GREEN_COMMIT: &str = "5b9e0c4ad1f2e3c4b5a6978877665544332211ff";
+const FAILED_COMMIT: &str = "1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d";
+const MERGE_BASE_COMMIT: &str = "d4f2c1a6be28d0a9c1c6e9b1b8cfa4e3f0d1e2a3";
+
+fn mock_incoming_commits(server: &MockServer) {
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("{REPOSITORY_PATH}/pull-requests/7"));
+        then.status(200).json_body(json!({
+            "id": 7,
+            "version": 0,
+            "title": "Add new feature",
+            "state": "OPEN",
+            "open": true,
+            "closed": false,
+            "fromRef": {
+                "id": "refs/heads/feature",
+                "displayId": "feature",
+                "latestCommit": HEAD_COMMIT,
+                "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+            },
+            "toRef": {
+                "id": "refs/heads/main",
+                "displayId": "main",
+                "latestCommit": "0a943a29376f2336b78312d99e65da17048951db",
+                "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+            }
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("{REPOSITORY_PATH}/pull-requests/7/merge-base"));
+        then.status(200)
+            .json_body(json!({"id": MERGE_BASE_COMMIT, "displayId": "d4f2c1a6be2"}));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("{REPOSITORY_PATH}/commits"))
+            .query_param("since", MERGE_BASE_COMMIT)
+            .query_param("until", HEAD_COMMIT);
+        then.status(200).json_body(json!({
+            "size": 3,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": [
+                {"id": HEAD_COMMIT, "displayId": "8d51122def5"},
+                {"id": GREEN_COMMIT, "displayId": "5b9e0c4ad1f"},
+                {"id": FAILED_COMMIT, "displayId": "1c2d3e4f5a6"}
+            ]
+        }));
+    });
+}
+
+fn mock_build_status_get<'a>(
+    server: &'a MockServer,
+    commit_id: &str,
+    state: Option<&str>,
+) -> Mock<'a> {
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("{REPOSITORY_PATH}/commits/{commit_id}/builds"))
+            .query_param("key", "lint");
+        match state {
+            Some(state) => then.status(200).json_body(json!({
+                "key": "lint",
+                "state": state,
+                "url": "https://ci.example.com/build/122"
+            })),
+            None => then.status(404).json_body(json!({
+                "errors": [{"message": "No build status with key lint"}]
+            })),
+        };
+    })
+}
+
+fn mock_build_status_post<'a>(server: &'a MockServer, commit_id: &str) -> Mock<'a> {
+    server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("{REPOSITORY_PATH}/commits/{commit_id}/builds"))
+            .json_body(json!({
+                "key": "lint",
+                "state": "SUCCESSFUL",
+                "url": "https://ci.example.com/build/123"
+            }));
+        then.status(204);
+    })
+}
+
+fn build_status() -> BuildStatusPostPayload {
+    BuildStatusPostPayload {
+        key: "lint".to_string(),
+        state: BuildStatusState::Successful,
+        url: "https://ci.example.com/build/123".to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn it_skips_commits_already_green() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    mock_incoming_commits(&server);
+    mock_build_status_get(&server, HEAD_COMMIT, None);
+    mock_build_status_get(&server, GREEN_COMMIT, Some("SUCCESSFUL"));
+    mock_build_status_get(&server, FAILED_COMMIT, Some("FAILED"));
+    let head_post_mock = mock_build_status_post(&server, HEAD_COMMIT);
+    let green_post_mock = mock_build_status_post(&server, GREEN_COMMIT);
+    let failed_post_mock = mock_build_status_post(&server, FAILED_COMMIT);
+
+    let batch = client
+        .api()
+        .build_status_post_for_pr_commits("PROJECT_KEY", "REPOSITORY_SLUG", "7", &build_status())
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(batch.posted, [HEAD_COMMIT, FAILED_COMMIT]);
+    assert_eq!(batch.skipped, [GREEN_COMMIT]);
+    head_post_mock.assert();
+    green_post_mock.assert_hits(0);
+    failed_post_mock.assert();
+}
+
+#[tokio::test]
+async fn it_stops_on_the_first_failed_post() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    mock_incoming_commits(&server);
+    mock_build_status_get(&server, HEAD_COMMIT, None);
+    mock_build_status_get(&server, GREEN_COMMIT, None);
+    server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("{REPOSITORY_PATH}/commits/{HEAD_COMMIT}/builds"));
+        then.status(500).body("Internal Server Error");
+    });
+    let next_post_mock = mock_build_status_post(&server, GREEN_COMMIT);
+
+    let error = client
+        .api()
+        .build_status_post_for_pr_commits("PROJECT_KEY", "REPOSITORY_SLUG", "7", &build_status())
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(error.is_response_error());
+    next_post_mock.assert_hits(0);
+}
+
+#[tokio::test]
+async fn it_posts_when_the_build_status_is_not_found() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    mock_incoming_commits(&server);
+    // detected from the status, whatever the body
+    for commit_id in [HEAD_COMMIT, GREEN_COMMIT, FAILED_COMMIT] {
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(format!("{REPOSITORY_PATH}/commits/{commit_id}/builds"));
+            then.status(404).body("Not Found");
+        });
+    }
+    let post_mocks = [HEAD_COMMIT, GREEN_COMMIT, FAILED_COMMIT]
+        .map(|commit_id| mock_build_status_post(&server, commit_id));
+
+    let batch = client
+        .api()
+        .build_status_post_for_pr_commits("PROJECT_KEY", "REPOSITORY_SLUG", "7", &build_status())
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(batch.posted, [HEAD_COMMIT, GREEN_COMMIT, FAILED_COMMIT]);
+    assert!(batch.skipped.is_empty());
+    for post_mock in post_mocks {
+        post_mock.assert();
+    }
+}
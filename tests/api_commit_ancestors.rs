@@ -0,0 +1,55 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use serde_json::json;
+
+const COMMITS_PATH: &str = "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits";
+
+fn mock_commit<'a>(server: &'a MockServer, id: &str, parents: &[&str]) -> httpmock::Mock<'a> {
+    let parents: Vec<_> = parents
+        .iter()
+        .map(|parent| json!({"id": parent, "displayId": parent}))
+        .collect();
+    let body = json!({
+        "id": id,
+        "displayId": id,
+        "message": format!("commit {id}"),
+        "parents": parents
+    });
+
+    server.mock(|when, then| {
+        when.method(GET).path(format!("{COMMITS_PATH}/{id}"));
+        then.status(200).json_body(body);
+    })
+}
+
+#[tokio::test]
+async fn it_can_walk_commit_ancestors() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    // e is a merge of c and d, which both have b as parent
+    let e = mock_commit(&server, "e", &["c", "d"]);
+    let c = mock_commit(&server, "c", &["b"]);
+    let d = mock_commit(&server, "d", &["b"]);
+    let b = mock_commit(&server, "b", &["a"]);
+    let a = mock_commit(&server, "a", &[]);
+
+    let ancestors = client
+        .api()
+        .commit_ancestors("PROJECT_KEY", "REPOSITORY_SLUG", "e", 2)
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let ids: Vec<String> = ancestors.into_iter().map(|commit| commit.id).collect();
+    assert_eq!(ids, vec!["c", "d", "b"]);
+    e.assert();
+    c.assert();
+    d.assert();
+    b.assert_hits(1);
+    a.assert_hits(0);
+}
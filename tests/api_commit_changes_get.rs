@@ -0,0 +1,102 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+
+#[tokio::test]
+async fn it_can_get_commit_changes() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(
+            "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits/COMMIT_ID/changes",
+        );
+        then.status(200).body(TEST_RESPONSE);
+    });
+
+    let response = client
+        .api()
+        .commit_changes_get("PROJECT_KEY", "REPOSITORY_SLUG", "COMMIT_ID")
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    let page = response.unwrap().unwrap();
+    assert_eq!(page.values.len(), 2);
+    assert_eq!(page.values[0].change_type, "ADD");
+    assert_eq!(page.values[1].path.to_string, "another/target");
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_can_get_commit_changes_with_params() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits/COMMIT_ID/changes")
+            .query_param("since", "SINCE_COMMIT_ID")
+            .query_param("start", "2")
+            .query_param("limit", "2");
+        then.status(200).body(TEST_RESPONSE);
+    });
+
+    let response = client
+        .api()
+        .commit_changes_get("PROJECT_KEY", "REPOSITORY_SLUG", "COMMIT_ID")
+        .since("SINCE_COMMIT_ID")
+        .start(2u32)
+        .limit(2u32)
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    let page = response.unwrap().unwrap();
+    assert_eq!(page.start, 2);
+    assert!(!page.is_last_page);
+    assert_eq!(page.next_page_start, Some(4));
+    mock.assert();
+}
+
+static TEST_RESPONSE: &str = r#"{
+  "fromHash": "SINCE_COMMIT_ID",
+  "toHash": "COMMIT_ID",
+  "size": 2,
+  "limit": 2,
+  "isLastPage": false,
+  "start": 2,
+  "nextPageStart": 4,
+  "values": [
+    {"contentId": "12345", "type": "ADD", "path": {"toString": "path/to/file"}},
+    {"contentId": "67890", "type": "MODIFY", "path": {"toString": "another/target"}}
+  ]
+}"#;
+
+#[tokio::test]
+async fn it_encodes_ref_with_slash() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(
+            "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits/refs%2Fheads%2Ffeature%2Ffoo%20bar/changes",
+        );
+        then.status(200)
+            .body(r#"{"size":0,"limit":25,"isLastPage":true,"values":[],"start":0}"#);
+    });
+
+    let response = client
+        .api()
+        .commit_changes_get("PROJECT_KEY", "REPOSITORY_SLUG", "refs/heads/feature/foo bar")
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    assert!(response.unwrap().is_some());
+    mock.assert();
+}
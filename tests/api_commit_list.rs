@@ -0,0 +1,59 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use serde_json::json;
+
+#[tokio::test]
+async fn it_can_list_commits_between_two_refs() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits")
+            .query_param("since", "refs/tags/v1.0")
+            .query_param("until", "0a943a29376f2336b78312d99e65da17048951db")
+            .query_param("merges", "exclude")
+            .query_param("limit", "50");
+        then.status(200).json_body(json!({
+            "size": 2,
+            "limit": 50,
+            "isLastPage": true,
+            "start": 0,
+            "values": [
+                {
+                    "id": "0a943a29376f2336b78312d99e65da17048951db",
+                    "displayId": "0a943a29376",
+                    "message": "Fix login redirect",
+                    "parents": [{"id": "8d51122def5632836d1cb1026e879069e10a1e13", "displayId": "8d51122def5"}]
+                },
+                {
+                    "id": "8d51122def5632836d1cb1026e879069e10a1e13",
+                    "displayId": "8d51122def5",
+                    "message": "Add login page",
+                    "parents": [{"id": "def0123abcdef4567abcdef8987abcdef6543abc", "displayId": "def0123abcd"}]
+                }
+            ]
+        }));
+    });
+
+    let page = client
+        .api()
+        .commit_list("PROJECT_KEY", "REPOSITORY_SLUG")
+        .since("refs/tags/v1.0")
+        .until("0a943a29376f2336b78312d99e65da17048951db")
+        .merges("exclude")
+        .limit(50u64)
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let commits: Vec<&str> = page.values.iter().map(|commit| commit.display_id.as_str()).collect();
+    assert_eq!(commits, ["0a943a29376", "8d51122def5"]);
+    assert_eq!(page.next_start(), None);
+    mock.assert();
+}
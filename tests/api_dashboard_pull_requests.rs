@@ -0,0 +1,145 @@
+mod common;
+
+use bitbucket_server_rs::api::common::RequestExt;
+use bitbucket_server_rs::api::pull_request_participant::ParticipantRole;
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+
+#[tokio::test]
+async fn it_can_list_dashboard_pull_requests_by_role() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/dashboard/pull-requests")
+            .query_param("state", "OPEN")
+            .query_param("role", "REVIEWER");
+        then.status(200).body(FIRST_PAGE);
+    });
+
+    let response = client
+        .api()
+        .dashboard_pull_requests()
+        .state("OPEN")
+        .role(ParticipantRole::Reviewer)
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    let page = response.unwrap().unwrap();
+    assert_eq!(page.values.len(), 1);
+    assert_eq!(page.values[0].id, 101);
+    assert_eq!(page.values[0].from_ref.repository.slug, "REPOSITORY_SLUG");
+    assert!(!page.is_last_page);
+    assert_eq!(page.next_page_start, Some(1));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_sends_the_common_query_flags() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/dashboard/pull-requests")
+            .query_param("role", "REVIEWER")
+            .query_param("avatarSize", "64")
+            .query_param("markup", "true")
+            // only the flags that are set are sent
+            .matches(|req| {
+                req.query_params.as_ref().is_some_and(|params| {
+                    params.len() == 3 && params.iter().all(|(name, _)| name != "withAttributes")
+                })
+            });
+        then.status(200).body(FIRST_PAGE);
+    });
+
+    let response = client
+        .api()
+        .dashboard_pull_requests()
+        .role(ParticipantRole::Reviewer)
+        .avatar_size(64)
+        .markup(true)
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    assert_eq!(response.unwrap().unwrap().values[0].id, 101);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_can_page_through_dashboard_pull_requests() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/dashboard/pull-requests")
+            .query_param("role", "AUTHOR")
+            .query_param("start", "1")
+            .query_param("limit", "1");
+        then.status(200).body(LAST_PAGE);
+    });
+
+    let response = client
+        .api()
+        .dashboard_pull_requests()
+        .role(ParticipantRole::Author)
+        .start(1u32)
+        .limit(1u32)
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    let page = response.unwrap().unwrap();
+    assert!(page.is_last_page);
+    assert_eq!(page.start, 1);
+    assert!(page.values.is_empty());
+    mock.assert();
+}
+
+static FIRST_PAGE: &str = r#"{
+  "size": 1,
+  "limit": 1,
+  "isLastPage": false,
+  "start": 0,
+  "nextPageStart": 1,
+  "values": [
+    {
+      "id": 101,
+      "version": 2,
+      "title": "Add new feature",
+      "state": "OPEN",
+      "open": true,
+      "closed": false,
+      "createdDate": 1738198923000,
+      "updatedDate": 1738198924000,
+      "fromRef": {
+        "id": "refs/heads/feature-branch",
+        "displayId": "feature-branch",
+        "latestCommit": "FROM_COMMIT_ID",
+        "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+      },
+      "toRef": {
+        "id": "refs/heads/main",
+        "displayId": "main",
+        "latestCommit": "TO_COMMIT_ID",
+        "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+      }
+    }
+  ]
+}"#;
+
+static LAST_PAGE: &str = r#"{
+  "size": 0,
+  "limit": 1,
+  "isLastPage": true,
+  "start": 1,
+  "values": []
+}"#;
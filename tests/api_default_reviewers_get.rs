@@ -0,0 +1,61 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+
+#[tokio::test]
+async fn it_can_get_default_reviewers() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/default-reviewers/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/reviewers")
+            .query_param("sourceRepoId", "11")
+            .query_param("targetRepoId", "12")
+            .query_param("sourceRefId", "refs/heads/feature")
+            .query_param("targetRefId", "refs/heads/main");
+        then.status(200).body(
+            r#"[
+                {
+                    "name": "jdoe",
+                    "emailAddress": "jdoe@example.com",
+                    "id": 101,
+                    "displayName": "Jane Doe",
+                    "active": true,
+                    "slug": "jdoe",
+                    "type": "NORMAL"
+                },
+                {
+                    "name": "rroe",
+                    "id": 102,
+                    "displayName": "Richard Roe",
+                    "active": true,
+                    "slug": "rroe",
+                    "type": "NORMAL"
+                }
+            ]"#,
+        );
+    });
+
+    let reviewers = client
+        .api()
+        .default_reviewers_get("PROJECT_KEY", "REPOSITORY_SLUG")
+        .source_repo_id(11u64)
+        .target_repo_id(12u64)
+        .source_ref_id("refs/heads/feature")
+        .target_ref_id("refs/heads/main")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(reviewers.len(), 2);
+    assert_eq!(reviewers[0].name, "jdoe");
+    assert_eq!(reviewers[0].email_address, Some("jdoe@example.com".to_string()));
+    assert_eq!(reviewers[1].display_name, Some("Richard Roe".to_string()));
+    assert_eq!(reviewers[1].email_address, None);
+    mock.assert();
+}
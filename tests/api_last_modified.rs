@@ -0,0 +1,60 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use serde_json::json;
+
+#[tokio::test]
+async fn it_can_get_last_modified_at_ref() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/last-modified/src/api")
+            .query_param("at", "refs/heads/main");
+        then.status(200).json_body(json!({
+            "latestCommit": {"id": "8d51122def5632836d1cb1026e879069e10a1e13", "displayId": "8d51122def5"},
+            "files": {
+                "mod.rs": {"id": "0a943a29376f2336b78312d99e65da17048951db", "displayId": "0a943a29376"}
+            }
+        }));
+    });
+
+    let last_modified = client
+        .api()
+        .last_modified("PROJECT_KEY", "REPOSITORY_SLUG", "/src/api/")
+        .at("refs/heads/main")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(last_modified.files["mod.rs"].display_id, "0a943a29376");
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_encodes_path_with_special_characters() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/last-modified/docs/release%20notes/v1%232");
+        then.status(200).json_body(json!({"files": {}}));
+    });
+
+    let response = client
+        .api()
+        .last_modified("PROJECT_KEY", "REPOSITORY_SLUG", "docs/release notes/v1#2")
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    assert!(response.unwrap().is_some());
+    mock.assert();
+}
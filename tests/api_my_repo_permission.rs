@@ -0,0 +1,100 @@
+mod common;
+
+use bitbucket_server_rs::api::my_repo_permission::RepositoryPermission;
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use httpmock::{Mock, MockServer};
+use serde_json::{json, Value};
+
+/// Lists the repositories of PROJECT the authenticated user holds a permission on.
+fn mock_repositories<'a>(server: &'a MockServer, permission: &str, slugs: &[&str]) -> Mock<'a> {
+    let values: Vec<Value> = slugs
+        .iter()
+        .map(|slug| json!({"slug": slug, "name": slug, "project": {"key": "PROJECT"}}))
+        .collect();
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/repos")
+            .query_param("projectkey", "PROJECT")
+            .query_param("permission", permission);
+        then.status(200).json_body(json!({
+            "size": values.len(),
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": values
+        }));
+    })
+}
+
+#[tokio::test]
+async fn it_can_get_highest_repository_permission() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let admin_mock = mock_repositories(&server, "REPO_ADMIN", &["OTHER"]);
+    let write_mock = mock_repositories(&server, "REPO_WRITE", &["OTHER", "REPO"]);
+    let read_mock = mock_repositories(&server, "REPO_READ", &["OTHER", "REPO"]);
+
+    let permission = client
+        .api()
+        .my_repo_permission("PROJECT", "REPO")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(permission, Some(RepositoryPermission::Write));
+    assert!(permission.unwrap().can_write());
+    admin_mock.assert();
+    write_mock.assert();
+    // a lower permission isn't looked for once a higher one is found
+    read_mock.assert_hits(0);
+}
+
+#[tokio::test]
+async fn it_sees_write_permission_granted_through_a_group() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    // no permission is granted to the user directly, and listing them is forbidden
+    let users_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT/repos/REPO/permissions/users");
+        then.status(403).json_body(json!({
+            "errors": [{"message": "You are not permitted to access this resource"}]
+        }));
+    });
+    // a group of the user is granted REPO_WRITE, so the repository is listed for it
+    mock_repositories(&server, "REPO_ADMIN", &[]);
+    mock_repositories(&server, "REPO_WRITE", &["REPO"]);
+
+    let permission = client
+        .api()
+        .my_repo_permission("PROJECT", "REPO")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(permission, Some(RepositoryPermission::Write));
+    users_mock.assert_hits(0);
+}
+
+#[tokio::test]
+async fn it_reports_no_permission_as_none() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    mock_repositories(&server, "REPO_ADMIN", &[]);
+    mock_repositories(&server, "REPO_WRITE", &[]);
+    mock_repositories(&server, "REPO_READ", &["OTHER"]);
+
+    let permission = client
+        .api()
+        .my_repo_permission("PROJECT", "REPO")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(permission, None);
+}
@@ -84,3 +84,47 @@ async fn it_can_get_pull_request_changes_with_params() {
     assert!(response.is_ok());
     mock.assert();
 }
+
+#[tokio::test]
+async fn it_can_filter_pull_request_changes_by_path_prefix() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(
+            "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/PULL_REQUEST_ID/changes"
+        );
+        then.status(200).body(r#"{
+            "fromHash":"from_hash",
+            "toHash":"to_hash",
+            "values":[
+                {"contentId":"1","type":"MODIFY","path":{"toString":"services/billing/src/lib.rs"}},
+                {"contentId":"2","type":"ADD","path":{"toString":"services/shipping/src/lib.rs"}},
+                {"contentId":"3","type":"DELETE","path":{"toString":"services/billing/README.md"}},
+                {"contentId":"4","type":"MODIFY","path":{"toString":"Cargo.toml"}}
+            ]
+        }"#);
+    });
+
+    let changes = client
+        .api()
+        .pull_request_changes_get("PROJECT_KEY", "REPOSITORY_SLUG", "PULL_REQUEST_ID")
+        .filter_path_prefix("services/billing/")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let paths: Vec<String> = changes
+        .values
+        .into_iter()
+        .map(|change| change.path.to_string)
+        .collect();
+    assert_eq!(
+        paths,
+        vec!["services/billing/src/lib.rs", "services/billing/README.md"]
+    );
+    mock.assert();
+}
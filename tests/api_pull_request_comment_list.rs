@@ -0,0 +1,60 @@
+mod common;
+
+use bitbucket_server_rs::api::common::RequestExt;
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use serde_json::json;
+
+#[tokio::test]
+async fn it_sends_the_common_query_flags() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/comments")
+            .query_param("path", "src/main.rs")
+            .query_param("avatarSize", "48")
+            .query_param("markup", "true")
+            // only the flags that are set are sent
+            .matches(|req| {
+                req.query_params.as_ref().is_some_and(|params| {
+                    params.len() == 3 && params.iter().all(|(name, _)| name != "withAttributes")
+                })
+            });
+        then.status(200).json_body(json!({
+            "size": 1,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": [{
+                "id": 1,
+                "version": 0,
+                "text": "Why is this needed?",
+                "author": {
+                    "name": "jdoe",
+                    "avatarUrl": "https://bitbucket-server/users/jdoe/avatar.png?s=48"
+                }
+            }]
+        }));
+    });
+
+    let page = client
+        .api()
+        .pull_request_comment_list("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .path("src/main.rs")
+        .avatar_size(48)
+        .markup(true)
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        page.values[0].author.as_ref().unwrap().avatar_url,
+        Some("https://bitbucket-server/users/jdoe/avatar.png?s=48".to_string())
+    );
+    mock.assert();
+}
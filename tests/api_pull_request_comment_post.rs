@@ -0,0 +1,68 @@
+mod common;
+
+use bitbucket_server_rs::api::pull_request_comment_post::CommentPostPayload;
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::POST;
+use serde_json::json;
+
+#[tokio::test]
+async fn it_can_reply_to_comment() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7/comments")
+            .json_body(json!({
+                "text": "Fixed in the latest commit",
+                "parent": {"id": 2}
+            }));
+        then.status(201).json_body(json!({
+            "id": 1,
+            "version": 0,
+            "text": "Please rename this",
+            "author": {"name": "reviewer"},
+            "createdDate": 1738198923000i64,
+            "comments": [
+                {
+                    "id": 2,
+                    "version": 0,
+                    "text": "Which name?",
+                    "author": {"name": "author"},
+                    "comments": [
+                        {
+                            "id": 3,
+                            "version": 0,
+                            "text": "Fixed in the latest commit",
+                            "author": {"name": "bot"},
+                            "comments": []
+                        }
+                    ]
+                }
+            ]
+        }));
+    });
+
+    let thread = client
+        .api()
+        .pull_request_comment_post(
+            "PROJECT_KEY",
+            "REPOSITORY_SLUG",
+            "7",
+            &CommentPostPayload::reply(2, "Fixed in the latest commit"),
+        )
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let chain: Vec<u64> = thread
+        .reply_chain(3)
+        .unwrap()
+        .iter()
+        .map(|comment| comment.id)
+        .collect();
+    assert_eq!(chain, vec![1, 2, 3]);
+    assert_eq!(thread.find(3).unwrap().author.as_ref().unwrap().name, "bot");
+    mock.assert();
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use serde_json::json;
+
+#[tokio::test]
+async fn it_rebuilds_threads_from_paged_comments_on_a_path() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let first_page_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/comments")
+            .query_param("path", "src/main.rs")
+            .query_param("start", "0");
+        then.status(200).json_body(json!({
+            "size": 2,
+            "limit": 2,
+            "isLastPage": false,
+            "start": 0,
+            "nextPageStart": 2,
+            "values": [
+                {"id": 1, "version": 0, "text": "Why is this needed?"},
+                {"id": 2, "version": 0, "text": "For the migration.", "parent": {"id": 1}}
+            ]
+        }));
+    });
+    let second_page_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/comments")
+            .query_param("path", "src/main.rs")
+            .query_param("start", "2");
+        then.status(200).json_body(json!({
+            "size": 2,
+            "limit": 2,
+            "isLastPage": true,
+            "start": 2,
+            "values": [
+                {"id": 3, "version": 0, "text": "Makes sense, thanks.", "parent": {"id": 2}},
+                {"id": 4, "version": 0, "text": "Typo in the title"}
+            ]
+        }));
+    });
+
+    let threads = client
+        .api()
+        .pull_request_comment_threads("PROJECT_KEY", "REPOSITORY_SLUG", "1", "src/main.rs")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(threads.len(), 2);
+    assert_eq!(threads[0].id, 1);
+    assert_eq!(threads[0].comments[0].id, 2);
+    assert_eq!(threads[0].comments[0].comments[0].text, "Makes sense, thanks.");
+    assert_eq!(threads[1].id, 4);
+    assert!(threads[1].comments.is_empty());
+    first_page_mock.assert();
+    second_page_mock.assert();
+}
+
+#[tokio::test]
+async fn it_can_list_comments_on_path() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/comments")
+            .query_param("path", "src/main.rs")
+            .query_param("limit", "10");
+        then.status(200).json_body(json!({
+            "size": 1,
+            "limit": 10,
+            "isLastPage": true,
+            "start": 0,
+            "values": [{"id": 1, "version": 0, "text": "Nit: rename this"}]
+        }));
+    });
+
+    let page = client
+        .api()
+        .pull_request_comment_list("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .path("src/main.rs")
+        .limit(10u64)
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(page.values[0].text, "Nit: rename this");
+    mock.assert();
+}
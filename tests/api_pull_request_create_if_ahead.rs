@@ -0,0 +1,149 @@
+mod common;
+
+use bitbucket_server_rs::api::pull_request_post::{
+    ProjectInfo, PullRequestPostPayload, RefInfo, RepositoryInfo,
+};
+use bitbucket_server_rs::client::ApiRequest;
+use bitbucket_server_rs::Error;
+use httpmock::Method::{GET, POST};
+use serde_json::json;
+
+#[tokio::test]
+async fn it_creates_pull_request_when_source_is_ahead() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let compare_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/compare/commits")
+            .query_param("from", "refs/heads/feature-branch")
+            .query_param("to", "refs/heads/main")
+            .query_param("limit", "1");
+        then.status(200).json_body(json!({
+            "size": 1,
+            "limit": 1,
+            "isLastPage": false,
+            "start": 0,
+            "nextPageStart": 1,
+            "values": [{
+                "id": "FROM_COMMIT_ID",
+                "displayId": "FROM_COMMI",
+                "message": "Add new feature",
+                "parents": [{"id": "TO_COMMIT_ID", "displayId": "TO_COMMIT_"}]
+            }]
+        }));
+    });
+    let list_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests");
+        then.status(200).json_body(json!({
+            "size": 0,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": []
+        }));
+    });
+    let post_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests");
+        then.status(201).json_body(pull_request_json(102));
+    });
+
+    let response = client
+        .api()
+        .pull_request_create_if_ahead("PROJECT_KEY", "REPOSITORY_SLUG", &payload())
+        .send()
+        .await;
+
+    assert_eq!(response.unwrap().unwrap().id, 102);
+    compare_mock.assert();
+    list_mock.assert();
+    post_mock.assert();
+}
+
+#[tokio::test]
+async fn it_reports_no_commits_between_identical_branches() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let compare_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/compare/commits");
+        then.status(200).json_body(json!({
+            "size": 0,
+            "limit": 1,
+            "isLastPage": true,
+            "start": 0,
+            "values": []
+        }));
+    });
+    let post_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests");
+        then.status(400);
+    });
+
+    let error = client
+        .api()
+        .pull_request_create_if_ahead("PROJECT_KEY", "REPOSITORY_SLUG", &payload())
+        .send()
+        .await
+        .unwrap_err();
+
+    match error {
+        Error::NoCommitsBetween { from, to } => {
+            assert_eq!(from, "refs/heads/feature-branch");
+            assert_eq!(to, "refs/heads/main");
+        }
+        error => panic!("unexpected error: {error:?}"),
+    }
+    compare_mock.assert();
+    post_mock.assert_hits(0);
+}
+
+fn payload() -> PullRequestPostPayload {
+    let repository_info = RepositoryInfo {
+        slug: "REPOSITORY_SLUG".to_string(),
+        project: ProjectInfo {
+            key: "PROJECT_KEY".to_string(),
+        },
+    };
+
+    PullRequestPostPayload {
+        title: "Add new feature".to_string(),
+        description: None,
+        from_ref: RefInfo {
+            id: "refs/heads/feature-branch".to_string(),
+            repository: repository_info.clone(),
+        },
+        to_ref: RefInfo {
+            id: "refs/heads/main".to_string(),
+            repository: repository_info,
+        },
+        reviewers: None,
+    }
+}
+
+fn pull_request_json(id: u64) -> serde_json::Value {
+    json!({
+        "id": id,
+        "version": 0,
+        "title": "Add new feature",
+        "state": "OPEN",
+        "open": true,
+        "closed": false,
+        "fromRef": {
+            "id": "refs/heads/feature-branch",
+            "displayId": "feature-branch",
+            "latestCommit": "FROM_COMMIT_ID",
+            "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+        },
+        "toRef": {
+            "id": "refs/heads/main",
+            "displayId": "main",
+            "latestCommit": "TO_COMMIT_ID",
+            "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+        }
+    })
+}
@@ -0,0 +1,326 @@
+mod common;
+
+use bitbucket_server_rs::api::pull_request_post::{
+    ProjectInfo, PullRequestPostPayload, RefInfo, RepositoryInfo,
+};
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::{GET, POST};
+use serde_json::json;
+use std::time::Duration;
+
+#[tokio::test]
+async fn it_returns_existing_pull_request() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let list_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests")
+            .query_param("direction", "INCOMING")
+            .query_param("at", "refs/heads/main")
+            .query_param("state", "OPEN");
+        then.status(200).json_body(json!({
+            "size": 2,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": [
+                // the same branch, but from a fork
+                fork_pull_request_json(100),
+                pull_request_json(101, "refs/heads/main")
+            ]
+        }));
+    });
+
+    let post_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests");
+        then.status(201).json_body(pull_request_json(102, "refs/heads/main"));
+    });
+
+    let response = client
+        .api()
+        .pull_request_create_or_get("PROJECT_KEY", "REPOSITORY_SLUG", &payload())
+        .send()
+        .await;
+
+    assert_eq!(response.unwrap().unwrap().id, 101);
+    list_mock.assert();
+    post_mock.assert_hits(0);
+}
+
+#[tokio::test]
+async fn it_returns_existing_pull_request_from_a_fork() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let list_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests")
+            .query_param("direction", "INCOMING")
+            .query_param("at", "refs/heads/main")
+            .query_param("state", "OPEN");
+        then.status(200).json_body(json!({
+            "size": 2,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": [
+                pull_request_json(100, "refs/heads/main"),
+                fork_pull_request_json(101)
+            ]
+        }));
+    });
+    let post_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests");
+        then.status(201).json_body(fork_pull_request_json(102));
+    });
+
+    let mut payload = payload();
+    payload.from_ref.repository = RepositoryInfo {
+        slug: "REPOSITORY_SLUG".to_string(),
+        project: ProjectInfo {
+            key: "~JDOE".to_string(),
+        },
+    };
+
+    let response = client
+        .api()
+        .pull_request_create_or_get("PROJECT_KEY", "REPOSITORY_SLUG", &payload)
+        .send()
+        .await;
+
+    // the pull request from the same branch of the upstream repository is not a match
+    assert_eq!(response.unwrap().unwrap().id, 101);
+    list_mock.assert();
+    post_mock.assert_hits(0);
+}
+
+#[tokio::test]
+async fn it_creates_pull_request_when_none_exists() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let list_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests");
+        then.status(200).json_body(json!({
+            "size": 1,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": [pull_request_json(100, "refs/heads/release")]
+        }));
+    });
+
+    let post_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests")
+            .json_body(json!({
+                "title": "Add new feature",
+                "fromRef": {
+                    "id": "refs/heads/feature-branch",
+                    "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+                },
+                "toRef": {
+                    "id": "refs/heads/main",
+                    "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+                }
+            }));
+        then.status(201).json_body(pull_request_json(102, "refs/heads/main"));
+    });
+
+    let response = client
+        .api()
+        .pull_request_create_or_get("PROJECT_KEY", "REPOSITORY_SLUG", &payload())
+        .send()
+        .await;
+
+    assert_eq!(response.unwrap().unwrap().id, 102);
+    list_mock.assert();
+    post_mock.assert();
+}
+
+#[tokio::test]
+async fn it_stops_paginating_when_dropped() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let first_page_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests")
+            .query_param("start", "0");
+        then.status(200)
+            .delay(Duration::from_millis(300))
+            .json_body(json!({
+                "size": 1,
+                "limit": 1,
+                "isLastPage": false,
+                "start": 0,
+                "nextPageStart": 1,
+                "values": [pull_request_json(100, "refs/heads/release")]
+            }));
+    });
+    let second_page_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests")
+            .query_param("start", "1");
+        then.status(200).json_body(json!({
+            "size": 0,
+            "limit": 1,
+            "isLastPage": true,
+            "start": 1,
+            "values": []
+        }));
+    });
+    let post_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests");
+        then.status(201).json_body(pull_request_json(102, "refs/heads/main"));
+    });
+
+    let request = client
+        .api()
+        .pull_request_create_or_get("PROJECT_KEY", "REPOSITORY_SLUG", &payload());
+
+    // the caller gives up while the first page is in flight
+    let response = tokio::time::timeout(Duration::from_millis(100), request.send()).await;
+    assert!(response.is_err());
+
+    // nothing keeps running in the background once the future is dropped
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    first_page_mock.assert();
+    second_page_mock.assert_hits(0);
+    post_mock.assert_hits(0);
+}
+
+#[tokio::test]
+async fn it_stops_paginating_when_next_page_start_does_not_advance() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    // a broken server that keeps pointing back at the same page
+    let list_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests");
+        then.status(200).json_body(json!({
+            "size": 1,
+            "limit": 1,
+            "isLastPage": false,
+            "start": 0,
+            "nextPageStart": 0,
+            "values": [pull_request_json(100, "refs/heads/release")]
+        }));
+    });
+    let post_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests");
+        then.status(201).json_body(pull_request_json(102, "refs/heads/main"));
+    });
+
+    let response = client
+        .api()
+        .pull_request_create_or_get("PROJECT_KEY", "REPOSITORY_SLUG", &payload())
+        .send()
+        .await;
+
+    assert_eq!(response.unwrap().unwrap().id, 102);
+    list_mock.assert_hits(1);
+    post_mock.assert();
+}
+
+#[tokio::test]
+async fn it_looks_again_after_a_conflict() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let list_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests");
+        then.status(200).json_body(json!({
+            "size": 0,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": []
+        }));
+    });
+    // detected from the status, whatever the wording of the message
+    let post_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests");
+        then.status(409).json_body(json!({
+            "errors": [{
+                "message": "Only one pull request may be open for a given source and target branch",
+                "exceptionName": "com.atlassian.bitbucket.pull.DuplicatePullRequestException"
+            }]
+        }));
+    });
+
+    let error = client
+        .api()
+        .pull_request_create_or_get("PROJECT_KEY", "REPOSITORY_SLUG", &payload())
+        .send()
+        .await
+        .unwrap_err();
+
+    // the conflicting pull request was looked for again, but is no longer open
+    assert!(error.is_http_client_error());
+    assert_eq!(error.status(), Some(409));
+    list_mock.assert_hits(2);
+    post_mock.assert();
+}
+
+fn payload() -> PullRequestPostPayload {
+    let repository_info = RepositoryInfo {
+        slug: "REPOSITORY_SLUG".to_string(),
+        project: ProjectInfo {
+            key: "PROJECT_KEY".to_string(),
+        },
+    };
+
+    PullRequestPostPayload {
+        title: "Add new feature".to_string(),
+        description: None,
+        from_ref: RefInfo {
+            id: "refs/heads/feature-branch".to_string(),
+            repository: repository_info.clone(),
+        },
+        to_ref: RefInfo {
+            id: "refs/heads/main".to_string(),
+            repository: repository_info,
+        },
+        reviewers: None,
+    }
+}
+
+fn pull_request_json(id: u64, to_ref: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "version": 0,
+        "title": "Add new feature",
+        "state": "OPEN",
+        "open": true,
+        "closed": false,
+        "fromRef": {
+            "id": "refs/heads/feature-branch",
+            "displayId": "feature-branch",
+            "latestCommit": "FROM_COMMIT_ID",
+            "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+        },
+        "toRef": {
+            "id": to_ref,
+            "displayId": to_ref.trim_start_matches("refs/heads/"),
+            "latestCommit": "TO_COMMIT_ID",
+            "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+        }
+    })
+}
+
+fn fork_pull_request_json(id: u64) -> serde_json::Value {
+    let mut pull_request = pull_request_json(id, "refs/heads/main");
+    pull_request["fromRef"]["repository"] =
+        json!({"slug": "REPOSITORY_SLUG", "project": {"key": "~JDOE"}});
+    pull_request
+}
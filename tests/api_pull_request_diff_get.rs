@@ -0,0 +1,88 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+
+#[tokio::test]
+async fn it_can_get_pull_request_diff() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/PULL_REQUEST_ID/diff")
+            .query_param("contextLines", "3")
+            .query_param("whitespace", "ignore-all");
+        then.status(200).body(
+            r#"{
+                "fromHash": "from_hash",
+                "toHash": "to_hash",
+                "contextLines": 3,
+                "whitespace": "ignore-all",
+                "diffs": [{
+                    "source": {"toString": "hello.txt"},
+                    "destination": {"toString": "hello.txt"},
+                    "hunks": [],
+                    "truncated": false
+                }],
+                "truncated": false
+            }"#,
+        );
+    });
+
+    let diffs = client
+        .api()
+        .pull_request_diff_get("PROJECT_KEY", "REPOSITORY_SLUG", "PULL_REQUEST_ID")
+        .context_lines(3u32)
+        .whitespace("ignore-all")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(diffs.diffs.len(), 1);
+    assert!(!diffs.is_truncated());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_surfaces_truncated_pull_request_diff() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(
+            "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/PULL_REQUEST_ID/diff",
+        );
+        then.status(200).body(
+            r#"{
+                "fromHash": "from_hash",
+                "toHash": "to_hash",
+                "diffs": [{
+                    "source": {"toString": "huge.txt"},
+                    "destination": {"toString": "huge.txt"},
+                    "hunks": [],
+                    "truncated": true
+                }],
+                "truncated": true
+            }"#,
+        );
+    });
+
+    let diffs = client
+        .api()
+        .pull_request_diff_get("PROJECT_KEY", "REPOSITORY_SLUG", "PULL_REQUEST_ID")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(diffs.truncated);
+    assert!(diffs.diffs[0].truncated);
+    assert!(diffs.is_truncated());
+    mock.assert();
+}
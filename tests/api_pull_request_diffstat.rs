@@ -0,0 +1,68 @@
+mod common;
+
+use bitbucket_server_rs::api::diff::DiffStat;
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use serde_json::json;
+
+#[tokio::test]
+async fn it_can_get_pull_request_diffstat() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7/diff")
+            .query_param("contextLines", "0");
+        then.status(200).json_body(json!({
+            "contextLines": 0,
+            "diffs": [
+                {
+                    "destination": {"toString": "assets/logo.png"},
+                    "binary": true,
+                    "truncated": false
+                },
+                {
+                    "source": {"toString": "README.md"},
+                    "destination": {"toString": "README.md"},
+                    "hunks": [{
+                        "sourceLine": 3,
+                        "sourceSpan": 2,
+                        "destinationLine": 3,
+                        "destinationSpan": 1,
+                        "segments": [
+                            {"type": "REMOVED", "lines": [
+                                {"source": 3, "destination": 3, "line": "old line"},
+                                {"source": 4, "destination": 3, "line": "another old line"}
+                            ]},
+                            {"type": "ADDED", "lines": [
+                                {"source": 5, "destination": 3, "line": "new line"}
+                            ]}
+                        ]
+                    }]
+                }
+            ],
+            "truncated": false
+        }));
+    });
+
+    let stat = client
+        .api()
+        .pull_request_diffstat("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        stat,
+        DiffStat {
+            files_changed: 2,
+            binary_files: 1,
+            lines_added: 1,
+            lines_removed: 2,
+            truncated: false,
+        }
+    );
+    mock.assert();
+}
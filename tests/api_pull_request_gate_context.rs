@@ -0,0 +1,242 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use httpmock::{Mock, MockServer};
+use serde_json::{json, Value};
+
+const PULL_REQUEST_PATH: &str =
+    "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7";
+const HEAD_COMMIT: &str = "8d51122def5632836d1cb1026e879069e10a1e13";
+
+fn mock_pull_request(server: &MockServer) -> Mock<'_> {
+    server.mock(|when, then| {
+        when.method(GET).path(PULL_REQUEST_PATH);
+        then.status(200).json_body(json!({
+            "id": 7,
+            "version": 3,
+            "title": "Add new feature",
+            "state": "OPEN",
+            "open": true,
+            "closed": false,
+            "fromRef": {
+                "id": "refs/heads/feature",
+                "displayId": "feature",
+                "latestCommit": HEAD_COMMIT,
+                "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+            },
+            "toRef": {
+                "id": "refs/heads/main",
+                "displayId": "main",
+                "latestCommit": "0a943a29376f2336b78312d99e65da17048951db",
+                "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+            }
+        }));
+    })
+}
+
+fn mock_changes<'a>(
+    server: &'a MockServer,
+    from_hash: &str,
+    start: u64,
+    paths: &[&str],
+    next_page_start: Option<u64>,
+) -> Mock<'a> {
+    let values: Vec<Value> = paths
+        .iter()
+        .map(|path| json!({"contentId": "CONTENT_ID", "type": "MODIFY", "path": {"toString": path}}))
+        .collect();
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("{PULL_REQUEST_PATH}/changes"))
+            .query_param("start", start.to_string());
+        then.status(200).json_body(json!({
+            "fromHash": from_hash,
+            "toHash": "0a943a29376f2336b78312d99e65da17048951db",
+            "size": values.len(),
+            "limit": 2,
+            "isLastPage": next_page_start.is_none(),
+            "start": start,
+            "nextPageStart": next_page_start,
+            "values": values
+        }));
+    })
+}
+
+fn mock_build_statuses<'a>(server: &'a MockServer, states: &[&str]) -> Mock<'a> {
+    let values: Vec<Value> = states
+        .iter()
+        .enumerate()
+        .map(|(i, state)| {
+            json!({
+                "key": format!("build-{i}"),
+                "state": state,
+                "url": format!("https://ci.example.com/build/{i}")
+            })
+        })
+        .collect();
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/rest/build-status/latest/commits/{HEAD_COMMIT}"));
+        then.status(200).json_body(json!({
+            "size": values.len(),
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": values
+        }));
+    })
+}
+
+#[tokio::test]
+async fn it_reads_changes_and_build_statuses() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let pull_request_mock = mock_pull_request(&server);
+    let first_page_mock = mock_changes(
+        &server,
+        HEAD_COMMIT,
+        0,
+        &["services/billing/src/lib.rs", "README.md"],
+        Some(2),
+    );
+    let second_page_mock =
+        mock_changes(&server, HEAD_COMMIT, 2, &["services/billing/Cargo.toml"], None);
+    let build_statuses_mock = mock_build_statuses(&server, &["SUCCESSFUL", "SUCCESSFUL"]);
+
+    let context = client
+        .api()
+        .pull_request_gate_context("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .filter_path_prefix("services/billing/")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(context.pull_request.id, 7);
+    assert_eq!(context.commit_id, HEAD_COMMIT);
+    let paths: Vec<&str> = context
+        .changes
+        .iter()
+        .map(|change| change.path.to_string.as_str())
+        .collect();
+    assert_eq!(paths, ["services/billing/src/lib.rs", "services/billing/Cargo.toml"]);
+    assert_eq!(context.build_statuses.len(), 2);
+    assert!(context.is_green());
+
+    pull_request_mock.assert();
+    first_page_mock.assert();
+    second_page_mock.assert();
+    build_statuses_mock.assert();
+}
+
+#[tokio::test]
+async fn it_is_not_green_with_a_failed_build() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    mock_pull_request(&server);
+    mock_changes(&server, HEAD_COMMIT, 0, &["README.md"], None);
+    mock_build_statuses(&server, &["SUCCESSFUL", "FAILED"]);
+
+    let context = client
+        .api()
+        .pull_request_gate_context("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(context.changes.len(), 1);
+    assert!(!context.is_green());
+}
+
+#[tokio::test]
+async fn it_is_green_once_a_failed_build_is_rerun() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    mock_pull_request(&server);
+    mock_changes(&server, HEAD_COMMIT, 0, &["README.md"], None);
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/rest/build-status/latest/commits/{HEAD_COMMIT}"));
+        then.status(200).json_body(json!({
+            "size": 2,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": [
+                {"key": "build-0", "state": "FAILED", "url": "https://ci.example.com/build/0", "updatedDate": 1738198900000u64},
+                {"key": "build-0", "state": "SUCCESSFUL", "url": "https://ci.example.com/build/1", "updatedDate": 1738199000000u64}
+            ]
+        }));
+    });
+
+    let context = client
+        .api()
+        .pull_request_gate_context("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    // every status is kept, but only the latest one of each key is judged
+    assert_eq!(context.build_statuses.len(), 2);
+    assert!(context.is_green());
+}
+
+#[tokio::test]
+async fn it_propagates_the_first_error() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    mock_pull_request(&server);
+    mock_changes(&server, HEAD_COMMIT, 0, &["README.md"], None);
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/rest/build-status/latest/commits/{HEAD_COMMIT}"));
+        then.status(500).body("Internal Server Error");
+    });
+
+    let error = client
+        .api()
+        .pull_request_gate_context("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(error.is_response_error());
+}
+
+#[tokio::test]
+async fn it_reports_a_rescoped_pull_request() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    mock_pull_request(&server);
+    mock_changes(&server, "NEW_HEAD_COMMIT", 0, &["README.md"], None);
+    mock_build_statuses(&server, &["SUCCESSFUL"]);
+
+    let error = client
+        .api()
+        .pull_request_gate_context("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(error.to_string().contains("rescoped"));
+}
@@ -0,0 +1,207 @@
+mod common;
+
+use bitbucket_server_rs::api::common::RequestExt;
+use bitbucket_server_rs::client::{ApiRequest, Conditional};
+use httpmock::Method::GET;
+use serde_json::json;
+
+#[tokio::test]
+async fn it_can_get_pull_request_with_expansions() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7")
+            .query_param("avatarSize", "64")
+            .query_param("markup", "true")
+            .query_param("withProperties", "true");
+        then.status(200).json_body(json!({
+            "id": 7,
+            "version": 2,
+            "title": "Add new feature",
+            "description": "Implements **the** feature",
+            "descriptionAsHtml": "<p>Implements <strong>the</strong> feature</p>",
+            "state": "OPEN",
+            "open": true,
+            "closed": false,
+            "fromRef": {
+                "id": "refs/heads/feature",
+                "displayId": "feature",
+                "latestCommit": "8d51122def5632836d1cb1026e879069e10a1e13",
+                "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+            },
+            "toRef": {
+                "id": "refs/heads/main",
+                "displayId": "main",
+                "latestCommit": "0a943a29376f2336b78312d99e65da17048951db",
+                "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+            },
+            "author": {
+                "user": {
+                    "name": "jdoe",
+                    "avatarUrl": "https://bitbucket-server/users/jdoe/avatar.png?s=64",
+                    "links": {"self": [{"href": "https://bitbucket-server/users/jdoe"}]}
+                },
+                "role": "AUTHOR",
+                "approved": false,
+                "status": "UNAPPROVED"
+            },
+            "reviewers": [{
+                "user": {"name": "rroe", "avatarUrl": "https://bitbucket-server/users/rroe/avatar.png?s=64"},
+                "role": "REVIEWER",
+                "approved": true,
+                "status": "APPROVED"
+            }],
+            "participants": [],
+            "links": {"self": [{"href": "https://bitbucket-server/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7"}]}
+        }));
+    });
+
+    let pull_request = client
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .avatar_size(64)
+        .markup(true)
+        .with_properties(true)
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let author = pull_request.author.unwrap().user;
+    assert_eq!(
+        author.avatar_url,
+        Some("https://bitbucket-server/users/jdoe/avatar.png?s=64".to_string())
+    );
+    assert_eq!(
+        author.links.unwrap().self_links[0].href,
+        "https://bitbucket-server/users/jdoe"
+    );
+    assert_eq!(
+        pull_request.description_as_html,
+        Some("<p>Implements <strong>the</strong> feature</p>".to_string())
+    );
+    assert!(pull_request.reviewers[0].approved);
+    assert!(pull_request.links.is_some());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_omits_unset_expansions() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7")
+            .matches(|req| req.query_params.as_ref().is_none_or(|params| params.is_empty()));
+        then.status(200).json_body(json!({
+            "id": 7,
+            "version": 0,
+            "title": "Add new feature",
+            "state": "OPEN",
+            "open": true,
+            "closed": false,
+            "fromRef": {
+                "id": "refs/heads/feature",
+                "displayId": "feature",
+                "latestCommit": "8d51122def5632836d1cb1026e879069e10a1e13",
+                "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+            },
+            "toRef": {
+                "id": "refs/heads/main",
+                "displayId": "main",
+                "latestCommit": "0a943a29376f2336b78312d99e65da17048951db",
+                "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+            }
+        }));
+    });
+
+    let pull_request = client
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(pull_request.author.is_none());
+    assert!(pull_request.reviewers.is_empty());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_can_poll_pull_request_with_etag() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let unchanged = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7")
+            .header("If-None-Match", "\"v2\"");
+        then.status(304).header("ETag", "\"v2\"");
+    });
+    let changed = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7")
+            .header("If-None-Match", "\"v1\"");
+        then.status(200).header("ETag", "\"v2\"").json_body(json!({
+            "id": 7,
+            "version": 2,
+            "title": "Add new feature",
+            "state": "OPEN",
+            "open": true,
+            "closed": false,
+            "fromRef": {
+                "id": "refs/heads/feature",
+                "displayId": "feature",
+                "latestCommit": "8d51122def5632836d1cb1026e879069e10a1e13",
+                "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+            },
+            "toRef": {
+                "id": "refs/heads/main",
+                "displayId": "main",
+                "latestCommit": "0a943a29376f2336b78312d99e65da17048951db",
+                "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+            }
+        }));
+    });
+
+    let first = client
+        .clone()
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .if_none_match("\"v1\"")
+        .build()
+        .unwrap()
+        .send_conditional()
+        .await
+        .unwrap();
+
+    assert!(first.is_modified());
+    assert_eq!(first.etag(), Some("\"v2\""));
+
+    let second = client
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .if_none_match(first.etag().unwrap())
+        .build()
+        .unwrap()
+        .send_conditional()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        second,
+        Conditional::NotModified {
+            etag: "\"v2\"".to_string()
+        }
+    );
+    changed.assert();
+    unchanged.assert();
+}
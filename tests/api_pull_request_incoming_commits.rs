@@ -0,0 +1,153 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use httpmock::{Mock, MockServer};
+use serde_json::{json, Value};
+
+const PULL_REQUEST_PATH: &str =
+    "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7";
+const COMMITS_PATH: &str = "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits";
+const HEAD_COMMIT: &str = "8d51122def5632836d1cb1026e879069e10a1e13";
+const TARGET_COMMIT: &str = "0a943a29376f2336b78312d99e65da17048951db";
+const MERGE_BASE_COMMIT: &str = "d4f2c1a6be28d0a9c1c6e9b1b8cfa4e3f0d1e2a3";
+
+fn mock_pull_request<'a>(server: &'a MockServer, state: &str) -> Mock<'a> {
+    server.mock(|when, then| {
+        when.method(GET).path(PULL_REQUEST_PATH);
+        then.status(200).json_body(json!({
+            "id": 7,
+            "version": 3,
+            "title": "Add new feature",
+            "state": state,
+            "open": state == "OPEN",
+            "closed": state != "OPEN",
+            "fromRef": {
+                "id": "refs/heads/feature",
+                "displayId": "feature",
+                "latestCommit": HEAD_COMMIT,
+                "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+            },
+            "toRef": {
+                "id": "refs/heads/main",
+                "displayId": "main",
+                "latestCommit": TARGET_COMMIT,
+                "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+            }
+        }));
+    })
+}
+
+fn mock_merge_base(server: &MockServer) -> Mock<'_> {
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("{PULL_REQUEST_PATH}/merge-base"));
+        then.status(200).json_body(json!({
+            "id": MERGE_BASE_COMMIT,
+            "displayId": "d4f2c1a6be2"
+        }));
+    })
+}
+
+fn mock_commits<'a>(
+    server: &'a MockServer,
+    since: &str,
+    start: u64,
+    ids: &[&str],
+    next_page_start: Option<u64>,
+) -> Mock<'a> {
+    let values: Vec<Value> = ids
+        .iter()
+        .map(|id| json!({"id": id, "displayId": &id[..11], "message": format!("Commit {id}")}))
+        .collect();
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(COMMITS_PATH)
+            .query_param("since", since)
+            .query_param("until", HEAD_COMMIT)
+            .query_param("start", start.to_string());
+        then.status(200).json_body(json!({
+            "size": values.len(),
+            "limit": 2,
+            "isLastPage": next_page_start.is_none(),
+            "start": start,
+            "nextPageStart": next_page_start,
+            "values": values
+        }));
+    })
+}
+
+#[tokio::test]
+async fn it_lists_commits_since_the_merge_base() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let pull_request_mock = mock_pull_request(&server, "OPEN");
+    let merge_base_mock = mock_merge_base(&server);
+    let first_page_mock = mock_commits(
+        &server,
+        MERGE_BASE_COMMIT,
+        0,
+        &[HEAD_COMMIT, "5b9e0c4ad1f2e3c4b5a6978877665544332211ff"],
+        Some(2),
+    );
+    let second_page_mock = mock_commits(
+        &server,
+        MERGE_BASE_COMMIT,
+        2,
+        &["1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d"],
+        None,
+    );
+    // commits only on the target branch, since the merge base
+    let target_commits_mock = mock_commits(&server, HEAD_COMMIT, 0, &[TARGET_COMMIT], None);
+
+    let commits = client
+        .api()
+        .pull_request_incoming_commits("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let ids: Vec<&str> = commits.iter().map(|commit| commit.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        [
+            HEAD_COMMIT,
+            "5b9e0c4ad1f2e3c4b5a6978877665544332211ff",
+            "1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d"
+        ]
+    );
+    assert!(!ids.contains(&TARGET_COMMIT));
+
+    pull_request_mock.assert();
+    merge_base_mock.assert();
+    first_page_mock.assert();
+    second_page_mock.assert();
+    target_commits_mock.assert_hits(0);
+}
+
+#[tokio::test]
+async fn it_lists_commits_of_a_merged_pull_request_since_its_target() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    mock_pull_request(&server, "MERGED");
+    let merge_base_mock = mock_merge_base(&server);
+    let commits_mock = mock_commits(&server, TARGET_COMMIT, 0, &[HEAD_COMMIT], None);
+
+    let commits = client
+        .api()
+        .pull_request_incoming_commits("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(commits.len(), 1);
+    assert_eq!(commits[0].id, HEAD_COMMIT);
+
+    merge_base_mock.assert_hits(0);
+    commits_mock.assert();
+}
@@ -0,0 +1,32 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use serde_json::json;
+
+#[tokio::test]
+async fn it_can_get_linked_jira_issues() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/jira/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7/issues");
+        then.status(200).json_body(json!([
+            {"key": "PRJ-1", "url": "https://jira.example.com/browse/PRJ-1"},
+            {"key": "PRJ-2", "url": "https://jira.example.com/browse/PRJ-2"}
+        ]));
+    });
+
+    let issues = client
+        .api()
+        .pull_request_jira_issues("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let keys: Vec<&str> = issues.iter().map(|issue| issue.key.as_str()).collect();
+    assert_eq!(keys, vec!["PRJ-1", "PRJ-2"]);
+    mock.assert();
+}
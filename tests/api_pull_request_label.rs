@@ -0,0 +1,143 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::{DELETE, GET, POST};
+use serde_json::json;
+
+#[tokio::test]
+async fn it_can_add_label() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/labels")
+            .json_body(json!({"name": "dependencies"}));
+        then.status(200).json_body(json!({"name": "dependencies"}));
+    });
+
+    let label = client
+        .api()
+        .pull_request_label_post("PROJECT_KEY", "REPOSITORY_SLUG", "1", "dependencies")
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(label.name, "dependencies");
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_can_list_labels() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/labels");
+        then.status(200).json_body(json!({
+            "size": 2,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": [{"name": "dependencies"}, {"name": "needs-review"}]
+        }));
+    });
+
+    let page = client
+        .api()
+        .pull_request_label_list("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let names: Vec<&str> = page.values.iter().map(|label| label.name.as_str()).collect();
+    assert_eq!(names, vec!["dependencies", "needs-review"]);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_can_remove_label() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(DELETE)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/labels/needs-review")
+            .matches(|req| {
+                req.body.as_ref().is_none_or(|body| body.is_empty())
+                    && req.headers.as_ref().is_none_or(|headers| {
+                        !headers
+                            .iter()
+                            .any(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+                    })
+            });
+        then.status(204);
+    });
+
+    let response = client
+        .api()
+        .pull_request_label_delete("PROJECT_KEY", "REPOSITORY_SLUG", "1", "needs-review")
+        .send()
+        .await;
+
+    assert!(response.is_ok());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_reports_unsupported_labels() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/labels");
+        then.status(404).body("<html><body>Not Found</body></html>");
+    });
+
+    let error = client
+        .api()
+        .pull_request_label_list("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(error.is_unsupported());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_reports_missing_pull_requests_as_not_found() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(DELETE)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/labels/needs-review");
+        then.status(404).json_body(json!({
+            "errors": [{
+                "context": null,
+                "message": "Pull request 1 does not exist in PROJECT_KEY/REPOSITORY_SLUG.",
+                "exceptionName": "com.atlassian.bitbucket.pull.NoSuchPullRequestException"
+            }]
+        }));
+    });
+
+    let error = client
+        .api()
+        .pull_request_label_delete("PROJECT_KEY", "REPOSITORY_SLUG", "1", "needs-review")
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(!error.is_unsupported());
+    assert_eq!(error.status(), Some(404));
+    mock.assert();
+}
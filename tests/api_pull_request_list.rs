@@ -0,0 +1,106 @@
+mod common;
+
+use bitbucket_server_rs::api::common::RequestExt;
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+
+#[tokio::test]
+async fn it_can_list_pull_requests() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests")
+            .matches(|req| {
+                req.query_params.as_ref().is_none_or(|params| {
+                    !params.iter().any(|(name, _)| name == "withProperties")
+                })
+            });
+        then.status(200).body(TEST_RESPONSE);
+    });
+
+    let response = client
+        .api()
+        .pull_request_list("PROJECT_KEY", "REPOSITORY_SLUG")
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    let page = response.unwrap().unwrap();
+    assert_eq!(page.values.len(), 1);
+    assert_eq!(page.values[0].id, 101);
+    assert_eq!(page.values[0].from_ref.latest_commit, "FROM_COMMIT_ID");
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_can_list_pull_requests_with_params() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests")
+            .query_param("direction", "OUTGOING")
+            .query_param("at", "refs/heads/feature-branch")
+            .query_param("state", "OPEN")
+            .query_param("order", "OLDEST")
+            .query_param("start", "0")
+            .query_param("limit", "10")
+            .query_param("withAttributes", "false")
+            .query_param("withProperties", "false");
+        then.status(200).body(TEST_RESPONSE);
+    });
+
+    let response = client
+        .api()
+        .pull_request_list("PROJECT_KEY", "REPOSITORY_SLUG")
+        .direction("OUTGOING")
+        .at("refs/heads/feature-branch")
+        .state("OPEN")
+        .order("OLDEST")
+        .start(0u32)
+        .limit(10u32)
+        .with_properties(false)
+        .with_attributes(false)
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    assert!(response.is_ok());
+    mock.assert();
+}
+
+static TEST_RESPONSE: &str = r#"{
+  "size": 1,
+  "limit": 25,
+  "isLastPage": true,
+  "start": 0,
+  "values": [
+    {
+      "id": 101,
+      "version": 2,
+      "title": "Add new feature",
+      "state": "OPEN",
+      "open": true,
+      "closed": false,
+      "createdDate": 1738198923000,
+      "updatedDate": 1738198924000,
+      "fromRef": {
+        "id": "refs/heads/feature-branch",
+        "displayId": "feature-branch",
+        "latestCommit": "FROM_COMMIT_ID",
+        "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+      },
+      "toRef": {
+        "id": "refs/heads/main",
+        "displayId": "main",
+        "latestCommit": "TO_COMMIT_ID",
+        "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+      }
+    }
+  ]
+}"#;
@@ -0,0 +1,90 @@
+mod common;
+
+use bitbucket_server_rs::api::pull_request_merge::MergeOutcome;
+use bitbucket_server_rs::api::pull_request_settings::MergeStrategyId;
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::POST;
+use serde_json::{json, Value};
+
+fn pull_request_json(state: &str) -> Value {
+    json!({
+        "id": 7,
+        "version": 4,
+        "title": "Add new feature",
+        "state": state,
+        "open": state == "OPEN",
+        "closed": state != "OPEN",
+        "fromRef": {
+            "id": "refs/heads/feature",
+            "displayId": "feature",
+            "latestCommit": "8d51122def5632836d1cb1026e879069e10a1e13",
+            "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+        },
+        "toRef": {
+            "id": "refs/heads/main",
+            "displayId": "main",
+            "latestCommit": "0a943a29376f2336b78312d99e65da17048951db",
+            "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+        }
+    })
+}
+
+#[tokio::test]
+async fn it_can_merge_pull_request() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7/merge")
+            .query_param("version", "3")
+            .json_body(json!({"strategyId": "squash"}));
+        then.status(200).json_body(pull_request_json("MERGED"));
+    });
+
+    let outcome = client
+        .api()
+        .pull_request_merge("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .version(3)
+        .strategy_id(MergeStrategyId::Squash)
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(outcome.is_merged());
+    assert_eq!(outcome.pull_request().version, 4);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_can_schedule_auto_merge() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7/merge")
+            .query_param("version", "3")
+            .json_body(json!({"autoMerge": true}));
+        then.status(200).json_body(pull_request_json("OPEN"));
+    });
+
+    let outcome = client
+        .api()
+        .pull_request_merge("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .version(3)
+        .auto_merge(true)
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(matches!(outcome, MergeOutcome::AutoMergeScheduled(_)));
+    assert!(outcome.pull_request().open);
+    mock.assert();
+}
@@ -0,0 +1,36 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use serde_json::json;
+
+#[tokio::test]
+async fn it_can_get_pull_request_merge_base() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7/merge-base");
+        then.status(200).json_body(json!({
+            "id": "8d51122def5632836d1cb1026e879069e10a1e13",
+            "displayId": "8d51122def5",
+            "message": "Branch off main",
+            "parents": [
+                {"id": "1f2a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c", "displayId": "1f2a3b4c5d6"}
+            ]
+        }));
+    });
+
+    let merge_base = client
+        .api()
+        .pull_request_merge_base("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(merge_base.id, "8d51122def5632836d1cb1026e879069e10a1e13");
+    assert_eq!(merge_base.parents.len(), 1);
+    mock.assert();
+}
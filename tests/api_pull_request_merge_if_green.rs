@@ -0,0 +1,252 @@
+mod common;
+
+use bitbucket_server_rs::api::build_status::BuildStatusState;
+use bitbucket_server_rs::api::pull_request_merge_if_green::MergeIfGreenOutcome;
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::{GET, POST};
+use httpmock::{Mock, MockServer};
+use serde_json::{json, Value};
+
+const PULL_REQUEST_PATH: &str =
+    "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7";
+const HEAD_COMMIT: &str = "8d51122def5632836d1cb1026e879069e10a1e13";
+
+fn pull_request(state: &str) -> Value {
+    json!({
+        "id": 7,
+        "version": 3,
+        "title": "Add new feature",
+        "state": state,
+        "open": state == "OPEN",
+        "closed": state != "OPEN",
+        "fromRef": {
+            "id": "refs/heads/feature",
+            "displayId": "feature",
+            "latestCommit": HEAD_COMMIT,
+            "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+        },
+        "toRef": {
+            "id": "refs/heads/main",
+            "displayId": "main",
+            "latestCommit": "0a943a29376f2336b78312d99e65da17048951db",
+            "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+        }
+    })
+}
+
+fn mock_merge_status(server: &MockServer, can_merge: bool, vetoes: Value) -> Mock<'_> {
+    server.mock(|when, then| {
+        when.method(GET).path(format!("{PULL_REQUEST_PATH}/merge"));
+        then.status(200).json_body(json!({
+            "canMerge": can_merge,
+            "conflicted": false,
+            "outcome": "CLEAN",
+            "vetoes": vetoes
+        }));
+    })
+}
+
+fn mock_pull_request(server: &MockServer) -> Mock<'_> {
+    server.mock(|when, then| {
+        when.method(GET).path(PULL_REQUEST_PATH);
+        then.status(200).json_body(pull_request("OPEN"));
+    })
+}
+
+fn mock_build_statuses<'a>(server: &'a MockServer, states: &[&str]) -> Mock<'a> {
+    let values: Vec<Value> = states
+        .iter()
+        .enumerate()
+        .map(|(i, state)| {
+            json!({
+                "key": format!("build-{i}"),
+                "state": state,
+                "url": format!("https://ci.example.com/build/{i}")
+            })
+        })
+        .collect();
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/rest/build-status/latest/commits/{HEAD_COMMIT}"));
+        then.status(200).json_body(json!({
+            "size": values.len(),
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": values
+        }));
+    })
+}
+
+#[tokio::test]
+async fn it_merges_green_pull_requests() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let merge_status_mock = mock_merge_status(&server, true, json!([]));
+    let pull_request_mock = mock_pull_request(&server);
+    let build_status_mock = mock_build_statuses(&server, &["SUCCESSFUL", "SUCCESSFUL"]);
+    let merge_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("{PULL_REQUEST_PATH}/merge"))
+            .query_param("version", "3");
+        then.status(200).json_body(pull_request("MERGED"));
+    });
+
+    let outcome = client
+        .api()
+        .pull_request_merge_if_green("PROJECT_KEY", "REPOSITORY_SLUG", "7", 3)
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(outcome.is_merged());
+    merge_status_mock.assert();
+    pull_request_mock.assert();
+    build_status_mock.assert();
+    merge_mock.assert();
+}
+
+#[tokio::test]
+async fn it_does_not_merge_vetoed_pull_requests() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let merge_status_mock = mock_merge_status(
+        &server,
+        false,
+        json!([{"summaryMessage": "Not enough approvals"}]),
+    );
+    let merge_mock = server.mock(|when, then| {
+        when.method(POST).path(format!("{PULL_REQUEST_PATH}/merge"));
+        then.status(200).json_body(pull_request("MERGED"));
+    });
+
+    let outcome = client
+        .api()
+        .pull_request_merge_if_green("PROJECT_KEY", "REPOSITORY_SLUG", "7", 3)
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    match outcome {
+        MergeIfGreenOutcome::BlockedByVeto(merge_status) => {
+            assert_eq!(merge_status.vetoes[0].summary_message, "Not enough approvals");
+        }
+        other => panic!("Expected a veto, got {:?}", other),
+    }
+    merge_status_mock.assert();
+    merge_mock.assert_hits(0);
+}
+
+#[tokio::test]
+async fn it_does_not_merge_pull_requests_with_failed_builds() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let merge_status_mock = mock_merge_status(&server, true, json!([]));
+    let pull_request_mock = mock_pull_request(&server);
+    let build_status_mock = mock_build_statuses(&server, &["SUCCESSFUL", "FAILED", "INPROGRESS"]);
+    let merge_mock = server.mock(|when, then| {
+        when.method(POST).path(format!("{PULL_REQUEST_PATH}/merge"));
+        then.status(200).json_body(pull_request("MERGED"));
+    });
+
+    let outcome = client
+        .api()
+        .pull_request_merge_if_green("PROJECT_KEY", "REPOSITORY_SLUG", "7", 3)
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    match outcome {
+        MergeIfGreenOutcome::BlockedByBuild {
+            commit_id,
+            build_statuses,
+        } => {
+            assert_eq!(commit_id, HEAD_COMMIT);
+            let states: Vec<BuildStatusState> = build_statuses
+                .into_iter()
+                .map(|build_status| build_status.state)
+                .collect();
+            assert_eq!(
+                states,
+                vec![BuildStatusState::Failed, BuildStatusState::InProgress]
+            );
+        }
+        other => panic!("Expected blocked builds, got {:?}", other),
+    }
+    merge_status_mock.assert();
+    pull_request_mock.assert();
+    build_status_mock.assert();
+    merge_mock.assert_hits(0);
+}
+
+#[tokio::test]
+async fn it_merges_pull_requests_whose_failed_builds_were_rerun() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    mock_merge_status(&server, true, json!([]));
+    mock_pull_request(&server);
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/rest/build-status/latest/commits/{HEAD_COMMIT}"));
+        then.status(200).json_body(json!({
+            "size": 3,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": [
+                {"key": "build-0", "state": "SUCCESSFUL", "url": "https://ci.example.com/build/0", "updatedDate": 1738199000000u64},
+                {"key": "build-1", "state": "FAILED", "url": "https://ci.example.com/build/1", "updatedDate": 1738198900000u64},
+                {"key": "build-1", "state": "SUCCESSFUL", "url": "https://ci.example.com/build/2", "updatedDate": 1738199100000u64}
+            ]
+        }));
+    });
+    let merge_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("{PULL_REQUEST_PATH}/merge"))
+            .query_param("version", "3");
+        then.status(200).json_body(pull_request("MERGED"));
+    });
+
+    let outcome = client
+        .api()
+        .pull_request_merge_if_green("PROJECT_KEY", "REPOSITORY_SLUG", "7", 3)
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    // the failed status of build-1 is superseded by its rerun
+    assert!(outcome.is_merged());
+    merge_mock.assert();
+}
+
+#[tokio::test]
+async fn it_does_not_merge_pull_requests_without_builds() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    mock_merge_status(&server, true, json!([]));
+    mock_pull_request(&server);
+    mock_build_statuses(&server, &[]);
+
+    let outcome = client
+        .api()
+        .pull_request_merge_if_green("PROJECT_KEY", "REPOSITORY_SLUG", "7", 3)
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(matches!(
+        outcome,
+        MergeIfGreenOutcome::BlockedByBuild { build_statuses, .. } if build_statuses.is_empty()
+    ));
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use bitbucket_server_rs::api::pull_request_participant::ParticipantStatus;
+use bitbucket_server_rs::client::ApiRequest;
+use bitbucket_server_rs::Error;
+use httpmock::Method::PUT;
+use serde_json::json;
+
+const PARTICIPANT_PATH: &str =
+    "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7/participants/jdoe";
+
+#[tokio::test]
+async fn it_can_approve_pull_request() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(PUT).path(PARTICIPANT_PATH).json_body(json!({
+            "status": "APPROVED",
+            "lastReviewedCommit": "7549846524f8aed2bd1c0249993ae1bf9d3c9998"
+        }));
+        then.status(200).json_body(json!({
+            "user": {"name": "jdoe", "slug": "jdoe"},
+            "lastReviewedCommit": "7549846524f8aed2bd1c0249993ae1bf9d3c9998",
+            "role": "REVIEWER",
+            "approved": true,
+            "status": "APPROVED"
+        }));
+    });
+
+    let participant = client
+        .api()
+        .pull_request_participant_status_put("PROJECT_KEY", "REPOSITORY_SLUG", "7", "jdoe")
+        .status(ParticipantStatus::Approved)
+        .last_reviewed_commit("7549846524f8aed2bd1c0249993ae1bf9d3c9998")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(participant.approved);
+    assert_eq!(participant.status, ParticipantStatus::Approved);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_reports_stale_review() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(PUT).path(PARTICIPANT_PATH);
+        then.status(409).json_body(json!({
+            "errors": [{
+                "context": null,
+                "message": "The pull request has been updated since it was last reviewed.",
+                "exceptionName": "com.atlassian.bitbucket.pull.InvalidPullRequestReviewerStatusException"
+            }]
+        }));
+    });
+
+    let response = client
+        .api()
+        .pull_request_participant_status_put("PROJECT_KEY", "REPOSITORY_SLUG", "7", "jdoe")
+        .status(ParticipantStatus::Approved)
+        .last_reviewed_commit("0a943a29376f2336b78312d99e65da17048951db")
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    match response {
+        Err(Error::StaleReview {
+            last_reviewed_commit,
+        }) => assert_eq!(
+            last_reviewed_commit,
+            "0a943a29376f2336b78312d99e65da17048951db"
+        ),
+        other => panic!("Expected a stale review error, got {:?}", other),
+    }
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_keeps_conflicts_without_last_reviewed_commit() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(PUT).path(PARTICIPANT_PATH);
+        then.status(409).body("Conflict");
+    });
+
+    let error = client
+        .api()
+        .pull_request_participant_status_put("PROJECT_KEY", "REPOSITORY_SLUG", "7", "jdoe")
+        .status(ParticipantStatus::Approved)
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap_err();
+
+    // without a commit to report, the conflict is not a stale review
+    assert!(matches!(
+        error,
+        Error::HttpClientError { status: 409, ref body } if body == "Conflict"
+    ));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_keeps_conflicts_that_are_not_stale_reviews() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(PUT).path(PARTICIPANT_PATH);
+        then.status(409).json_body(json!({
+            "errors": [{
+                "context": null,
+                "message": "The pull request is declined, so its review status can't be changed.",
+                "exceptionName": "com.atlassian.bitbucket.pull.IllegalPullRequestStateException"
+            }]
+        }));
+    });
+
+    let error = client
+        .api()
+        .pull_request_participant_status_put("PROJECT_KEY", "REPOSITORY_SLUG", "7", "jdoe")
+        .status(ParticipantStatus::Approved)
+        .last_reviewed_commit("0a943a29376f2336b78312d99e65da17048951db")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap_err();
+
+    // a commit was sent, but the conflict is about the state of the pull request
+    assert!(matches!(error, Error::HttpClientError { status: 409, .. }));
+    assert_eq!(
+        error.server_errors()[0].exception_simple_name(),
+        Some("IllegalPullRequestStateException")
+    );
+    mock.assert();
+}
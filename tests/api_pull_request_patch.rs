@@ -0,0 +1,49 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+
+#[tokio::test]
+async fn it_can_get_pull_request_patch() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/PULL_REQUEST_ID.patch")
+            .header("Accept", "text/plain");
+        then.status(200).body(TEST_PATCH);
+    });
+
+    let response = client
+        .api()
+        .pull_request_patch("PROJECT_KEY", "REPOSITORY_SLUG", "PULL_REQUEST_ID")
+        .send()
+        .await;
+
+    // the patch must be returned verbatim
+    assert_eq!(response.unwrap(), Some(TEST_PATCH.to_string()));
+    mock.assert();
+}
+
+static TEST_PATCH: &str = r#"From 8d51122def5632836d1cb1026e879069e10a1e13 Mon Sep 17 00:00:00 2001
+From: Jane Citizen <jane@example.com>
+Date: Thu, 30 Jan 2025 01:02:03 +0000
+Subject: [PATCH] Add a greeting
+
+---
+ hello.txt | 1 +
+ 1 file changed, 1 insertion(+)
+ create mode 100644 hello.txt
+
+diff --git a/hello.txt b/hello.txt
+new file mode 100644
+index 0000000..ce01362
+--- /dev/null
++++ b/hello.txt
+@@ -0,0 +1 @@
++hello
+--
+2.39.5
+
+"#;
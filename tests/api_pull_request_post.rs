@@ -103,6 +103,7 @@ async fn it_can_create_pull_request() {
                 reviewers: Some(vec![Reviewer {
                     user: User {
                         name: "reviewer1".to_string(),
+                        ..Default::default()
                     },
                 }]),
             },
@@ -200,3 +201,78 @@ async fn it_can_create_pull_request_without_optional_fields() {
     assert!(result.is_ok());
     mock.assert();
 }
+
+#[tokio::test]
+async fn it_can_create_pull_request_with_headers() {
+    common::setup();
+
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests");
+        then.status(201)
+            .header(
+                "Location",
+                "https://bitbucket-server/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1",
+            )
+            .json_body(json!({
+                "title": "Add new feature",
+                "fromRef": {
+                    "id": "refs/heads/feature-branch",
+                    "repository": {
+                        "slug": "my-repo",
+                        "project": {
+                            "key": "PROJECT_KEY"
+                        }
+                    }
+                },
+                "toRef": {
+                    "id": "refs/heads/main",
+                    "repository": {
+                        "slug": "my-repo",
+                        "project": {
+                            "key": "PROJECT_KEY"
+                        }
+                    }
+                }
+            }));
+    });
+
+    let repository_info = RepositoryInfo {
+        slug: "my-repo".to_string(),
+        project: ProjectInfo {
+            key: "PROJECT_KEY".to_string(),
+        },
+    };
+
+    let (pull_request, headers) = client
+        .api()
+        .pull_request_post(
+            "PROJECT_KEY",
+            "REPOSITORY_SLUG",
+            &PullRequestPostPayload {
+                title: "Add new feature".to_string(),
+                description: None,
+                from_ref: RefInfo {
+                    id: "refs/heads/feature-branch".to_string(),
+                    repository: repository_info.clone(),
+                },
+                to_ref: RefInfo {
+                    id: "refs/heads/main".to_string(),
+                    repository: repository_info,
+                },
+                reviewers: None,
+            },
+        )
+        .send_with_headers()
+        .await
+        .unwrap();
+
+    assert_eq!(pull_request.unwrap().title, "Add new feature");
+    assert_eq!(
+        headers.get("Location").unwrap(),
+        "https://bitbucket-server/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1"
+    );
+    mock.assert();
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use serde_json::json;
+
+const HEAD_COMMIT: &str = "8d51122def5632836d1cb1026e879069e10a1e13";
+
+#[tokio::test]
+async fn it_detects_rescoped_pull_request() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7");
+        then.status(200).json_body(pull_request_json(HEAD_COMMIT));
+    });
+
+    let response = client
+        .api()
+        .pull_request_rescoped(
+            "PROJECT_KEY",
+            "REPOSITORY_SLUG",
+            "7",
+            "0a943a29376f2336b78312d99e65da17048951db",
+        )
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(response.rescoped);
+    assert_eq!(response.latest_commit, HEAD_COMMIT);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_reports_unchanged_pull_request() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7");
+        then.status(200).json_body(pull_request_json(HEAD_COMMIT));
+    });
+
+    let response = client
+        .api()
+        .pull_request_rescoped("PROJECT_KEY", "REPOSITORY_SLUG", "7", HEAD_COMMIT)
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(!response.rescoped);
+    assert_eq!(response.latest_commit, HEAD_COMMIT);
+    mock.assert();
+}
+
+fn pull_request_json(latest_commit: &str) -> serde_json::Value {
+    json!({
+        "id": 7,
+        "version": 1,
+        "title": "Add new feature",
+        "state": "OPEN",
+        "open": true,
+        "closed": false,
+        "fromRef": {
+            "id": "refs/heads/feature",
+            "displayId": "feature",
+            "latestCommit": latest_commit,
+            "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+        },
+        "toRef": {
+            "id": "refs/heads/main",
+            "displayId": "main",
+            "latestCommit": "c8b3a1e4d0f2b7a9e6c5d4f3a2b1c0d9e8f7a6b5",
+            "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+        }
+    })
+}
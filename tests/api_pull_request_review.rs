@@ -0,0 +1,98 @@
+mod common;
+
+use bitbucket_server_rs::api::pull_request_comment::LineType;
+use bitbucket_server_rs::api::pull_request_comment_post::CommentPostPayload;
+use httpmock::Method::POST;
+use serde_json::json;
+
+const COMMENTS_PATH: &str =
+    "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7/comments";
+
+#[tokio::test]
+async fn it_posts_inline_comments_and_reports_failures() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let first = server.mock(|when, then| {
+        when.method(POST).path(COMMENTS_PATH).json_body(json!({
+            "text": "Needs a test",
+            "anchor": {"path": "src/lib.rs", "line": 10, "lineType": "ADDED", "fileType": "TO"}
+        }));
+        then.status(201).json_body(json!({
+            "id": 1,
+            "version": 0,
+            "text": "Needs a test",
+            "anchor": {"path": "src/lib.rs", "line": 10, "lineType": "ADDED", "fileType": "TO"}
+        }));
+    });
+    let second = server.mock(|when, then| {
+        when.method(POST).path(COMMENTS_PATH).json_body(json!({
+            "text": "Typo",
+            "anchor": {"path": "src/gone.rs", "line": 4, "lineType": "CONTEXT", "fileType": "TO"}
+        }));
+        then.status(409).json_body(json!({
+            "errors": [{"message": "The file src/gone.rs is not part of the pull request."}]
+        }));
+    });
+    let third = server.mock(|when, then| {
+        when.method(POST).path(COMMENTS_PATH).json_body(json!({
+            "text": "Why remove this?",
+            "anchor": {"path": "src/main.rs", "line": 2, "lineType": "REMOVED", "fileType": "FROM"}
+        }));
+        then.status(201).json_body(json!({"id": 3, "version": 0, "text": "Why remove this?"}));
+    });
+
+    let outcome = client
+        .api()
+        .review("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .comment(CommentPostPayload::inline("src/lib.rs", 10, LineType::Added, "Needs a test"))
+        .comment(CommentPostPayload::inline("src/gone.rs", 4, LineType::Context, "Typo"))
+        .comment(CommentPostPayload::inline("src/main.rs", 2, LineType::Removed, "Why remove this?"))
+        .concurrency(2usize)
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    assert!(!outcome.is_success());
+    assert_eq!(outcome.results.len(), 3);
+
+    let posted: Vec<u64> = outcome.posted().iter().map(|comment| comment.id).collect();
+    assert_eq!(posted, [1, 3]);
+    assert_eq!(
+        outcome.posted()[0].anchor.as_ref().unwrap().path,
+        "src/lib.rs"
+    );
+
+    let failures = outcome.failures();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].0, 1);
+    assert!(failures[0].1.to_string().contains("409"));
+
+    first.assert();
+    second.assert();
+    third.assert();
+}
+
+#[tokio::test]
+async fn it_posts_nothing_for_empty_review() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST).path(COMMENTS_PATH);
+        then.status(201);
+    });
+
+    let outcome = client
+        .api()
+        .review("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    assert!(outcome.is_success());
+    assert!(outcome.results.is_empty());
+    mock.assert_hits(0);
+}
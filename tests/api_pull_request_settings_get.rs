@@ -0,0 +1,89 @@
+mod common;
+
+use bitbucket_server_rs::api::pull_request_settings::MergeStrategyId;
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+
+#[tokio::test]
+async fn it_can_get_pull_request_settings() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(
+            "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/settings/pull-requests",
+        );
+        then.status(200).body(
+            r#"{
+                "mergeConfig": {
+                    "defaultStrategy": {"id": "no-ff", "name": "Merge commit", "enabled": true, "flag": "--no-ff"},
+                    "strategies": [
+                        {"id": "no-ff", "name": "Merge commit", "enabled": true, "flag": "--no-ff"},
+                        {"id": "rebase-no-ff", "name": "Rebase, merge", "enabled": true, "flag": "rebase + merge --no-ff"},
+                        {"id": "squash-ff-only", "name": "Squash, fast-forward only", "enabled": false, "flag": "--squash --ff-only"}
+                    ],
+                    "type": "DEFAULT"
+                },
+                "requiredApprovers": 1
+            }"#,
+        );
+    });
+
+    let settings = client
+        .api()
+        .pull_request_settings_get("PROJECT_KEY", "REPOSITORY_SLUG")
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let merge_config = settings.merge_config.unwrap();
+    assert_eq!(
+        merge_config.allowed_strategies(),
+        vec![
+            &MergeStrategyId::NoFastForward,
+            &MergeStrategyId::RebaseNoFastForward
+        ]
+    );
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_reads_unknown_merge_strategies() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(
+            "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/settings/pull-requests",
+        );
+        then.status(200).body(
+            r#"{
+                "mergeConfig": {
+                    "defaultStrategy": {"id": "squash", "name": "Squash", "enabled": true},
+                    "strategies": [
+                        {"id": "squash", "name": "Squash", "enabled": true},
+                        {"id": "rebase-squash", "name": "Rebase, squash", "enabled": true}
+                    ],
+                    "type": "REPOSITORY"
+                }
+            }"#,
+        );
+    });
+
+    let settings = client
+        .api()
+        .pull_request_settings_get("PROJECT_KEY", "REPOSITORY_SLUG")
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    // a strategy added by a newer server doesn't fail the whole response
+    let merge_config = settings.merge_config.unwrap();
+    assert_eq!(
+        merge_config.allowed_strategies(),
+        vec![&MergeStrategyId::Squash, &MergeStrategyId::Unknown]
+    );
+    mock.assert();
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use bitbucket_server_rs::api::pull_request_settings::{
+    MergeConfig, MergeStrategy, MergeStrategyId, PullRequestSettings,
+};
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::POST;
+use serde_json::json;
+
+#[tokio::test]
+async fn it_can_update_pull_request_settings() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/settings/pull-requests")
+            .json_body(json!({
+                "mergeConfig": {
+                    "defaultStrategy": {"id": "squash", "enabled": true},
+                    "strategies": [{"id": "squash", "enabled": true}]
+                },
+                "requiredApprovers": 2,
+                "requiredSuccessfulBuilds": 1
+            }));
+        then.status(200).json_body(json!({
+            "mergeConfig": {
+                "defaultStrategy": {"id": "squash", "name": "Squash", "enabled": true, "flag": "--squash"},
+                "strategies": [{"id": "squash", "name": "Squash", "enabled": true, "flag": "--squash"}],
+                "type": "REPOSITORY"
+            },
+            "requiredApprovers": 2,
+            "requiredAllApprovers": false,
+            "requiredAllTasksComplete": false,
+            "requiredSuccessfulBuilds": 1,
+            "unapproveOnUpdate": false
+        }));
+    });
+
+    let squash = MergeStrategy {
+        id: MergeStrategyId::Squash,
+        enabled: true,
+        ..Default::default()
+    };
+    let settings = PullRequestSettings {
+        merge_config: Some(MergeConfig {
+            default_strategy: squash.clone(),
+            strategies: vec![squash],
+            ..Default::default()
+        }),
+        required_approvers: Some(2),
+        required_successful_builds: Some(1),
+        ..Default::default()
+    };
+
+    let updated = client
+        .api()
+        .pull_request_settings_update("PROJECT_KEY", "REPOSITORY_SLUG", &settings)
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(updated.required_approvers, Some(2));
+    assert_eq!(updated.required_all_approvers, Some(false));
+    assert_eq!(
+        updated.merge_config.unwrap().default_strategy.id,
+        MergeStrategyId::Squash
+    );
+    mock.assert();
+}
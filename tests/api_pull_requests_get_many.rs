@@ -0,0 +1,95 @@
+mod common;
+
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use serde_json::json;
+use std::time::Duration;
+
+#[tokio::test]
+async fn it_preserves_the_order_of_ids() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    // the first pull request is the slowest, so it completes last
+    let mocks =
+        [(1, 300), (2, 100), (3, 0)].map(|(id, delay)| mock_pull_request(&server, id, delay));
+
+    let pull_requests = client
+        .api()
+        .pull_requests_get_many("PROJECT_KEY", "REPOSITORY_SLUG", &["1", "2", "3"])
+        .concurrency(3usize)
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    let ids: Vec<u64> = pull_requests
+        .into_iter()
+        .map(|pull_request| pull_request.unwrap().id)
+        .collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+    for mock in mocks {
+        mock.assert();
+    }
+}
+
+#[tokio::test]
+async fn it_returns_other_pull_requests_when_one_fails() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let first_mock = mock_pull_request(&server, 1, 0);
+    let missing_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/2");
+        then.status(404)
+            .body(r#"{"errors":[{"message":"Pull request 2 does not exist."}]}"#);
+    });
+    let third_mock = mock_pull_request(&server, 3, 0);
+
+    let pull_requests = client
+        .api()
+        .pull_requests_get_many("PROJECT_KEY", "REPOSITORY_SLUG", &["1", "2", "3"])
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    assert_eq!(pull_requests.len(), 3);
+    assert_eq!(pull_requests[0].as_ref().unwrap().id, 1);
+    assert!(pull_requests[1].as_ref().unwrap_err().is_response_error());
+    assert_eq!(pull_requests[2].as_ref().unwrap().id, 3);
+    first_mock.assert();
+    missing_mock.assert();
+    third_mock.assert();
+}
+
+fn mock_pull_request(server: &MockServer, id: u64, delay_ms: u64) -> httpmock::Mock<'_> {
+    server.mock(|when, then| {
+        when.method(GET).path(format!(
+            "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/{id}"
+        ));
+        then.status(200)
+            .delay(Duration::from_millis(delay_ms))
+            .json_body(json!({
+                "id": id,
+                "version": 0,
+                "title": format!("Pull request {id}"),
+                "state": "OPEN",
+                "open": true,
+                "closed": false,
+                "fromRef": {
+                    "id": "refs/heads/feature",
+                    "displayId": "feature",
+                    "latestCommit": "8d51122def5632836d1cb1026e879069e10a1e13",
+                    "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+                },
+                "toRef": {
+                    "id": "refs/heads/main",
+                    "displayId": "main",
+                    "latestCommit": "0a943a29376f2336b78312d99e65da17048951db",
+                    "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+                }
+            }));
+    })
+}
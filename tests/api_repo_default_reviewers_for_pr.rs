@@ -0,0 +1,71 @@
+mod common;
+
+use bitbucket_server_rs::api::pull_request_post::{ProjectInfo, RefInfo, RepositoryInfo};
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::GET;
+use serde_json::json;
+
+#[tokio::test]
+async fn it_fills_payload_with_default_reviewers() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let repository_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG");
+        then.status(200).json_body(json!({
+            "slug": "REPOSITORY_SLUG",
+            "id": 11,
+            "name": "Repository",
+            "project": {"key": "PROJECT_KEY", "id": 1}
+        }));
+    });
+    let reviewers_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/default-reviewers/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/reviewers")
+            .query_param("sourceRepoId", "11")
+            .query_param("targetRepoId", "11")
+            .query_param("sourceRefId", "refs/heads/feature")
+            .query_param("targetRefId", "refs/heads/main");
+        then.status(200).json_body(json!([
+            {"name": "jdoe", "id": 101, "slug": "jdoe", "displayName": "Jane Doe", "active": true},
+            {"name": "rroe", "id": 102, "slug": "rroe", "displayName": "Richard Roe", "active": true}
+        ]));
+    });
+
+    let repository = RepositoryInfo {
+        slug: "REPOSITORY_SLUG".to_string(),
+        project: ProjectInfo {
+            key: "PROJECT_KEY".to_string(),
+        },
+    };
+    let from_ref = RefInfo {
+        id: "refs/heads/feature".to_string(),
+        repository: repository.clone(),
+    };
+    let to_ref = RefInfo {
+        id: "refs/heads/main".to_string(),
+        repository,
+    };
+
+    let payload = client
+        .api()
+        .repo_default_reviewers_for_pr(&from_ref, &to_ref)
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let reviewers: Vec<String> = payload
+        .reviewers
+        .unwrap()
+        .into_iter()
+        .map(|reviewer| reviewer.user.name)
+        .collect();
+    assert_eq!(reviewers, vec!["jdoe", "rroe"]);
+    assert_eq!(payload.from_ref, from_ref);
+    assert_eq!(payload.to_ref, to_ref);
+    assert!(payload.title.is_empty());
+    repository_mock.assert_hits(1);
+    reviewers_mock.assert();
+}
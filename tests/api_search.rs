@@ -0,0 +1,72 @@
+mod common;
+
+use bitbucket_server_rs::api::search::SearchEntity;
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::POST;
+use serde_json::json;
+
+#[tokio::test]
+async fn it_can_search_code() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/search/latest/search")
+            .json_body(json!({
+                "query": "parse_config",
+                "entities": {"code": {}},
+                "limits": {"primary": 10}
+            }));
+        then.status(200).json_body(json!({
+            "scope": {"type": "GLOBAL"},
+            "code": {
+                "category": "primary",
+                "isLastPage": false,
+                "count": 12,
+                "start": 0,
+                "nextStart": 10,
+                "values": [
+                    {
+                        "repository": {
+                            "slug": "REPOSITORY_SLUG",
+                            "id": 1,
+                            "name": "Repository",
+                            "project": {"key": "PROJECT_KEY"}
+                        },
+                        "file": "src/config.rs",
+                        "hitContexts": [[
+                            {"line": 11, "text": "/// Reads the configuration"},
+                            {"line": 12, "text": "pub fn <em>parse_config</em>(path: &amp;Path) {"}
+                        ]],
+                        "pathMatches": [],
+                        "hitCount": 1
+                    }
+                ]
+            },
+            "query": {"substituted": false}
+        }));
+    });
+
+    let result = client
+        .api()
+        .search("parse_config")
+        .entities(vec![SearchEntity::Code])
+        .limit(10u64)
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let code = result.code.unwrap();
+    assert_eq!(code.count, 12);
+    assert_eq!(code.next_start, Some(10));
+    assert!(!code.is_last_page);
+    assert_eq!(code.values[0].repository.slug, "REPOSITORY_SLUG");
+    assert_eq!(code.values[0].file, "src/config.rs");
+    assert_eq!(code.values[0].hit_contexts[0][1].line, 12);
+    assert!(result.repositories.is_none());
+    mock.assert();
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use bitbucket_server_rs::api::webhook::{Webhook, WebhookConfiguration};
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::{GET, POST};
+use serde_json::json;
+
+#[tokio::test]
+async fn it_sends_the_secret_when_creating_webhook() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/webhooks")
+            .json_body(json!({
+                "name": "CI",
+                "url": "https://ci.example.com/hook",
+                "events": ["repo:refs_changed"],
+                "active": true,
+                "configuration": {"secret": "SECRET"}
+            }));
+        then.status(201).json_body(json!({
+            "id": 1,
+            "name": "CI",
+            "url": "https://ci.example.com/hook",
+            "events": ["repo:refs_changed"],
+            "active": true,
+            "configuration": {},
+            "createdDate": 1738198923000_u64
+        }));
+    });
+
+    let webhook = Webhook {
+        name: "CI".to_string(),
+        url: "https://ci.example.com/hook".to_string(),
+        events: vec!["repo:refs_changed".to_string()],
+        active: true,
+        configuration: WebhookConfiguration {
+            secret: Some("SECRET".to_string()),
+        },
+        ..Default::default()
+    };
+
+    let created = client
+        .api()
+        .webhook_post("PROJECT_KEY", "REPOSITORY_SLUG", &webhook)
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(created.id, Some(1));
+    assert_eq!(created.configuration.secret, None);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_can_list_webhooks_without_secrets() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/webhooks")
+            .query_param("event", "repo:refs_changed");
+        then.status(200).json_body(json!({
+            "size": 2,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": [
+                {
+                    "id": 1,
+                    "name": "CI",
+                    "url": "https://ci.example.com/hook",
+                    "events": ["repo:refs_changed"],
+                    "active": true,
+                    "configuration": {}
+                },
+                {
+                    "id": 2,
+                    "name": "Chat",
+                    "url": "https://chat.example.com/hook",
+                    "events": ["repo:refs_changed", "pr:opened"],
+                    "active": false
+                }
+            ]
+        }));
+    });
+
+    let page = client
+        .api()
+        .webhook_list("PROJECT_KEY", "REPOSITORY_SLUG")
+        .event("repo:refs_changed")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(page.values.len(), 2);
+    assert_eq!(page.values[0].configuration.secret, None);
+    assert!(!page.values[1].active);
+    mock.assert();
+}
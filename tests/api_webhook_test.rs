@@ -0,0 +1,72 @@
+mod common;
+
+use bitbucket_server_rs::api::webhook_test::WebhookOutcome;
+use bitbucket_server_rs::client::ApiRequest;
+use httpmock::Method::POST;
+use serde_json::json;
+
+#[tokio::test]
+async fn it_can_test_webhook() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/webhooks/1/test")
+            .json_body(json!({"event": "repo:refs_changed"}));
+        then.status(200).json_body(json!({
+            "event": "repo:refs_changed",
+            "duration": 42,
+            "result": {"outcome": "SUCCESS", "description": "200"}
+        }));
+    });
+
+    let delivery = client
+        .api()
+        .webhook_test("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .event("repo:refs_changed")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(delivery.is_success());
+    assert_eq!(delivery.duration, Some(42));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_reports_failed_webhook_delivery() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/webhooks/1/test")
+            .json_body(json!({}));
+        then.status(200).json_body(json!({
+            "event": "diagnostics:ping",
+            "result": {"outcome": "ERROR", "description": "Connection refused"}
+        }));
+    });
+
+    let delivery = client
+        .api()
+        .webhook_test("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(!delivery.is_success());
+    assert_eq!(delivery.result.outcome, WebhookOutcome::Error);
+    assert_eq!(
+        delivery.result.description,
+        Some("Connection refused".to_string())
+    );
+    mock.assert();
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use bitbucket_server_rs::Error;
+use httpmock::Method::GET;
+use serde_json::json;
+
+#[tokio::test]
+async fn it_can_get_authenticated_user() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let properties_mock = server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/application-properties");
+        then.status(200)
+            .header("X-AUSERNAME", "jcitizen")
+            .json_body(json!({"version": "8.19.0", "displayName": "Bitbucket"}));
+    });
+    let users_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/users")
+            .query_param("filter", "jcitizen");
+        then.status(200).json_body(json!({
+            "size": 2,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": [
+                {"name": "jcitizen2", "displayName": "John Citizen", "id": 102},
+                {"name": "jcitizen", "displayName": "Jane Citizen", "id": 101}
+            ]
+        }));
+    });
+
+    let user = client.api().whoami().send().await.unwrap().unwrap();
+
+    assert_eq!(user.name, "jcitizen");
+    assert_eq!(user.display_name.as_deref(), Some("Jane Citizen"));
+    properties_mock.assert();
+    users_mock.assert();
+}
+
+#[tokio::test]
+async fn it_reports_anonymous_access_as_unauthorized() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/application-properties");
+        then.status(200).json_body(json!({"version": "8.19.0"}));
+    });
+
+    let error = client.api().whoami().send().await.unwrap_err();
+
+    // the response was successful, so no status is reported
+    assert!(matches!(error, Error::Anonymous));
+    assert_eq!(error.status(), None);
+    assert!(error.is_unauthenticated());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_reports_invalid_token_as_unauthorized() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/application-properties");
+        then.status(401);
+    });
+
+    let error = client.api().whoami().send().await.unwrap_err();
+
+    assert!(error.is_unauthenticated());
+    mock.assert();
+}
@@ -0,0 +1,144 @@
+mod common;
+
+use bitbucket_server_rs::client::{ApiRequest, CircuitBreakerPolicy};
+use bitbucket_server_rs::Error;
+use httpmock::Method::GET;
+use std::time::Duration;
+
+const PULL_REQUEST_PATH: &str =
+    "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1";
+
+fn circuit_breaker() -> CircuitBreakerPolicy {
+    CircuitBreakerPolicy {
+        failure_threshold: 2,
+        window: Duration::from_secs(60),
+        cooldown: Duration::from_millis(200),
+    }
+}
+
+async fn get_pull_request(client: &bitbucket_server_rs::client::Client) -> Result<(), Error> {
+    client
+        .clone()
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .map(|_| ())
+}
+
+#[tokio::test]
+async fn it_opens_the_circuit_after_repeated_failures() {
+    common::setup();
+    let (server, mut client) = common::mock_client();
+    client.with_circuit_breaker(circuit_breaker());
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(PULL_REQUEST_PATH);
+        then.status(503);
+    });
+
+    assert!(get_pull_request(&client).await.unwrap_err().is_response_error());
+    assert!(get_pull_request(&client).await.unwrap_err().is_response_error());
+
+    // clones share the circuit
+    let error = get_pull_request(&client.clone()).await.unwrap_err();
+    match error {
+        Error::CircuitOpen { retry_after } => assert!(retry_after <= Duration::from_millis(200)),
+        error => panic!("unexpected error: {error:?}"),
+    }
+    mock.assert_hits(2);
+}
+
+#[tokio::test]
+async fn it_half_opens_the_circuit_after_the_cooldown() {
+    common::setup();
+    let (server, mut client) = common::mock_client();
+    client.with_circuit_breaker(circuit_breaker());
+
+    let mut failing = server.mock(|when, then| {
+        when.method(GET).path(PULL_REQUEST_PATH);
+        then.status(500);
+    });
+
+    get_pull_request(&client).await.unwrap_err();
+    get_pull_request(&client).await.unwrap_err();
+    assert!(get_pull_request(&client).await.unwrap_err().is_circuit_open());
+
+    // the trial request fails, so the circuit opens again
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    assert!(get_pull_request(&client).await.unwrap_err().is_response_error());
+    assert!(get_pull_request(&client).await.unwrap_err().is_circuit_open());
+    failing.assert_hits(3);
+    failing.delete();
+
+    let recovered = server.mock(|when, then| {
+        when.method(GET).path(PULL_REQUEST_PATH);
+        then.status(200).body(PULL_REQUEST);
+    });
+
+    // the trial request succeeds, so the circuit closes
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    get_pull_request(&client).await.unwrap();
+    get_pull_request(&client).await.unwrap();
+    recovered.assert_hits(2);
+}
+
+#[tokio::test]
+async fn it_forgets_failures_outside_the_window() {
+    common::setup();
+    let (server, mut client) = common::mock_client();
+    client.with_circuit_breaker(CircuitBreakerPolicy {
+        window: Duration::from_millis(50),
+        ..circuit_breaker()
+    });
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(PULL_REQUEST_PATH);
+        then.status(500);
+    });
+
+    get_pull_request(&client).await.unwrap_err();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert!(get_pull_request(&client).await.unwrap_err().is_response_error());
+    mock.assert_hits(2);
+}
+
+#[tokio::test]
+async fn it_never_opens_the_circuit_by_default() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(PULL_REQUEST_PATH);
+        then.status(500);
+    });
+
+    for _ in 0..5 {
+        assert!(get_pull_request(&client).await.unwrap_err().is_response_error());
+    }
+    mock.assert_hits(5);
+}
+
+static PULL_REQUEST: &str = r#"{
+  "id": 1,
+  "version": 0,
+  "title": "Add new feature",
+  "state": "OPEN",
+  "open": true,
+  "closed": false,
+  "fromRef": {
+    "id": "refs/heads/feature-branch",
+    "displayId": "feature-branch",
+    "latestCommit": "FROM_COMMIT_ID",
+    "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+  },
+  "toRef": {
+    "id": "refs/heads/main",
+    "displayId": "main",
+    "latestCommit": "TO_COMMIT_ID",
+    "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+  }
+}"#;
@@ -0,0 +1,955 @@
+mod common;
+
+use bitbucket_server_rs::api::pull_request_participant_status_put::PullRequestParticipantStatusPut;
+use bitbucket_server_rs::api::Api;
+use bitbucket_server_rs::client::{
+    ApiRequest, ApiResponse, ApiResponseWithHeaders, DescribeRequest, RetryPolicy, WithClient,
+};
+use bitbucket_server_rs::Error;
+use bitbucket_server_rs::timestamp::epoch_seconds;
+use httpmock::Method::{DELETE, GET, POST, PUT};
+use reqwest::redirect::Policy;
+use std::collections::BTreeMap;
+
+#[tokio::test]
+async fn it_reports_unfollowed_redirects() {
+    common::setup();
+    let (server, mut client) = common::mock_client();
+    client.with_http_client(
+        reqwest::Client::builder()
+            .redirect(Policy::none())
+            .build()
+            .unwrap(),
+    );
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(
+            "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits/COMMIT_ID/builds",
+        );
+        then.status(301)
+            .header("Location", "https://bitbucket-server/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits/COMMIT_ID/builds");
+    });
+
+    let response = client
+        .api()
+        .build_status_get("PROJECT_KEY", "COMMIT_ID", "REPOSITORY_SLUG")
+        .build()
+        .unwrap()
+        .send()
+        .await;
+
+    match response {
+        Err(Error::Redirect { location }) => assert_eq!(
+            location,
+            "https://bitbucket-server/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits/COMMIT_ID/builds"
+        ),
+        other => panic!("Expected a redirect error, got {:?}", other),
+    }
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_can_post_without_body() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7/watch")
+            .header("Authorization", "Bearer API_TOKEN")
+            .matches(|req| {
+                req.body.as_ref().is_none_or(|body| body.is_empty())
+                    && req.headers.as_ref().is_none_or(|headers| {
+                        !headers
+                            .iter()
+                            .any(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+                    })
+            });
+        then.status(204);
+    });
+
+    let response = client
+        .post_empty_with_headers::<serde_json::Value>(
+            "api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7/watch",
+        )
+        .await
+        .unwrap();
+
+    assert!(response.0.is_none());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_can_time_requests() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1");
+        then.status(404)
+            .delay(std::time::Duration::from_millis(50))
+            .body("not found");
+    });
+
+    let (response, elapsed) = client
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .build()
+        .unwrap()
+        .send_timed()
+        .await;
+
+    assert!(response.unwrap_err().is_response_error());
+    assert!(elapsed >= std::time::Duration::from_millis(50));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_sends_no_content_type_on_get() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7")
+            .header("Authorization", "Bearer API_TOKEN")
+            .matches(|req| {
+                req.headers.as_ref().is_none_or(|headers| {
+                    !headers
+                        .iter()
+                        .any(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+                })
+            });
+        then.status(200).json_body(serde_json::json!({"id": 7}));
+    });
+
+    let response = client
+        .get_with_headers::<serde_json::Value>(
+            "api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7",
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.0.unwrap()["id"], 7);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_tracks_rate_limit_state() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let limited = server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/limited");
+        then.status(200)
+            .header("X-RateLimit-Limit", "100")
+            .header("X-RateLimit-Remaining", "7")
+            .header("X-RateLimit-Reset", "1738198923")
+            .json_body(serde_json::json!({}));
+    });
+    let unlimited = server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/unlimited");
+        then.status(200).json_body(serde_json::json!({}));
+    });
+
+    assert!(client.rate_limit_state().is_none());
+
+    client
+        .get_with_headers::<serde_json::Value>("api/latest/limited", None)
+        .await
+        .unwrap();
+
+    let state = client.clone().rate_limit_state().unwrap();
+    assert_eq!(state.limit, Some(100));
+    assert_eq!(state.remaining, Some(7));
+    assert_eq!(epoch_seconds(&state.reset.unwrap()), 1738198923);
+
+    // responses without rate-limit headers leave the state unchanged
+    client
+        .get_with_headers::<serde_json::Value>("api/latest/unlimited", None)
+        .await
+        .unwrap();
+
+    assert_eq!(client.rate_limit_state(), Some(state));
+    limited.assert();
+    unlimited.assert();
+}
+
+#[tokio::test]
+async fn it_keeps_context_path_in_requests() {
+    common::setup();
+    let server = httpmock::MockServer::start();
+    let client = bitbucket_server_rs::client::new(&server.url("/bitbucket"), "API_TOKEN");
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/bitbucket/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/settings/pull-requests");
+        then.status(200).json_body(serde_json::json!({}));
+    });
+
+    let response = client
+        .clone()
+        .api()
+        .pull_request_settings_get("PROJECT_KEY", "REPOSITORY_SLUG")
+        .send()
+        .await;
+
+    assert!(response.is_ok());
+    assert_eq!(client.server_url(), server.url("/bitbucket"));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_reports_captcha_challenge_as_throttled() {
+    common::setup();
+    let (server, mut client) = common::mock_client();
+    client.with_retry_policy(RetryPolicy {
+        max_retries: 2,
+        initial_backoff: std::time::Duration::from_millis(1),
+    });
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1");
+        then.status(401).header(
+            "X-Authentication-Denied-Reason",
+            "CAPTCHA_CHALLENGE; login-url=https://bitbucket-server/login",
+        );
+    });
+
+    let error = client
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap_err();
+
+    match error {
+        Error::AuthenticationThrottled { reason } => {
+            assert_eq!(reason, "CAPTCHA_CHALLENGE; login-url=https://bitbucket-server/login")
+        }
+        error => panic!("expected AuthenticationThrottled, got {error:?}"),
+    }
+    // retrying would only prolong the lockout
+    mock.assert_hits(1);
+}
+
+const UNAUTHENTICATED_BODY: &str = r#"{"errors":[{"context":null,"message":"Authentication failed. Please check your credentials and try again.","exceptionName":"com.atlassian.bitbucket.auth.IncorrectPasswordAuthenticationException"}]}"#;
+const FORBIDDEN_BODY: &str = r#"{"errors":[{"context":null,"message":"You are not permitted to access this resource","exceptionName":"com.atlassian.bitbucket.AuthorisationException"}]}"#;
+
+#[tokio::test]
+async fn it_reports_401_as_unauthenticated() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1");
+        then.status(401).body(UNAUTHENTICATED_BODY);
+    });
+
+    let error = client
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(error.is_unauthorized());
+    match error {
+        Error::Unauthenticated { status, body } => {
+            assert_eq!(status, 401);
+            assert_eq!(body, UNAUTHENTICATED_BODY);
+        }
+        error => panic!("unexpected error: {error:?}"),
+    }
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_reports_send_failures_as_request_errors() {
+    common::setup();
+    // nothing listens on port 1
+    let client = bitbucket_server_rs::client::new("http://127.0.0.1:1/rest", "API_TOKEN");
+
+    let error: Error = client
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(error.is_request_error());
+    assert!(!error.is_unauthorized());
+}
+
+#[tokio::test]
+async fn it_reports_403_as_forbidden() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1");
+        then.status(403).body(FORBIDDEN_BODY);
+    });
+
+    let error = client
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(error.is_unauthorized());
+    match error {
+        Error::Forbidden { status, body } => {
+            assert_eq!(status, 403);
+            assert_eq!(body, FORBIDDEN_BODY);
+        }
+        error => panic!("unexpected error: {error:?}"),
+    }
+    mock.assert();
+}
+
+const NOT_FOUND_BODY: &str = r#"{"errors":[{"context":null,"message":"Pull request 1 does not exist in PROJECT_KEY/REPOSITORY_SLUG.","exceptionName":"com.atlassian.bitbucket.pull.NoSuchPullRequestException"}]}"#;
+
+#[tokio::test]
+async fn it_reports_4xx_as_http_client_errors() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1");
+        then.status(404).body(NOT_FOUND_BODY);
+    });
+
+    let error = client
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(error.is_http_client_error());
+    assert!(error.is_response_error());
+    assert_eq!(error.status(), Some(404));
+    match error {
+        Error::HttpClientError { status, body } => {
+            assert_eq!(status, 404);
+            assert_eq!(body, NOT_FOUND_BODY);
+        }
+        error => panic!("unexpected error: {error:?}"),
+    }
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_reports_5xx_as_http_server_errors() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1");
+        then.status(500).body("Internal Server Error");
+    });
+
+    let error = client
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(error.is_http_server_error());
+    assert!(!error.is_http_client_error());
+    match error {
+        Error::HttpServerError { status, body } => {
+            assert_eq!(status, 500);
+            assert_eq!(body, "Internal Server Error");
+        }
+        error => panic!("unexpected error: {error:?}"),
+    }
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_reports_mismatched_bodies_as_deserialization_errors() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1");
+        then.status(200).body(r#"{"id":"not a number"}"#);
+    });
+
+    let error = client
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(error.is_deserialization_error());
+    assert_eq!(error.status(), None);
+    assert!(std::error::Error::source(&error).is_some());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_can_put() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(PUT)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/participants/jdoe")
+            .header("Authorization", "Bearer API_TOKEN")
+            .header("Content-Type", "application/json")
+            .body(r#"{"status":"APPROVED"}"#);
+        then.status(200).json_body(serde_json::json!({
+            "user": {"name": "jdoe"},
+            "role": "REVIEWER",
+            "approved": true,
+            "status": "APPROVED"
+        }));
+    });
+
+    let participant = client
+        .put::<PullRequestParticipantStatusPut>(
+            "api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/participants/jdoe",
+            r#"{"status":"APPROVED"}"#,
+        )
+        .await
+        .unwrap();
+
+    assert!(participant.unwrap().approved);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_returns_none_on_put_without_content() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(PUT)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/participants/jdoe");
+        then.status(204);
+    });
+
+    let response = client
+        .put::<PullRequestParticipantStatusPut>(
+            "api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/participants/jdoe",
+            r#"{"status":"APPROVED"}"#,
+        )
+        .await;
+
+    assert!(response.unwrap().is_none());
+    mock.assert();
+}
+
+/// Deletes the build statuses of a commit; only its output type is used, by `Client::delete`.
+struct CommitBuildStatusDelete;
+
+impl ApiRequest for CommitBuildStatusDelete {
+    type Output = ();
+
+    async fn send(&self) -> ApiResponse<Self::Output> {
+        unimplemented!("only used as the type parameter of Client::delete")
+    }
+
+    async fn send_with_headers(&self) -> ApiResponseWithHeaders<Self::Output> {
+        unimplemented!("only used as the type parameter of Client::delete")
+    }
+}
+
+#[tokio::test]
+async fn it_can_delete() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(DELETE)
+            .path("/rest/build-status/latest/commits/COMMIT_ID")
+            .query_param("key", "ci")
+            .header("Authorization", "Bearer API_TOKEN")
+            .header("X-Atlassian-Token", "no-check")
+            .matches(|req| {
+                req.body.as_ref().is_none_or(|body| body.is_empty())
+                    && req.headers.as_ref().is_none_or(|headers| {
+                        !headers
+                            .iter()
+                            .any(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+                    })
+            });
+        then.status(204);
+    });
+
+    let params = BTreeMap::from([("key".to_string(), "ci".to_string())]);
+    let response = client
+        .delete::<CommitBuildStatusDelete>("build-status/latest/commits/COMMIT_ID", Some(params))
+        .await;
+
+    assert!(response.unwrap().is_none());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_sends_atlassian_token_on_post() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/labels")
+            .header("X-Atlassian-Token", "no-check");
+        then.status(200).body(r#"{"name":"dependencies"}"#);
+    });
+
+    let response = client
+        .api()
+        .pull_request_label_post("PROJECT_KEY", "REPOSITORY_SLUG", "1", "dependencies")
+        .send()
+        .await;
+
+    assert!(response.is_ok());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_can_omit_atlassian_token() {
+    common::setup();
+    let (server, mut client) = common::mock_client();
+    client.with_atlassian_token_no_check(false);
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/labels")
+            .matches(|req| {
+                !req.headers.iter().flatten().any(|(name, _)| name.eq_ignore_ascii_case("X-Atlassian-Token"))
+            });
+        then.status(200).body(r#"{"name":"dependencies"}"#);
+    });
+
+    let response = client
+        .api()
+        .pull_request_label_post("PROJECT_KEY", "REPOSITORY_SLUG", "1", "dependencies")
+        .send()
+        .await;
+
+    assert!(response.is_ok());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_reports_xsrf_rejection() {
+    common::setup();
+    let (server, mut client) = common::mock_client();
+    client.with_atlassian_token_no_check(false);
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/labels");
+        then.status(403).body("XSRF check failed");
+    });
+
+    let error = client
+        .api()
+        .pull_request_label_post("PROJECT_KEY", "REPOSITORY_SLUG", "1", "dependencies")
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, Error::XsrfCheckFailed));
+    assert!(error.is_xsrf_check_failed());
+    assert!(!error.is_unauthorized());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_describes_requests_without_sending_them() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.any_request();
+        then.status(200);
+    });
+
+    let request = client
+        .api()
+        .build_status_get("PROJECT_KEY", "COMMIT_ID", "REPOSITORY_SLUG")
+        .key("x")
+        .build()
+        .unwrap()
+        .describe()
+        .unwrap();
+
+    assert_eq!(request.method, "GET");
+    assert_eq!(
+        request.url,
+        server.url("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits/COMMIT_ID/builds?key=x")
+    );
+    assert_eq!(request.body, None);
+    mock.assert_hits(0);
+}
+
+#[test]
+fn it_describes_query_parameters_in_a_stable_order() {
+    let (server, client) = common::mock_client();
+
+    let request = client
+        .api()
+        .commit_list("PROJECT_KEY", "REPOSITORY_SLUG")
+        .until("refs/heads/feature")
+        .since("refs/heads/main")
+        .start(25u64)
+        .build()
+        .unwrap()
+        .describe()
+        .unwrap();
+
+    // sorted by name, whatever the order they were set in
+    assert_eq!(
+        request.url,
+        server.url("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits?since=refs%2Fheads%2Fmain&start=25&until=refs%2Fheads%2Ffeature")
+    );
+}
+
+#[test]
+fn it_describes_request_bodies_with_api_token_redacted() {
+    let (server, client) = common::mock_client();
+
+    let request = client
+        .api()
+        .pull_request_label_post("PROJECT_KEY", "REPOSITORY_SLUG", "1", "dependencies")
+        .describe()
+        .unwrap();
+
+    assert_eq!(request.method, "POST");
+    assert_eq!(
+        request.url,
+        server.url("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/labels")
+    );
+    assert_eq!(request.body.as_deref(), Some(r#"{"name":"dependencies"}"#));
+    assert_eq!(request.header("content-type"), Some("application/json"));
+    assert_eq!(request.header("Authorization"), Some("Bearer ***"));
+    assert!(!format!("{:?}", request).contains("API_TOKEN"));
+}
+
+#[tokio::test]
+async fn it_sends_the_described_request() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let request = client
+        .api()
+        .pull_request_label_delete("PROJECT_KEY", "REPOSITORY_SLUG", "1", "needs review");
+    let description = request.describe().unwrap();
+
+    let mock = server.mock(|when, then| {
+        when.method(DELETE)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/labels/needs%20review")
+            .header("X-Atlassian-Token", "no-check");
+        then.status(204);
+    });
+
+    request.send().await.unwrap();
+
+    assert_eq!(description.method, "DELETE");
+    assert_eq!(
+        description.url,
+        server.url("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/labels/needs%20review")
+    );
+    assert_eq!(description.header("X-Atlassian-Token"), Some("no-check"));
+    assert_eq!(description.body, None);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_can_send_pretty_bodies() {
+    common::setup();
+    let (server, mut client) = common::mock_client();
+    client.with_pretty_bodies(true);
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/labels")
+            .body("{\n  \"name\": \"dependencies\"\n}");
+        then.status(200).body(r#"{"name":"dependencies"}"#);
+    });
+
+    let response = client
+        .api()
+        .pull_request_label_post("PROJECT_KEY", "REPOSITORY_SLUG", "1", "dependencies")
+        .send()
+        .await;
+
+    assert!(response.is_ok());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_sends_compact_bodies_by_default() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1/labels")
+            .body(r#"{"name":"dependencies"}"#);
+        then.status(200).body(r#"{"name":"dependencies"}"#);
+    });
+
+    let response = client
+        .api()
+        .pull_request_label_post("PROJECT_KEY", "REPOSITORY_SLUG", "1", "dependencies")
+        .send()
+        .await;
+
+    assert!(response.is_ok());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn it_accepts_json_with_charset() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let body = serde_json::json!({
+        "id": 1,
+        "version": 0,
+        "title": "Añadir función",
+        "state": "OPEN",
+        "open": true,
+        "closed": false,
+        "fromRef": {
+            "id": "refs/heads/feature",
+            "displayId": "feature",
+            "latestCommit": "FROM_COMMIT_ID",
+            "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+        },
+        "toRef": {
+            "id": "refs/heads/main",
+            "displayId": "main",
+            "latestCommit": "TO_COMMIT_ID",
+            "repository": {"slug": "REPOSITORY_SLUG", "project": {"key": "PROJECT_KEY"}}
+        }
+    })
+    .to_string();
+
+    for content_type in ["application/json; charset=utf-8", "application/json;charset=UTF-8"] {
+        let mut mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1");
+            then.status(200)
+                .header("Content-Type", content_type)
+                .body(&body);
+        });
+
+        let pull_request = client
+            .clone()
+            .api()
+            .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+            .build()
+            .unwrap()
+            .send()
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(pull_request.title, "Añadir función");
+        mock.assert();
+        mock.delete();
+    }
+}
+
+#[tokio::test]
+async fn it_sends_request_through_swapped_client() {
+    common::setup();
+    let (server, client) = common::mock_client();
+    let (swapped_server, swapped_client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.any_request();
+        then.status(500);
+    });
+    let swapped_mock = swapped_server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests")
+            .query_param("state", "MERGED")
+            .query_param("limit", "5");
+        then.status(200).json_body(serde_json::json!({
+            "size": 0,
+            "limit": 5,
+            "isLastPage": true,
+            "start": 0,
+            "values": []
+        }));
+    });
+
+    let request = client
+        .api()
+        .pull_request_list("PROJECT_KEY", "REPOSITORY_SLUG")
+        .state("MERGED")
+        .limit(5u64)
+        .build()
+        .unwrap();
+    let response = request.with_client(swapped_client).send().await;
+
+    assert!(response.unwrap().is_some());
+    swapped_mock.assert();
+    mock.assert_hits(0);
+}
+
+#[tokio::test]
+async fn it_sends_template_through_each_client() {
+    common::setup();
+    let (production_server, production_client) = common::mock_client();
+    let (staging_server, staging_client) = common::mock_client();
+
+    let mocks = [&production_server, &staging_server].map(|server| {
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests")
+                .query_param("state", "MERGED")
+                .header("Authorization", "Bearer API_TOKEN");
+            then.status(200).json_body(serde_json::json!({
+                "size": 0,
+                "limit": 25,
+                "isLastPage": true,
+                "start": 0,
+                "values": []
+            }));
+        })
+    });
+
+    let template = Api::template()
+        .pull_request_list("PROJECT_KEY", "REPOSITORY_SLUG")
+        .state("MERGED")
+        .build()
+        .unwrap();
+
+    assert!(template.send_with(&production_client).await.unwrap().is_some());
+    assert!(template.send_with(&staging_client).await.unwrap().is_some());
+    assert!(template.send_with(&staging_client).await.unwrap().is_some());
+
+    mocks[0].assert_hits(1);
+    mocks[1].assert_hits(2);
+}
+
+#[tokio::test]
+async fn it_fails_to_send_a_template_without_a_client() {
+    let template = Api::template()
+        .pull_request_list("PROJECT_KEY", "REPOSITORY_SLUG")
+        .build()
+        .unwrap();
+
+    let error = template.send().await.unwrap_err();
+    assert!(error.is_request_error());
+    assert!(error.to_string().contains("no base URL"));
+    assert!(template.describe().unwrap_err().is_request_error());
+}
+
+#[test]
+fn it_reports_stable_endpoint_names() {
+    use bitbucket_server_rs::api::*;
+
+    let names = [
+        (attachment_get::AttachmentGet::ENDPOINT_NAME, "attachment_get"),
+        (avatar_get::AvatarGet::ENDPOINT_NAME, "avatar_get"),
+        (branch_delete::BranchDelete::ENDPOINT_NAME, "branch_delete"),
+        (branch_is_merged::BranchIsMerged::ENDPOINT_NAME, "branch_is_merged"),
+        (branch_list::BranchList::ENDPOINT_NAME, "branch_list"),
+        (branch_model_get::BranchModelGet::ENDPOINT_NAME, "branch_model_get"),
+        (branch_prune_merged::PruneMergedBranches::ENDPOINT_NAME, "branch_prune_merged"),
+        (build_status_get::BuildStatusGet::ENDPOINT_NAME, "build_status_get"),
+        (build_status_list::BuildStatusList::ENDPOINT_NAME, "build_status_list"),
+        (build_status_post::BuildStatusPost::ENDPOINT_NAME, "build_status_post"),
+        (build_status_post_for_pr::BuildStatusPostForPr::ENDPOINT_NAME, "build_status_post_for_pr"),
+        (build_status_post_for_pr_commits::BuildStatusPostForPrCommits::ENDPOINT_NAME, "build_status_post_for_pr_commits"),
+        (commit_ancestors::CommitAncestors::ENDPOINT_NAME, "commit_ancestors"),
+        (commit_changes_get::CommitChangesGet::ENDPOINT_NAME, "commit_changes_get"),
+        (commit_get::CommitGet::ENDPOINT_NAME, "commit_get"),
+        (commit_list::CommitList::ENDPOINT_NAME, "commit_list"),
+        (compare_commits::CompareCommits::ENDPOINT_NAME, "compare_commits"),
+        (dashboard_pull_requests::DashboardPullRequests::ENDPOINT_NAME, "dashboard_pull_requests"),
+        (default_reviewers_get::DefaultReviewersGet::ENDPOINT_NAME, "default_reviewers_get"),
+        (last_modified::LastModifiedGet::ENDPOINT_NAME, "last_modified"),
+        (my_repo_permission::MyRepoPermission::ENDPOINT_NAME, "my_repo_permission"),
+        (pull_request_changes_get::PullRequestChangesGet::ENDPOINT_NAME, "pull_request_changes_get"),
+        (pull_request_comment_list::PullRequestCommentList::ENDPOINT_NAME, "pull_request_comment_list"),
+        (pull_request_comment_post::PullRequestCommentPost::ENDPOINT_NAME, "pull_request_comment_post"),
+        (pull_request_comment_threads::PullRequestCommentThreads::ENDPOINT_NAME, "pull_request_comment_threads"),
+        (pull_request_create_if_ahead::PullRequestCreateIfAhead::ENDPOINT_NAME, "pull_request_create_if_ahead"),
+        (pull_request_create_or_get::PullRequestCreateOrGet::ENDPOINT_NAME, "pull_request_create_or_get"),
+        (pull_request_diff_get::PullRequestDiffGet::ENDPOINT_NAME, "pull_request_diff_get"),
+        (pull_request_diffstat::PullRequestDiffstat::ENDPOINT_NAME, "pull_request_diffstat"),
+        (pull_request_gate_context::PullRequestGateContext::ENDPOINT_NAME, "pull_request_gate_context"),
+        (pull_request_get::PullRequestGet::ENDPOINT_NAME, "pull_request_get"),
+        (pull_request_incoming_commits::PullRequestIncomingCommits::ENDPOINT_NAME, "pull_request_incoming_commits"),
+        (pull_request_jira_issues::PullRequestJiraIssues::ENDPOINT_NAME, "pull_request_jira_issues"),
+        (pull_request_label_delete::PullRequestLabelDelete::ENDPOINT_NAME, "pull_request_label_delete"),
+        (pull_request_label_list::PullRequestLabelList::ENDPOINT_NAME, "pull_request_label_list"),
+        (pull_request_label_post::PullRequestLabelPost::ENDPOINT_NAME, "pull_request_label_post"),
+        (pull_request_list::PullRequestList::ENDPOINT_NAME, "pull_request_list"),
+        (pull_request_merge::PullRequestMerge::ENDPOINT_NAME, "pull_request_merge"),
+        (pull_request_merge_base::PullRequestMergeBase::ENDPOINT_NAME, "pull_request_merge_base"),
+        (pull_request_merge_if_green::PullRequestMergeIfGreen::ENDPOINT_NAME, "pull_request_merge_if_green"),
+        (pull_request_merge_status_get::PullRequestMergeStatusGet::ENDPOINT_NAME, "pull_request_merge_status_get"),
+        (pull_request_participant_status_put::PullRequestParticipantStatusPut::ENDPOINT_NAME, "pull_request_participant_status_put"),
+        (pull_request_patch::PullRequestPatch::ENDPOINT_NAME, "pull_request_patch"),
+        (pull_request_post::PullRequestPost::ENDPOINT_NAME, "pull_request_post"),
+        (pull_request_rescoped::PullRequestRescoped::ENDPOINT_NAME, "pull_request_rescoped"),
+        (pull_request_settings_get::PullRequestSettingsGet::ENDPOINT_NAME, "pull_request_settings_get"),
+        (pull_request_settings_update::PullRequestSettingsUpdate::ENDPOINT_NAME, "pull_request_settings_update"),
+        (repo_default_reviewers_for_pr::RepoDefaultReviewersForPr::ENDPOINT_NAME, "repo_default_reviewers_for_pr"),
+        (repository_list::RepositoryList::ENDPOINT_NAME, "repository_list"),
+        (search::Search::ENDPOINT_NAME, "search"),
+        (webhook_list::WebhookList::ENDPOINT_NAME, "webhook_list"),
+        (webhook_post::WebhookPost::ENDPOINT_NAME, "webhook_post"),
+        (webhook_test::WebhookTest::ENDPOINT_NAME, "webhook_test"),
+        (whoami::Whoami::ENDPOINT_NAME, "whoami"),
+    ];
+
+    for (name, expected) in names {
+        assert_eq!(name, expected);
+    }
+}
+
+#[test]
+fn it_names_requests_without_an_endpoint_name_after_their_type() {
+    assert_eq!(CommitBuildStatusDelete::ENDPOINT_NAME, "");
+    assert_eq!(
+        CommitBuildStatusDelete.endpoint_name(),
+        "client::CommitBuildStatusDelete"
+    );
+}
+
+#[test]
+fn it_redacts_api_token_in_request_debug_output() {
+    let client = bitbucket_server_rs::client::new("https://bitbucket-server/rest", "SECRET_API_TOKEN");
+
+    let request = client
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .build()
+        .unwrap();
+
+    assert!(!format!("{request:?}").contains("SECRET_API_TOKEN"));
+    assert!(!format!("{request:#?}").contains("SECRET_API_TOKEN"));
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "gzip")]
+
+mod common;
+
+use bitbucket_server_rs::client::ApiRequest;
+use bitbucket_server_rs::Error;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use httpmock::Method::GET;
+use std::io::Write;
+
+fn gzip(body: &str) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(body.as_bytes()).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[tokio::test]
+async fn it_decompresses_gzip_encoded_server_error_bodies() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1")
+            .header("Accept-Encoding", "gzip");
+        then.status(500)
+            .header("Content-Encoding", "gzip")
+            .body(gzip(r#"{"errors":[{"message":"Database unavailable"}]}"#));
+    });
+
+    let error = client
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap_err();
+
+    mock.assert();
+    match error {
+        Error::HttpServerError { status, body } => {
+            assert_eq!(status, 500);
+            assert_eq!(body, r#"{"errors":[{"message":"Database unavailable"}]}"#);
+        }
+        error => panic!("unexpected error: {error:?}"),
+    }
+}
+
+#[tokio::test]
+async fn it_decompresses_gzip_encoded_client_error_bodies() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/1");
+        then.status(404)
+            .header("Content-Encoding", "gzip")
+            .body(gzip(r#"{"errors":[{"message":"Pull request 1 does not exist"}]}"#));
+    });
+
+    let error = client
+        .api()
+        .pull_request_get("PROJECT_KEY", "REPOSITORY_SLUG", "1")
+        .build()
+        .unwrap()
+        .send()
+        .await
+        .unwrap_err();
+
+    mock.assert();
+    assert!(error.to_string().contains("Pull request 1 does not exist"));
+}
@@ -0,0 +1,161 @@
+mod common;
+
+use bitbucket_server_rs::api::pagination::{page_stream, paged_stream};
+use futures_util::{StreamExt, TryStreamExt};
+use httpmock::Method::GET;
+use httpmock::{Mock, MockServer};
+use serde_json::{json, Value};
+
+const CHANGES_PATH: &str =
+    "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/pull-requests/7/changes";
+const COMMITS_PATH: &str = "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits";
+
+fn mock_changes<'a>(
+    server: &'a MockServer,
+    start: u64,
+    paths: &[&str],
+    next_page_start: Option<u64>,
+) -> Mock<'a> {
+    let values: Vec<Value> = paths
+        .iter()
+        .map(|path| json!({"contentId": "CONTENT_ID", "type": "MODIFY", "path": {"toString": path}}))
+        .collect();
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(CHANGES_PATH)
+            .query_param("start", start.to_string())
+            .query_param("limit", "2");
+        then.status(200).json_body(json!({
+            "fromHash": "8d51122def5632836d1cb1026e879069e10a1e13",
+            "toHash": "0a943a29376f2336b78312d99e65da17048951db",
+            "size": values.len(),
+            "limit": 2,
+            "isLastPage": next_page_start.is_none(),
+            "start": start,
+            "nextPageStart": next_page_start,
+            "values": values
+        }));
+    })
+}
+
+#[tokio::test]
+async fn it_streams_all_pages() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let first_page_mock = mock_changes(&server, 0, &["src/lib.rs", "src/client.rs"], Some(2));
+    let second_page_mock = mock_changes(&server, 2, &["README.md"], None);
+
+    let paths: Vec<String> = paged_stream(|start| {
+        client
+            .clone()
+            .api()
+            .pull_request_changes_get("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+            .start(start)
+            .limit(2u64)
+            .build()
+    })
+    .map_ok(|change| change.path.to_string)
+    .try_collect()
+    .await
+    .unwrap();
+
+    assert_eq!(paths, ["src/lib.rs", "src/client.rs", "README.md"]);
+    first_page_mock.assert();
+    second_page_mock.assert();
+}
+
+#[tokio::test]
+async fn it_streams_the_pages_themselves() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    mock_changes(&server, 0, &["src/lib.rs", "src/client.rs"], Some(2));
+    mock_changes(&server, 2, &["README.md"], None);
+
+    let pages: Vec<_> = page_stream(|start| {
+        client
+            .clone()
+            .api()
+            .pull_request_changes_get("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+            .start(start)
+            .limit(2u64)
+            .build()
+    })
+    .try_collect()
+    .await
+    .unwrap();
+
+    assert_eq!(pages.len(), 2);
+    assert!(pages
+        .iter()
+        .all(|page| page.from_hash == "8d51122def5632836d1cb1026e879069e10a1e13"));
+    assert_eq!(pages[0].values.len(), 2);
+    assert_eq!(pages[1].values.len(), 1);
+}
+
+#[tokio::test]
+async fn it_streams_pages_of_a_list_endpoint() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(COMMITS_PATH).query_param("start", "0");
+        then.status(200).json_body(json!({
+            "size": 1,
+            "limit": 25,
+            "isLastPage": true,
+            "start": 0,
+            "values": [{"id": "8d51122def5632836d1cb1026e879069e10a1e13", "displayId": "8d51122def5"}]
+        }));
+    });
+
+    let commits: Vec<_> = paged_stream(|start| {
+        client
+            .clone()
+            .api()
+            .commit_list("PROJECT_KEY", "REPOSITORY_SLUG")
+            .start(start)
+            .build()
+    })
+    .try_collect()
+    .await
+    .unwrap();
+
+    assert_eq!(commits.len(), 1);
+    assert_eq!(commits[0].display_id, "8d51122def5");
+    mock.assert_hits(1);
+}
+
+#[tokio::test]
+async fn it_ends_the_stream_with_the_first_error() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    mock_changes(&server, 0, &["src/lib.rs", "src/client.rs"], Some(2));
+    let second_page_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(CHANGES_PATH)
+            .query_param("start", "2");
+        then.status(500).body("Internal Server Error");
+    });
+
+    let results: Vec<_> = paged_stream(|start| {
+        client
+            .clone()
+            .api()
+            .pull_request_changes_get("PROJECT_KEY", "REPOSITORY_SLUG", "7")
+            .start(start)
+            .limit(2u64)
+            .build()
+    })
+    .collect()
+    .await;
+
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_ok());
+    assert!(results[2].as_ref().unwrap_err().is_response_error());
+    second_page_mock.assert_hits(1);
+}
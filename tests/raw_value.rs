@@ -0,0 +1,44 @@
+#![cfg(feature = "raw-value")]
+
+mod common;
+
+use bitbucket_server_rs::api::build_status::BuildStatusState;
+use bitbucket_server_rs::client::{ApiRequest, RawValueRequest};
+use httpmock::Method::GET;
+
+#[tokio::test]
+async fn it_can_get_raw_value_alongside_typed_output() {
+    common::setup();
+    let (server, client) = common::mock_client();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(
+            "/rest/api/latest/projects/PROJECT_KEY/repos/REPOSITORY_SLUG/commits/COMMIT_ID/builds",
+        );
+        then.status(200).body(
+            r#"{
+                "key": "KEY",
+                "state": "SUCCESSFUL",
+                "url": "https://my-build-status.com/path",
+                "createdDate": 1738198923000,
+                "updatedDate": 1738198924000,
+                "unmodeledField": {"nested": 42}
+            }"#,
+        );
+    });
+
+    let (build_status, value) = client
+        .api()
+        .build_status_get("PROJECT_KEY", "COMMIT_ID", "REPOSITORY_SLUG")
+        .build()
+        .unwrap()
+        .with_raw_value()
+        .send()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(build_status.state, BuildStatusState::Successful);
+    assert_eq!(value["unmodeledField"]["nested"], 42);
+    mock.assert();
+}
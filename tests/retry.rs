@@ -0,0 +1,196 @@
+use bitbucket_server_rs::client::{new, RetryPolicy};
+use httpmock::Method::{GET, POST};
+use httpmock::MockServer;
+use log::{Level, Log, Metadata, Record};
+use std::sync::{Mutex, Once};
+use std::time::Duration;
+
+/// A logger that keeps the records of this crate so tests can inspect them.
+struct CapturingLogger {
+    records: Mutex<Vec<(Level, String)>>,
+}
+
+impl Log for CapturingLogger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        if record.target().starts_with("bitbucket_server_rs") {
+            self.records
+                .lock()
+                .unwrap()
+                .push((record.level(), record.args().to_string()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CapturingLogger = CapturingLogger {
+    records: Mutex::new(Vec::new()),
+};
+static INIT: Once = Once::new();
+
+fn setup() {
+    INIT.call_once(|| {
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(log::LevelFilter::Info);
+    });
+}
+
+/// The records logged for a path, so tests running in parallel don't see each other's.
+fn records_for(path: &str) -> Vec<(Level, String)> {
+    LOGGER
+        .records
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(_, message)| message.contains(path))
+        .cloned()
+        .collect()
+}
+
+fn retry_policy() -> RetryPolicy {
+    RetryPolicy {
+        max_retries: 3,
+        initial_backoff: Duration::from_millis(1),
+    }
+}
+
+#[tokio::test]
+async fn it_logs_each_retry() {
+    setup();
+    let server = MockServer::start();
+    let mut client = new(&server.url("/rest"), "API_TOKEN");
+    client.with_retry_policy(retry_policy());
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/retried");
+        then.status(503).body("Service Unavailable");
+    });
+
+    let response = client
+        .get_with_headers::<serde_json::Value>("api/latest/retried?token=SECRET", None)
+        .await;
+
+    assert!(response.unwrap_err().is_response_error());
+    mock.assert_hits(4);
+
+    let records = records_for("/rest/api/latest/retried");
+    assert_eq!(records.len(), 3);
+    for (i, (level, message)) in records.iter().enumerate() {
+        assert_eq!(*level, Level::Warn);
+        assert!(message.contains(&format!("({} of 3)", i + 1)), "{message}");
+        assert!(message.contains("HTTP Server error [503]"), "{message}");
+        assert!(!message.contains("SECRET") && !message.contains("API_TOKEN"));
+    }
+}
+
+#[tokio::test]
+async fn it_logs_the_success_after_retries() {
+    setup();
+    let server = MockServer::start();
+    let mut client = new(&server.url("/rest"), "API_TOKEN");
+    client.with_retry_policy(RetryPolicy {
+        max_retries: 3,
+        initial_backoff: Duration::from_millis(50),
+    });
+
+    let mut failing_mock = server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/recovered");
+        then.status(503).body("Service Unavailable");
+    });
+
+    // the server recovers while the client waits to retry for the second time
+    let (response, succeeding_mock) = tokio::join!(
+        client.get_with_headers::<serde_json::Value>("api/latest/recovered", None),
+        async {
+            while failing_mock.hits() < 2 {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            failing_mock.delete();
+            server.mock(|when, then| {
+                when.method(GET).path("/rest/api/latest/recovered");
+                then.status(200).body("{}");
+            })
+        }
+    );
+
+    assert!(response.is_ok());
+    succeeding_mock.assert();
+
+    let records = records_for("/rest/api/latest/recovered");
+    let levels: Vec<Level> = records.iter().map(|(level, _)| *level).collect();
+    assert_eq!(levels, [Level::Warn, Level::Warn, Level::Info]);
+    assert_eq!(
+        records[2].1,
+        "GET /rest/api/latest/recovered succeeded after 2 retries"
+    );
+}
+
+#[tokio::test]
+async fn it_never_logs_the_token() {
+    setup();
+    let server = MockServer::start();
+    let mut client = new(&server.url("/rest"), "API_TOKEN");
+    client.with_retry_policy(retry_policy());
+
+    server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/unlogged");
+        then.status(503).body("Service Unavailable");
+    });
+
+    let response = client
+        .get_with_headers::<serde_json::Value>("api/latest/unlogged", None)
+        .await;
+
+    assert!(response.is_err());
+    assert_eq!(records_for("/rest/api/latest/unlogged").len(), 3);
+    // whichever test logged it
+    for (_, message) in LOGGER.records.lock().unwrap().iter() {
+        assert!(!message.contains("API_TOKEN"), "{message}");
+        assert!(!message.contains("Authorization"), "{message}");
+        assert!(!message.contains("Bearer"), "{message}");
+    }
+}
+
+#[tokio::test]
+async fn it_does_not_retry_by_default() {
+    setup();
+    let server = MockServer::start();
+    let client = new(&server.url("/rest"), "API_TOKEN");
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/rest/api/latest/not-retried");
+        then.status(503);
+    });
+
+    let response = client
+        .get_with_headers::<serde_json::Value>("api/latest/not-retried", None)
+        .await;
+
+    assert!(response.is_err());
+    mock.assert_hits(1);
+    assert!(records_for("/rest/api/latest/not-retried").is_empty());
+}
+
+#[tokio::test]
+async fn it_does_not_retry_posts() {
+    setup();
+    let server = MockServer::start();
+    let mut client = new(&server.url("/rest"), "API_TOKEN");
+    client.with_retry_policy(retry_policy());
+
+    let mock = server.mock(|when, then| {
+        when.method(POST).path("/rest/api/latest/posted");
+        then.status(503);
+    });
+
+    let response = client
+        .post_with_headers::<serde_json::Value>("api/latest/posted", "{}")
+        .await;
+
+    assert!(response.is_err());
+    mock.assert_hits(1);
+}
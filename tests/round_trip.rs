@@ -0,0 +1,105 @@
+//! Round-trip conformance tests: each fixture in `tests/fixtures` is a canonical response of an
+//! endpoint. It must deserialize, and serialize back to the same JSON, ignoring key order.
+
+use bitbucket_server_rs::api::build_status_get::BuildStatus;
+use bitbucket_server_rs::api::common::{ChangeItem, Commit, Page};
+use bitbucket_server_rs::api::diff::Diffs;
+use bitbucket_server_rs::api::pull_request::PullRequest;
+use bitbucket_server_rs::api::pull_request_changes_get::PullRequestChanges;
+use bitbucket_server_rs::api::pull_request_comment::Comment;
+use bitbucket_server_rs::api::pull_request_settings::PullRequestSettings;
+use bitbucket_server_rs::api::webhook_test::WebhookTestResult;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Load a fixture, round-trip it through `T` and assert nothing was lost or added.
+fn assert_round_trip<T: DeserializeOwned + Serialize>(fixture: &str) {
+    let path = format!("{}/tests/fixtures/{fixture}", env!("CARGO_MANIFEST_DIR"));
+    let json = std::fs::read_to_string(&path).unwrap();
+    let expected: Value = serde_json::from_str(&json).unwrap();
+
+    let value: T = serde_json::from_value(expected.clone())
+        .unwrap_or_else(|e| panic!("{fixture} does not deserialize: {e}"));
+    let actual = serde_json::to_value(&value).unwrap();
+
+    let mut differences = Vec::new();
+    diff("", &expected, &actual, &mut differences);
+    assert!(
+        differences.is_empty(),
+        "{fixture} does not round-trip:\n{}",
+        differences.join("\n")
+    );
+}
+
+/// Collect the paths of the fields that differ between two JSON values.
+fn diff(path: &str, expected: &Value, actual: &Value, differences: &mut Vec<String>) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, expected_value) in expected {
+                let field = format!("{path}.{key}");
+                match actual.get(key) {
+                    Some(actual_value) => diff(&field, expected_value, actual_value, differences),
+                    None => differences.push(format!("{field}: missing after round-trip")),
+                }
+            }
+            for key in actual.keys().filter(|key| !expected.contains_key(*key)) {
+                differences.push(format!("{path}.{key}: added by round-trip"));
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) if expected.len() == actual.len() => {
+            for (index, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                diff(&format!("{path}[{index}]"), expected, actual, differences);
+            }
+        }
+        (expected, actual) if expected != actual => {
+            differences.push(format!("{path}: expected {expected}, got {actual}"));
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn build_status_round_trips() {
+    assert_round_trip::<BuildStatus>("build_status.json");
+}
+
+#[test]
+fn comment_round_trips() {
+    assert_round_trip::<Comment>("comment.json");
+}
+
+#[test]
+fn commit_round_trips() {
+    assert_round_trip::<Commit>("commit.json");
+}
+
+#[test]
+fn commit_changes_page_round_trips() {
+    assert_round_trip::<Page<ChangeItem>>("commit_changes_page.json");
+}
+
+#[test]
+fn diffs_round_trip() {
+    assert_round_trip::<Diffs>("diffs.json");
+}
+
+#[test]
+fn pull_request_round_trips() {
+    assert_round_trip::<PullRequest>("pull_request.json");
+}
+
+#[test]
+fn pull_request_changes_round_trip() {
+    assert_round_trip::<PullRequestChanges>("pull_request_changes.json");
+}
+
+#[test]
+fn pull_request_settings_round_trip() {
+    assert_round_trip::<PullRequestSettings>("pull_request_settings.json");
+}
+
+#[test]
+fn webhook_test_result_round_trips() {
+    assert_round_trip::<WebhookTestResult>("webhook_test_result.json");
+}